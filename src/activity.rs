@@ -0,0 +1,434 @@
+use std::path::Path;
+use std::process::Command;
+
+use crate::data::{PhaseId, PlanningData};
+
+/// Commits shown in the activity panel
+pub const DEFAULT_LIMIT: usize = 50;
+
+/// Field and record separators for `git log --format` (ASCII unit/record separators)
+const FIELD_SEP: char = '\x1f';
+const RECORD_SEP: char = '\x1e';
+
+/// The phase or plan a commit concerns
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ActivityTarget {
+    Phase(PhaseId),
+    Plan { phase: PhaseId, id: String },
+}
+
+impl ActivityTarget {
+    /// Short label (e.g., "Phase 2", "Plan 02-01")
+    pub fn label(&self) -> String {
+        match self {
+            ActivityTarget::Phase(phase) => format!("Phase {}", phase),
+            ActivityTarget::Plan { id, .. } => format!("Plan {}", id),
+        }
+    }
+
+    pub fn phase(&self) -> PhaseId {
+        match self {
+            ActivityTarget::Phase(phase) | ActivityTarget::Plan { phase, .. } => *phase,
+        }
+    }
+}
+
+/// A commit that touched the planning directory or a plan's files
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Commit {
+    /// Abbreviated hash
+    pub hash: String,
+    /// Author date as YYYY-MM-DD
+    pub date: String,
+    pub author: String,
+    pub subject: String,
+    /// Paths changed, relative to the project root
+    pub files: Vec<String>,
+    pub target: Option<ActivityTarget>,
+}
+
+/// Recent commits, newest first, or why they could not be read
+#[derive(Debug, Clone, Default)]
+pub struct ActivityFeed {
+    pub commits: Vec<Commit>,
+    /// Set when git is missing or the project is not a repository
+    pub error: Option<String>,
+    /// Set while the commits are being read in the background
+    pub loading: bool,
+}
+
+/// A plan as far as commit mapping is concerned
+struct PlanRef<'a> {
+    phase: PhaseId,
+    id: &'a str,
+    files: &'a [String],
+}
+
+/// Read the last `limit` commits touching `planning_dir` or any plan's `files_modified`
+///
+/// Runs the `git` binary in the project `root`, which may be below the repository
+/// root; failures are returned in the feed rather than as errors so the panel can
+/// explain them.
+pub fn load_activity(
+    root: &Path,
+    planning_dir: &Path,
+    data: &PlanningData,
+    limit: usize,
+) -> ActivityFeed {
+    let planning = planning_dir
+        .strip_prefix(root)
+        .unwrap_or(planning_dir)
+        .to_string_lossy()
+        .into_owned();
+    let plans = plan_refs(data);
+
+    let mut pathspecs = vec![planning.clone()];
+    for plan in &plans {
+        pathspecs.extend(plan.files.iter().cloned());
+    }
+    pathspecs.sort();
+    pathspecs.dedup();
+
+    match git_log(root, &pathspecs, limit) {
+        Ok(output) => {
+            let mut commits = parse_log(&output);
+            for commit in &mut commits {
+                commit.target = commit_target(commit, &planning, &plans);
+            }
+            ActivityFeed {
+                commits,
+                ..Default::default()
+            }
+        }
+        Err(error) => ActivityFeed {
+            error: Some(error),
+            ..Default::default()
+        },
+    }
+}
+
+fn plan_refs(data: &PlanningData) -> Vec<PlanRef<'_>> {
+    let archived = data
+        .roadmap
+        .milestones
+        .iter()
+        .flat_map(|m| &m.archived_phases);
+    data.roadmap
+        .phases
+        .iter()
+        .chain(archived)
+        .flat_map(|phase| {
+            phase.plans.iter().map(move |plan| PlanRef {
+                phase: phase.number,
+                id: &plan.id,
+                files: plan
+                    .frontmatter
+                    .as_ref()
+                    .map_or(&[][..], |fm| &fm.files_modified),
+            })
+        })
+        .collect()
+}
+
+/// `git log` output for `pathspecs`, or git's error message
+fn git_log(root: &Path, pathspecs: &[String], limit: usize) -> Result<String, String> {
+    let format = format!(
+        "--format={}%h{}%ad{}%an{}%s",
+        RECORD_SEP, FIELD_SEP, FIELD_SEP, FIELD_SEP
+    );
+    let max_count = format!("--max-count={}", limit);
+    let args = [
+        "log",
+        &max_count,
+        "--date=short",
+        &format,
+        "--name-only",
+        // Paths relative to the project, which may be below the repository root
+        "--relative",
+        "--",
+    ];
+    git(
+        root,
+        args.iter()
+            .copied()
+            .chain(pathspecs.iter().map(String::as_str)),
+    )
+}
+
+/// Run `git` in `root`, returning stdout or git's error message
+pub(crate) fn git<'a>(
+    root: &Path,
+    args: impl IntoIterator<Item = &'a str>,
+) -> Result<String, String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(root)
+        .args(args)
+        .output()
+        .map_err(|e| format!("Could not run git: {}", e))?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+/// Split `git log` output into commits (targets are filled in later)
+fn parse_log(output: &str) -> Vec<Commit> {
+    output
+        .split(RECORD_SEP)
+        .filter_map(|record| {
+            let mut lines = record.lines();
+            let mut fields = lines.next()?.split(FIELD_SEP);
+            let hash = fields.next()?.to_string();
+            let date = fields.next()?.to_string();
+            let author = fields.next()?.to_string();
+            let subject = fields.next()?.to_string();
+            let files = lines
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(String::from)
+                .collect();
+            Some(Commit {
+                hash,
+                date,
+                author,
+                subject,
+                files,
+                target: None,
+            })
+        })
+        .collect()
+}
+
+/// Map a commit to a plan or phase: by conventional-commit scope first, then by
+/// the paths it changed, preferring a plan over a phase
+fn commit_target(commit: &Commit, planning: &str, plans: &[PlanRef]) -> Option<ActivityTarget> {
+    if let Some(target) = conventional_scope(&commit.subject).and_then(scope_target) {
+        return Some(target);
+    }
+
+    let targets: Vec<ActivityTarget> = commit
+        .files
+        .iter()
+        .filter_map(|file| path_target(file, planning, plans))
+        .collect();
+    targets
+        .iter()
+        .find(|target| matches!(target, ActivityTarget::Plan { .. }))
+        .or(targets.first())
+        .cloned()
+}
+
+/// The scope of a `type(scope): subject` line
+fn conventional_scope(subject: &str) -> Option<&str> {
+    let (prefix, _) = subject.split_once(':')?;
+    let (kind, scope) = prefix.trim_end_matches('!').split_once('(')?;
+    if kind.is_empty() || !kind.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    scope.strip_suffix(')')
+}
+
+/// A scope such as "02-01" (plan), "2.1" or "phase-2" (phase)
+fn scope_target(scope: &str) -> Option<ActivityTarget> {
+    let scope = scope.trim();
+    if let Some(target) = plan_id_target(scope) {
+        return Some(target);
+    }
+    let number = scope
+        .strip_prefix("phase-")
+        .or_else(|| scope.strip_prefix("phase"))
+        .unwrap_or(scope);
+    number.parse().ok().map(ActivityTarget::Phase)
+}
+
+/// "02-01" or "02.1-03" as a plan, keeping the ID as written
+fn plan_id_target(id: &str) -> Option<ActivityTarget> {
+    let (phase, number) = id.split_once('-')?;
+    if number.is_empty() || !number.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some(ActivityTarget::Plan {
+        phase: phase.parse().ok()?,
+        id: id.to_string(),
+    })
+}
+
+/// Target for a changed path: a phase directory file, or a plan's `files_modified`
+fn path_target(path: &str, planning: &str, plans: &[PlanRef]) -> Option<ActivityTarget> {
+    let phases_dir = format!("{}/phases/", planning);
+    if let Some(rest) = path.strip_prefix(&phases_dir) {
+        let (dir, file) = rest.split_once('/').unwrap_or((rest, ""));
+        // NN-MM-PLAN.md, NN-MM-SUMMARY.md, ...
+        let mut parts = file.splitn(3, '-');
+        if let (Some(phase), Some(number), Some(_)) = (parts.next(), parts.next(), parts.next()) {
+            if let Some(target) = plan_id_target(&format!("{}-{}", phase, number)) {
+                return Some(target);
+            }
+        }
+        return dir
+            .split('-')
+            .next()
+            .and_then(|n| n.parse().ok())
+            .map(ActivityTarget::Phase);
+    }
+
+    plans
+        .iter()
+        .find(|plan| plan.files.iter().any(|file| file == path))
+        .map(|plan| ActivityTarget::Plan {
+            phase: plan.phase,
+            id: plan.id.to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{Phase, Plan, PlanFrontmatter};
+    use tempfile::tempdir;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {:?} failed", args);
+    }
+
+    fn commit(dir: &Path, file: &str, message: &str) {
+        let path = dir.join(file);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, message).unwrap();
+        git(dir, &["add", file]);
+        git(dir, &["commit", "-q", "-m", message]);
+    }
+
+    fn data_with_plan() -> PlanningData {
+        let mut data = PlanningData::default();
+        data.roadmap.phases.push(Phase {
+            number: PhaseId::new(2),
+            plans: vec![Plan {
+                id: "02-01".to_string(),
+                frontmatter: Some(PlanFrontmatter {
+                    files_modified: vec!["src/search.rs".to_string()],
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        data
+    }
+
+    #[test]
+    fn test_activity_from_git_log() {
+        let dir = tempdir().unwrap();
+        git(dir.path(), &["init", "-q"]);
+        commit(dir.path(), ".planning/ROADMAP.md", "docs: roadmap");
+        commit(dir.path(), "README.md", "docs: readme");
+        commit(dir.path(), "src/search.rs", "feat: fuzzy search");
+        commit(
+            dir.path(),
+            ".planning/phases/02-navigation/02-01-SUMMARY.md",
+            "docs: summary",
+        );
+        commit(dir.path(), "src/lib.rs", "fix(2): wire search");
+
+        let feed = load_activity(
+            dir.path(),
+            &dir.path().join(".planning"),
+            &data_with_plan(),
+            DEFAULT_LIMIT,
+        );
+
+        assert_eq!(feed.error, None);
+        let subjects: Vec<&str> = feed.commits.iter().map(|c| c.subject.as_str()).collect();
+        // README.md and src/lib.rs are neither planning files nor in files_modified
+        assert_eq!(
+            subjects,
+            vec!["docs: summary", "feat: fuzzy search", "docs: roadmap"]
+        );
+        let plan = Some(ActivityTarget::Plan {
+            phase: PhaseId::new(2),
+            id: "02-01".to_string(),
+        });
+        assert_eq!(feed.commits[0].target, plan);
+        assert_eq!(feed.commits[1].target, plan);
+        assert_eq!(feed.commits[1].files, vec!["src/search.rs"]);
+        assert_eq!(feed.commits[2].target, None);
+        assert_eq!(feed.commits[0].date.len(), "2026-01-25".len());
+    }
+
+    #[test]
+    fn test_project_below_repository_root() {
+        let dir = tempdir().unwrap();
+        git(dir.path(), &["init", "-q"]);
+        commit(
+            dir.path(),
+            "apps/web/.planning/phases/03-billing/PLAN-NOTES.md",
+            "docs: notes",
+        );
+        commit(dir.path(), "api/.planning/STATE.md", "docs: other project");
+
+        let root = dir.path().join("apps/web");
+        let feed = load_activity(
+            &root,
+            &root.join(".planning"),
+            &PlanningData::default(),
+            DEFAULT_LIMIT,
+        );
+
+        let [notes] = feed.commits.as_slice() else {
+            panic!("expected one commit, got {:?}", feed.commits);
+        };
+        assert_eq!(
+            notes.files,
+            vec![".planning/phases/03-billing/PLAN-NOTES.md"]
+        );
+        assert_eq!(notes.target, Some(ActivityTarget::Phase(PhaseId::new(3))));
+    }
+
+    #[test]
+    fn test_not_a_repository_is_reported() {
+        let dir = tempdir().unwrap();
+        let feed = load_activity(
+            dir.path(),
+            &dir.path().join(".planning"),
+            &PlanningData::default(),
+            DEFAULT_LIMIT,
+        );
+
+        assert!(feed.commits.is_empty());
+        assert!(feed.error.unwrap().contains("not a git repository"));
+    }
+
+    #[test]
+    fn test_scope_targets() {
+        let plan = |id: &str, major| ActivityTarget::Plan {
+            phase: major,
+            id: id.to_string(),
+        };
+        let target = |subject: &str| conventional_scope(subject).and_then(scope_target);
+
+        assert_eq!(
+            target("feat(02-01): add"),
+            Some(plan("02-01", PhaseId::new(2)))
+        );
+        assert_eq!(
+            target("fix(02.1-03)!: break"),
+            Some(plan("02.1-03", PhaseId::inserted(2, 1)))
+        );
+        assert_eq!(
+            target("docs(phase-3): plan"),
+            Some(ActivityTarget::Phase(PhaseId::new(3)))
+        );
+        assert_eq!(target("chore(deps): bump"), None);
+        assert_eq!(target("Merge branch 'x': y"), None);
+    }
+}
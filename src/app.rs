@@ -1,96 +1,199 @@
-use std::collections::HashSet;
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use color_eyre::Result;
 
-use crate::components::tree_view::{build_tree_items, phases_with_requirements, TreeItem};
-use crate::data::{load_planning_data, PlanningData};
+use crate::activity::{load_activity, ActivityFeed, DEFAULT_LIMIT};
+use crate::burnup::{load_burnup, Burnup};
+use crate::commands::{available_commands, rank_commands, suggested_command, KataCommand};
+use crate::components::tree_view::{
+    build_tree_items, find_item_index, phases_with_children, TreeItem, TreeItemKey,
+};
+use crate::data::config::write_setting;
+use crate::data::{
+    load_planning_data, Config, MilestoneStatus, PhaseId, PlanningData, SourceSpan, SETTINGS,
+};
+use crate::decisions::{all_decisions, visible_decisions};
+use crate::editor::{editor_args, editor_command};
 use crate::event::{Event, EventHandler};
+use crate::graph::{DependencyGraph, GraphKind};
+use crate::lint::{lint, Diagnostic};
+use crate::runner::{CommandRunner, DEFAULT_KATA_BIN};
 use crate::search::FuzzyMatcher;
 use crate::state::{AppState, FocusedPane, InputMode, Message};
-use crate::terminal::Terminal;
+use crate::terminal::{copy_to_clipboard, Terminal};
 use crate::update::{key_to_message, update};
+use crate::verify::{checked_files, repository_root, verify};
 use crate::view::view;
+use crate::watcher::{FileWatcher, SourceWatcher, DEFAULT_DEBOUNCE};
+use crate::waves::phases_with_plans;
+use crate::workspace::Workspace;
 
 /// Application state and lifecycle manager
 pub struct App {
-    /// Terminal wrapper
-    terminal: Terminal,
+    /// Path to the .planning/ directory being displayed
+    planning_dir: PathBuf,
+    /// Executable used to run Kata commands
+    kata_bin: PathBuf,
     /// Application state
     state: AppState,
     /// Planning data from .planning/ files
     data: PlanningData,
     /// Flattened tree items for rendering
     tree_items: Vec<TreeItem>,
-    /// Phases that have requirements (children)
-    phases_with_children: HashSet<u8>,
+    /// Phases that have requirements or plans (children)
+    phases_with_children: HashSet<PhaseId>,
     /// Fuzzy matcher for search
     fuzzy_matcher: FuzzyMatcher,
+    /// Lint diagnostics for the loaded files
+    diagnostics: Vec<Diagnostic>,
+    /// Recent git commits, read when the activity panel opens
+    activity: ActivityFeed,
+    /// Projects found by `--workspace`, if running in workspace mode
+    workspace: Option<Workspace>,
+    /// Tree state of workspace projects not currently shown, by planning directory
+    project_views: HashMap<PathBuf, ProjectView>,
+}
+
+/// Tree expansion and selection of a workspace project, kept while another is shown
+#[derive(Debug, Default)]
+struct ProjectView {
+    expanded_phases: HashSet<PhaseId>,
+    expanded_plans: HashSet<String>,
+    collapsed_milestones: HashSet<String>,
+    research_expanded: bool,
+    selected: Option<TreeItemKey>,
+    detail_scroll: u16,
+}
+
+impl ProjectView {
+    /// How a project looks when first shown: shipped milestones collapsed so the
+    /// active work is in view
+    fn initial(data: &PlanningData) -> Self {
+        let collapsed_milestones = data
+            .roadmap
+            .milestones
+            .iter()
+            .filter(|milestone| milestone.status == MilestoneStatus::Shipped)
+            .map(|milestone| milestone.version.clone())
+            .collect();
+        Self {
+            collapsed_milestones,
+            ..Default::default()
+        }
+    }
 }
 
 impl App {
     /// Create a new App instance
     ///
     /// Loads planning data from the specified directory (or current directory if None).
+    /// The terminal is not touched until `run` is called.
     pub fn new(planning_dir: Option<PathBuf>) -> Result<Self> {
-        let terminal = Terminal::new()?;
+        let dir = resolve_planning_dir(planning_dir);
 
-        let dir = planning_dir.unwrap_or_else(|| {
-            std::env::current_dir()
-                .unwrap_or_else(|_| PathBuf::from("."))
-                .join(".planning")
-        });
-
-        let data = load_planning_data(&dir)?;
-        let phases_with_children = phases_with_requirements(&data);
+        let mut data = load_planning_data(&dir)?;
+        verify(repository_root(&dir), &mut data);
+        let phases_with_children = phases_with_children(&data);
         let mut state = AppState::new();
-        let tree_items = build_tree_items(&data, &state.expanded_phases);
+        state.collapsed_milestones = ProjectView::initial(&data).collapsed_milestones;
+
+        let tree_items = build_tree_items(
+            &data,
+            &state.expanded_phases,
+            &state.expanded_plans,
+            &state.collapsed_milestones,
+            false,
+        );
 
         if tree_items.is_empty() {
             state.tree_state.select(None);
         }
 
+        let diagnostics = lint_relative(&dir, &data);
+
         Ok(Self {
-            terminal,
+            planning_dir: dir,
+            kata_bin: PathBuf::from(DEFAULT_KATA_BIN),
             state,
             data,
             tree_items,
             phases_with_children,
             fuzzy_matcher: FuzzyMatcher::new(),
+            diagnostics,
+            activity: ActivityFeed::default(),
+            workspace: None,
+            project_views: HashMap::new(),
         })
     }
 
+    /// Create an App showing the first project found below `root`
+    ///
+    /// The other projects can be switched to from the `p` list.
+    pub fn new_workspace(root: &Path) -> Result<Self> {
+        let workspace = Workspace::discover(root)?;
+        let mut app = Self::new(Some(workspace.active_project().planning_dir.clone()))?;
+        app.workspace = Some(workspace);
+        Ok(app)
+    }
+
+    /// Use a different executable for Kata commands (default: `kata` on PATH)
+    pub fn with_kata_bin(mut self, kata_bin: impl Into<PathBuf>) -> Self {
+        self.kata_bin = kata_bin.into();
+        self
+    }
+
     /// Run the application main loop
     pub async fn run(&mut self) -> Result<()> {
+        let mut terminal = Terminal::new()?;
         let mut events = EventHandler::new(Duration::from_millis(250));
+        let mut runner = CommandRunner::new(&self.kata_bin, events.sender());
+        // Commands run against the displayed project, wherever kata-tui was started
+        runner.set_working_dir(repository_root(&self.planning_dir));
+
+        // Live reload is best-effort: without a watcher the dashboard still works
+        let mut _watcher =
+            FileWatcher::new(&self.planning_dir, events.sender(), DEFAULT_DEBOUNCE).ok();
+        let mut _source_watcher = self.watch_sources(&events);
 
         loop {
-            self.terminal.draw(|frame| {
+            terminal.draw(|frame| {
                 view(
                     frame,
                     &mut self.state,
                     &self.data,
                     &self.tree_items,
                     &self.phases_with_children,
+                    &self.diagnostics,
+                    &self.activity,
                 );
             })?;
 
             if let Some(event) = events.next().await {
                 match event {
                     Event::Key(key) => {
+                        self.state.notice = None;
                         if let Some(mut message) = key_to_message(key, self.state.input_mode) {
                             // Convert navigation to expand/collapse when on expandable phases
                             message = self.maybe_convert_to_expand_message(message);
 
+                            // Start or cancel commands (side effects stay out of update)
+                            self.handle_command_message(&message, &mut runner);
+
                             // Update state with current tree length for bounds checking
                             let state_changed =
-                                update(&mut self.state, message, self.tree_items.len());
+                                update(&mut self.state, message.clone(), self.tree_items.len());
 
                             // Rebuild tree items if expansion state might have changed
                             if state_changed {
-                                self.tree_items =
-                                    build_tree_items(&self.data, &self.state.expanded_phases);
+                                self.tree_items = build_tree_items(
+                                    &self.data,
+                                    &self.state.expanded_phases,
+                                    &self.state.expanded_plans,
+                                    &self.state.collapsed_milestones,
+                                    self.state.research_expanded,
+                                );
 
                                 // Clamp selection to valid range after rebuild
                                 self.clamp_selection_to_tree_bounds();
@@ -99,6 +202,60 @@ impl App {
                                 if self.state.input_mode == InputMode::Search {
                                     self.update_search_matches();
                                 }
+
+                                if message == Message::EnterCommandMode {
+                                    self.state.palette.commands =
+                                        available_commands(self.selected_item(), &self.data);
+                                }
+                                if self.state.input_mode == InputMode::Command {
+                                    self.update_palette_matches();
+                                }
+                                if self.state.input_mode == InputMode::Decisions {
+                                    self.update_decision_rows();
+                                }
+                                if message == Message::ShowProjects {
+                                    self.open_project_list();
+                                }
+                                if message == Message::ShowActivity {
+                                    self.load_activity(&events);
+                                }
+                                if message == Message::ShowGraph {
+                                    self.open_graph();
+                                } else if self.state.input_mode == InputMode::Graph {
+                                    self.select_graph_node_in_tree();
+                                }
+                                if message == Message::ShowWaves {
+                                    let phase =
+                                        self.selected_item().and_then(|item| self.phase_of(item));
+                                    self.list_wave_phases(phase);
+                                }
+                                if message == Message::ShowProgress {
+                                    self.load_burnup(&events);
+                                }
+                            }
+
+                            if message == Message::SwitchProject
+                                && self.switch_project(self.state.project_list.selected)
+                            {
+                                // Watch and run commands in the newly shown project
+                                runner.set_working_dir(repository_root(&self.planning_dir));
+                                _watcher = FileWatcher::new(
+                                    &self.planning_dir,
+                                    events.sender(),
+                                    DEFAULT_DEBOUNCE,
+                                )
+                                .ok();
+                                _source_watcher = self.watch_sources(&events);
+                            }
+
+                            if message == Message::OpenInEditor {
+                                self.open_in_editor(&mut terminal, &mut events).await?;
+                            }
+                            if message == Message::ChangeSetting {
+                                self.change_setting();
+                            }
+                            if message == Message::OpenTaskFiles {
+                                self.open_task_files(&mut terminal, &mut events).await?;
                             }
 
                             if self.state.should_quit {
@@ -107,8 +264,24 @@ impl App {
                         }
                     }
                     Event::Resize(_, _) => {}
-                    Event::Tick => {
-                        // TODO(Phase 3): Refresh data from .planning/ files
+                    Event::Tick => {}
+                    Event::DataChanged(_) => {
+                        self.reload();
+                        // must_haves may now name different files
+                        _source_watcher = self.watch_sources(&events);
+                    }
+                    Event::SourcesChanged(_) => self.reload(),
+                    Event::CommandOutput(output) => self.state.command_output.apply(output),
+                    // Results for a project switched away from are dropped
+                    Event::BurnupLoaded(planning_dir, burnup) => {
+                        if planning_dir == self.planning_dir {
+                            self.state.burnup = burnup;
+                        }
+                    }
+                    Event::ActivityLoaded(planning_dir, feed) => {
+                        if planning_dir == self.planning_dir {
+                            self.activity = feed;
+                        }
                     }
                     Event::Error(e) => {
                         // Log error after terminal is restored (on drop)
@@ -122,6 +295,533 @@ impl App {
         Ok(())
     }
 
+    /// Fill the project list with fresh summaries, or explain it needs `--workspace`
+    fn open_project_list(&mut self) {
+        let Some(workspace) = &mut self.workspace else {
+            self.state.input_mode = InputMode::Normal;
+            self.state.notice = Some("Start with --workspace <dir> to switch projects".into());
+            return;
+        };
+        workspace.refresh();
+        let list = &mut self.state.project_list;
+        list.projects = workspace.projects.clone();
+        list.active = workspace.active;
+        list.selected = list.selected.min(list.projects.len().saturating_sub(1));
+    }
+
+    /// Read recent commits for the displayed project (on demand; git is not watched)
+    ///
+    /// git runs on a blocking thread; the feed arrives as `Event::ActivityLoaded`.
+    fn load_activity(&mut self, events: &EventHandler) {
+        self.activity = ActivityFeed {
+            loading: true,
+            ..Default::default()
+        };
+        let planning_dir = self.planning_dir.clone();
+        let data = self.data.clone();
+        let tx = events.sender();
+        tokio::task::spawn_blocking(move || {
+            let feed = load_activity(
+                repository_root(&planning_dir),
+                &planning_dir,
+                &data,
+                DEFAULT_LIMIT,
+            );
+            let _ = tx.send(Event::ActivityLoaded(planning_dir, feed));
+        });
+    }
+
+    /// Rebuild progress over time for the displayed project
+    ///
+    /// Uncached commits each cost two `git show` runs, so extraction happens on a
+    /// blocking thread; the result arrives as `Event::BurnupLoaded`.
+    fn load_burnup(&mut self, events: &EventHandler) {
+        self.state.burnup = Burnup {
+            loading: true,
+            ..Default::default()
+        };
+        let planning_dir = self.planning_dir.clone();
+        let tx = events.sender();
+        tokio::task::spawn_blocking(move || {
+            let burnup = load_burnup(repository_root(&planning_dir), &planning_dir);
+            let _ = tx.send(Event::BurnupLoaded(planning_dir, burnup));
+        });
+    }
+
+    /// Build the dependency graphs and highlight the node for the tree selection
+    ///
+    /// Plans, and tasks within them, open the plan graph; anything else inside a
+    /// phase opens the phase graph on that phase.
+    fn open_graph(&mut self) {
+        let graph = &mut self.state.graph;
+        graph.phases = DependencyGraph::phases(&self.data);
+        graph.plans = DependencyGraph::plans(&self.data);
+
+        let plan_id = match self.selected_item().map(TreeItem::key) {
+            Some(TreeItemKey::Plan { id, .. }) => Some(id),
+            Some(TreeItemKey::Task { plan_id, .. }) => Some(plan_id),
+            _ => None,
+        };
+        let phase = self.selected_item().and_then(|item| self.phase_of(item));
+        let graph = &mut self.state.graph;
+        let (kind, selected) = match (plan_id, phase) {
+            (Some(id), _) => (
+                GraphKind::Plans,
+                graph
+                    .plans
+                    .nodes
+                    .iter()
+                    .position(|node| node.plan.as_ref() == Some(&id)),
+            ),
+            (None, Some(phase)) => (
+                GraphKind::Phases,
+                graph
+                    .phases
+                    .nodes
+                    .iter()
+                    .position(|node| node.phase == phase),
+            ),
+            (None, None) => (graph.kind, None),
+        };
+        graph.kind = kind;
+        graph.selected = selected.unwrap_or(0);
+    }
+
+    /// Phase an item belongs to, if any
+    fn phase_of(&self, item: &TreeItem) -> Option<PhaseId> {
+        match item.key() {
+            TreeItemKey::Phase(phase)
+            | TreeItemKey::Requirement {
+                phase_num: phase, ..
+            }
+            | TreeItemKey::Plan {
+                phase_num: phase, ..
+            } => Some(phase),
+            TreeItemKey::Task { plan_id, .. } => self
+                .data
+                .roadmap
+                .phases
+                .iter()
+                .find(|phase| phase.plans.iter().any(|plan| plan.id == plan_id))
+                .map(|phase| phase.number),
+            _ => None,
+        }
+    }
+
+    /// Select the graph view's highlighted node in the tree, revealing it if hidden
+    fn select_graph_node_in_tree(&mut self) {
+        let Some(node) = self
+            .state
+            .graph
+            .graph()
+            .nodes
+            .get(self.state.graph.selected)
+        else {
+            return;
+        };
+        let key = match &node.plan {
+            Some(id) => {
+                self.state.expanded_phases.insert(node.phase);
+                TreeItemKey::Plan {
+                    phase_num: node.phase,
+                    id: id.clone(),
+                }
+            }
+            None => TreeItemKey::Phase(node.phase),
+        };
+        let phase = node.phase;
+        for milestone in &self.data.roadmap.milestones {
+            if milestone
+                .phases_in(&self.data.roadmap.phases)
+                .iter()
+                .any(|p| p.number == phase)
+            {
+                self.state.collapsed_milestones.remove(&milestone.version);
+            }
+        }
+
+        self.tree_items = build_tree_items(
+            &self.data,
+            &self.state.expanded_phases,
+            &self.state.expanded_plans,
+            &self.state.collapsed_milestones,
+            self.state.research_expanded,
+        );
+        if let Some(idx) = find_item_index(&self.tree_items, &key) {
+            self.state.tree_state.select(Some(idx));
+            self.state.selected_index = idx;
+            self.state.detail_scroll = 0;
+        }
+    }
+
+    /// Show workspace project `index`, keeping the current project's tree state
+    ///
+    /// Returns whether the displayed project changed.
+    fn switch_project(&mut self, index: usize) -> bool {
+        let Some(workspace) = &mut self.workspace else {
+            return false;
+        };
+        if index == workspace.active {
+            return false;
+        }
+        let Some(project) = workspace.projects.get(index) else {
+            return false;
+        };
+        let planning_dir = project.planning_dir.clone();
+        let Ok(mut data) = load_planning_data(&planning_dir) else {
+            self.state.notice = Some(format!("Could not load {}", planning_dir.display()));
+            return false;
+        };
+        verify(repository_root(&planning_dir), &mut data);
+        workspace.active = index;
+        self.state.project_list.active = index;
+
+        let previous = self.take_project_view();
+        let previous_dir = std::mem::replace(&mut self.planning_dir, planning_dir);
+        self.project_views.insert(previous_dir, previous);
+
+        self.data = data;
+        self.diagnostics = lint_relative(&self.planning_dir, &self.data);
+        self.phases_with_children = phases_with_children(&self.data);
+        let view = self
+            .project_views
+            .remove(&self.planning_dir)
+            .unwrap_or_else(|| ProjectView::initial(&self.data));
+        self.restore_project_view(view);
+        true
+    }
+
+    /// The current project's tree state, leaving fresh search state behind
+    fn take_project_view(&mut self) -> ProjectView {
+        let state = &mut self.state;
+        state.search_query.clear();
+        state.search_matches.clear();
+        state.current_match = 0;
+        ProjectView {
+            expanded_phases: std::mem::take(&mut state.expanded_phases),
+            expanded_plans: std::mem::take(&mut state.expanded_plans),
+            collapsed_milestones: std::mem::take(&mut state.collapsed_milestones),
+            research_expanded: state.research_expanded,
+            selected: state
+                .tree_state
+                .selected()
+                .and_then(|idx| self.tree_items.get(idx))
+                .map(TreeItem::key),
+            detail_scroll: state.detail_scroll,
+        }
+    }
+
+    /// Rebuild the tree for the loaded project with `view`'s expansion and selection
+    fn restore_project_view(&mut self, view: ProjectView) {
+        self.state.expanded_phases = view.expanded_phases;
+        self.state.expanded_plans = view.expanded_plans;
+        self.state.collapsed_milestones = view.collapsed_milestones;
+        self.state.research_expanded = view.research_expanded;
+        self.state.detail_scroll = view.detail_scroll;
+        self.tree_items = build_tree_items(
+            &self.data,
+            &self.state.expanded_phases,
+            &self.state.expanded_plans,
+            &self.state.collapsed_milestones,
+            self.state.research_expanded,
+        );
+
+        let index = view
+            .selected
+            .and_then(|key| find_item_index(&self.tree_items, &key))
+            .unwrap_or(0);
+        if self.tree_items.is_empty() {
+            self.state.tree_state.select(None);
+        } else {
+            self.state.tree_state.select(Some(index));
+            self.state.selected_index = index;
+        }
+    }
+
+    /// Flip or cycle the highlighted setting in config.json, then reload
+    fn change_setting(&mut self) {
+        let Some(setting) = SETTINGS.get(self.state.settings_selected) else {
+            return;
+        };
+        let current = match &self.data.config {
+            Some(config) => config.value(setting.key),
+            None => Config::default().value(setting.key),
+        };
+        let value = setting.next_value(&current);
+
+        let path = self.planning_dir.join("config.json");
+        match write_setting(&path, setting.key, value) {
+            Ok(()) => self.reload(),
+            Err(e) => self.state.notice = Some(format!("Could not update config.json: {}", e)),
+        }
+    }
+
+    /// Watch the files plan must_haves are checked against, if any
+    fn watch_sources(&self, events: &EventHandler) -> Option<SourceWatcher> {
+        let files = checked_files(repository_root(&self.planning_dir), &self.data);
+        if files.is_empty() {
+            return None;
+        }
+        SourceWatcher::new(files, events.sender(), DEFAULT_DEBOUNCE).ok()
+    }
+
+    /// Start, cancel or copy a Kata command in response to a message
+    fn handle_command_message(&mut self, message: &Message, runner: &mut CommandRunner) {
+        match message {
+            Message::RunCommand => {
+                let command = suggested_command(self.selected_item(), &self.data);
+                self.run_kata_command(command, runner);
+            }
+            Message::ConfirmCommand => {
+                if let Some(command) = self.state.palette.selected_command() {
+                    self.run_kata_command(command, runner);
+                }
+            }
+            Message::CopyCommand => {
+                if let Some(command) = self.state.palette.selected_command() {
+                    let text = command.slash_command();
+                    self.state.notice = Some(match copy_to_clipboard(&text) {
+                        Ok(()) => format!("Copied {}", text),
+                        Err(e) => format!("Copy failed: {}", e),
+                    });
+                }
+            }
+            Message::CancelCommand => runner.cancel(),
+            _ => {}
+        }
+    }
+
+    /// Hand a command to the runner and show its output pane
+    fn run_kata_command(&mut self, command: KataCommand, runner: &mut CommandRunner) {
+        let args = command.args();
+        let label = format!("{} {}", runner.program().display(), args.join(" "));
+        if runner.run(args) {
+            self.state.command_output.start(label);
+        } else {
+            self.state.notice = Some("A command is already running (Ctrl-C to cancel)".into());
+            self.state.command_output.visible = true;
+        }
+    }
+
+    /// Suspend the dashboard, edit the selection's source in `$EDITOR`, then reload
+    async fn open_in_editor(
+        &mut self,
+        terminal: &mut Terminal,
+        events: &mut EventHandler,
+    ) -> Result<()> {
+        let Some(span) = self.selected_source() else {
+            self.state.notice = Some("No source file for this item".into());
+            return Ok(());
+        };
+        self.run_editor(editor_args(&span), terminal, events).await
+    }
+
+    /// Suspend the dashboard, edit the selected task's files in `$EDITOR`, then reload
+    ///
+    /// Task files are relative to the project root (the parent of `.planning/`).
+    async fn open_task_files(
+        &mut self,
+        terminal: &mut Terminal,
+        events: &mut EventHandler,
+    ) -> Result<()> {
+        let files = match self.selected_item() {
+            Some(TreeItem::Task { task, .. }) => task.files.clone(),
+            _ => {
+                self.state.notice = Some("Select a task to open its files".into());
+                return Ok(());
+            }
+        };
+        if files.is_empty() {
+            self.state.notice = Some("This task lists no files".into());
+            return Ok(());
+        }
+
+        let root = repository_root(&self.planning_dir);
+        let args = files
+            .iter()
+            .map(|file| root.join(file).to_string_lossy().into_owned())
+            .collect();
+        self.run_editor(args, terminal, events).await
+    }
+
+    /// Run `$EDITOR` with `args`, then reload
+    ///
+    /// Terminal events are paused while the editor runs so keystrokes reach it
+    /// rather than the dashboard.
+    async fn run_editor(
+        &mut self,
+        args: Vec<String>,
+        terminal: &mut Terminal,
+        events: &mut EventHandler,
+    ) -> Result<()> {
+        let command = editor_command();
+
+        events.pause();
+        terminal.suspend()?;
+        let status = tokio::process::Command::new(&command[0])
+            .args(&command[1..])
+            .args(args)
+            .status()
+            .await;
+        terminal.resume()?;
+        events.resume();
+
+        match status {
+            Ok(status) if !status.success() => {
+                self.state.notice = Some(format!("{} exited with {}", command[0], status));
+            }
+            Ok(_) => {}
+            Err(e) => self.state.notice = Some(format!("Could not start {}: {}", command[0], e)),
+        }
+        self.reload();
+        Ok(())
+    }
+
+    /// Source span of the selected tree item
+    fn selected_source(&self) -> Option<SourceSpan> {
+        match self.selected_item()? {
+            TreeItem::Project(_) => self.data.project.span.clone(),
+            TreeItem::Milestone { milestone, .. } => milestone.span.clone(),
+            TreeItem::Phase(phase) => phase.span.clone(),
+            TreeItem::Requirement { requirement, .. } => requirement.span.clone(),
+            TreeItem::Plan { plan, .. } => plan.span.clone(),
+            TreeItem::Task { task, .. } => task.span.clone(),
+            TreeItem::Research(_) => None,
+            TreeItem::Document { document, .. } => document.span.clone(),
+        }
+    }
+
+    /// Currently selected tree item
+    fn selected_item(&self) -> Option<&TreeItem> {
+        self.state
+            .tree_state
+            .selected()
+            .and_then(|idx| self.tree_items.get(idx))
+    }
+
+    /// Re-rank palette commands against the current filter
+    fn update_palette_matches(&mut self) {
+        self.state.palette.matches = rank_commands(
+            &mut self.fuzzy_matcher,
+            &self.state.palette.query,
+            &self.state.palette.commands,
+        );
+        if self.state.palette.selected >= self.state.palette.matches.len() {
+            self.state.palette.selected = 0;
+        }
+    }
+
+    /// Re-filter and re-sort the decisions table, keeping the selection in range
+    fn update_decision_rows(&mut self) {
+        let view = &mut self.state.decisions;
+        view.rows = visible_decisions(
+            &mut self.fuzzy_matcher,
+            &view.query,
+            &all_decisions(&self.data),
+            view.sort,
+            view.descending,
+        );
+        view.selected = view.selected.min(view.rows.len().saturating_sub(1));
+        if view.rows.is_empty() {
+            view.detail_open = false;
+        }
+    }
+
+    /// Reload planning data from disk, preserving UI state by identity
+    ///
+    /// Expansion is keyed by phase number, and selection and the current search
+    /// match are restored by item key rather than flat index, since phases or
+    /// requirements may have been inserted or removed.
+    pub fn reload(&mut self) {
+        let Ok(mut data) = load_planning_data(&self.planning_dir) else {
+            return; // Keep showing the last good data
+        };
+        verify(repository_root(&self.planning_dir), &mut data);
+
+        let selected_key = self
+            .state
+            .tree_state
+            .selected()
+            .and_then(|idx| self.tree_items.get(idx))
+            .map(TreeItem::key);
+        let current_match_key = self
+            .state
+            .search_matches
+            .get(self.state.current_match)
+            .and_then(|&idx| self.tree_items.get(idx))
+            .map(TreeItem::key);
+
+        self.data = data;
+        self.diagnostics = lint_relative(&self.planning_dir, &self.data);
+        self.phases_with_children = phases_with_children(&self.data);
+        self.tree_items = build_tree_items(
+            &self.data,
+            &self.state.expanded_phases,
+            &self.state.expanded_plans,
+            &self.state.collapsed_milestones,
+            self.state.research_expanded,
+        );
+
+        match selected_key.and_then(|key| find_item_index(&self.tree_items, &key)) {
+            Some(idx) => {
+                self.state.tree_state.select(Some(idx));
+                self.state.selected_index = idx;
+            }
+            None => self.clamp_selection_to_tree_bounds(),
+        }
+
+        if !self.state.search_query.is_empty() {
+            self.state.search_matches = self.compute_search_matches();
+            self.state.current_match = current_match_key
+                .and_then(|key| {
+                    self.state
+                        .search_matches
+                        .iter()
+                        .position(|&idx| self.tree_items[idx].key() == key)
+                })
+                .unwrap_or(0);
+        }
+
+        if self.state.input_mode == InputMode::Decisions {
+            self.update_decision_rows();
+        }
+        if self.state.input_mode == InputMode::Graph {
+            self.reload_graph();
+        }
+        if self.state.input_mode == InputMode::Waves {
+            self.list_wave_phases(self.state.waves.phase());
+        }
+    }
+
+    /// List the phases with plans for the wave view and show `phase` if it has any
+    fn list_wave_phases(&mut self, phase: Option<PhaseId>) {
+        let waves = &mut self.state.waves;
+        waves.phases = phases_with_plans(&self.data);
+        waves.selected = phase
+            .and_then(|phase| waves.phases.iter().position(|&p| p == phase))
+            .unwrap_or(waves.selected.min(waves.phases.len().saturating_sub(1)));
+    }
+
+    /// Rebuild the dependency graphs, keeping the highlighted node by identity
+    fn reload_graph(&mut self) {
+        let graph = &mut self.state.graph;
+        let selected = graph
+            .graph()
+            .nodes
+            .get(graph.selected)
+            .map(|node| (node.phase, node.plan.clone()));
+        graph.phases = DependencyGraph::phases(&self.data);
+        graph.plans = DependencyGraph::plans(&self.data);
+        let nodes = &graph.graph().nodes;
+        graph.selected = selected
+            .and_then(|(phase, plan)| {
+                nodes
+                    .iter()
+                    .position(|node| node.phase == phase && node.plan == plan)
+            })
+            .unwrap_or(graph.selected.min(nodes.len().saturating_sub(1)));
+    }
+
     /// Clamp selection to valid tree bounds after rebuild
     fn clamp_selection_to_tree_bounds(&mut self) {
         if self.tree_items.is_empty() {
@@ -144,8 +844,21 @@ impl App {
             return;
         }
 
-        self.state.search_matches = self
-            .tree_items
+        self.state.search_matches = self.compute_search_matches();
+
+        // Clamp current_match and auto-select
+        if self.state.current_match >= self.state.search_matches.len() {
+            self.state.current_match = 0;
+        }
+        if let Some(&match_idx) = self.state.search_matches.get(self.state.current_match) {
+            self.state.tree_state.select(Some(match_idx));
+            self.state.selected_index = match_idx;
+        }
+    }
+
+    /// Indices of tree items matching the current search query
+    fn compute_search_matches(&mut self) -> Vec<usize> {
+        self.tree_items
             .iter()
             .enumerate()
             .filter_map(|(idx, item)| {
@@ -156,34 +869,37 @@ impl App {
                     None
                 }
             })
-            .collect();
-
-        // Clamp current_match and auto-select
-        if self.state.current_match >= self.state.search_matches.len() {
-            self.state.current_match = 0;
-        }
-        if let Some(&match_idx) = self.state.search_matches.get(self.state.current_match) {
-            self.state.tree_state.select(Some(match_idx));
-            self.state.selected_index = match_idx;
-        }
+            .collect()
     }
 
     /// Get searchable text from a tree item.
-    /// Projects use name, phases include number + name, requirements include ID + description.
+    /// Projects use name, milestones version + name, phases include number + name, requirements include ID + description,
+    /// plans include ID + title, tasks their name, documents include kind + title.
     fn item_searchable_text(item: &TreeItem) -> String {
         match item {
             TreeItem::Project(name) => name.clone(),
+            TreeItem::Milestone { milestone, .. } => milestone.title(),
             TreeItem::Phase(phase) => format!("Phase {}: {}", phase.number, phase.name),
             TreeItem::Requirement { requirement, .. } => {
                 format!("{}: {}", requirement.id, requirement.description)
             }
+            TreeItem::Plan { plan, .. } => format!("{}: {}", plan.id, plan.title()),
+            TreeItem::Task { task, .. } => task.name.clone(),
+            TreeItem::Research(_) => "Research".to_string(),
+            TreeItem::Document { document, .. } => {
+                format!("{}: {}", document.kind.label(), document.title)
+            }
         }
     }
 
-    /// Convert navigation messages to ToggleExpand for expandable phases.
-    /// Enter/Right expands collapsed phases, Left collapses expanded phases.
+    /// Convert navigation messages to ToggleExpand/ToggleMilestone/ToggleResearch for expandable items.
+    /// Enter/Right expands collapsed items, Left collapses expanded ones.
     fn maybe_convert_to_expand_message(&self, message: Message) -> Message {
-        if self.state.focused_pane != FocusedPane::Tree {
+        // h/l move between dependencies and dependents in the graph view, and
+        // between phases in the wave view
+        if self.state.focused_pane != FocusedPane::Tree
+            || matches!(self.state.input_mode, InputMode::Graph | InputMode::Waves)
+        {
             return message;
         }
 
@@ -192,6 +908,43 @@ impl App {
             None => return message,
         };
 
+        if let Some(TreeItem::Research(_)) = self.tree_items.get(selected_idx) {
+            let expanded = self.state.research_expanded;
+            return match message {
+                Message::Select => Message::ToggleResearch,
+                Message::NavigateRight if !expanded => Message::ToggleResearch,
+                Message::NavigateLeft if expanded => Message::ToggleResearch,
+                _ => message,
+            };
+        }
+
+        // Milestones toggle the same way, but start expanded
+        if let Some(TreeItem::Milestone { milestone, .. }) = self.tree_items.get(selected_idx) {
+            let version = milestone.version.clone();
+            let collapsed = self.state.is_milestone_collapsed(&version);
+            return match message {
+                Message::Select => Message::ToggleMilestone(version),
+                Message::NavigateRight if collapsed => Message::ToggleMilestone(version),
+                Message::NavigateLeft if !collapsed => Message::ToggleMilestone(version),
+                _ => message,
+            };
+        }
+
+        // Plans with tasks expand to show them
+        if let Some(TreeItem::Plan { plan, .. }) = self.tree_items.get(selected_idx) {
+            if plan.tasks.is_empty() {
+                return message;
+            }
+            let id = plan.id.clone();
+            let expanded = self.state.is_plan_expanded(&id);
+            return match message {
+                Message::Select => Message::TogglePlan(id),
+                Message::NavigateRight if !expanded => Message::TogglePlan(id),
+                Message::NavigateLeft if expanded => Message::TogglePlan(id),
+                _ => message,
+            };
+        }
+
         let phase_num = match self
             .tree_items
             .get(selected_idx)
@@ -218,8 +971,228 @@ impl App {
     }
 }
 
+/// Lint diagnostics with paths shown relative to the project root
+fn lint_relative(planning_dir: &Path, data: &PlanningData) -> Vec<Diagnostic> {
+    let root = repository_root(planning_dir);
+    lint(planning_dir, data)
+        .into_iter()
+        .map(|mut diagnostic| {
+            if let Ok(relative) = diagnostic.file.strip_prefix(root) {
+                diagnostic.file = relative.to_path_buf();
+            }
+            diagnostic
+        })
+        .collect()
+}
+
+/// The given .planning directory, or `./.planning` when none was specified
+pub fn resolve_planning_dir(planning_dir: Option<PathBuf>) -> PathBuf {
+    planning_dir.unwrap_or_else(|| {
+        std::env::current_dir()
+            .unwrap_or_else(|_| PathBuf::from("."))
+            .join(".planning")
+    })
+}
+
 /// Run the application
-pub async fn run(planning_dir: Option<PathBuf>) -> Result<()> {
-    let mut app = App::new(planning_dir)?;
+///
+/// With a `workspace` root, every project below it can be switched to; otherwise
+/// the single `planning_dir` is shown.
+pub async fn run(
+    planning_dir: Option<PathBuf>,
+    workspace: Option<PathBuf>,
+    kata_bin: PathBuf,
+) -> Result<()> {
+    let app = match workspace {
+        Some(root) => App::new_workspace(&root)?,
+        None => App::new(planning_dir)?,
+    };
+    let mut app = app.with_kata_bin(kata_bin);
     app.run().await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::tree_view::TreeItemKey;
+    use tempfile::tempdir;
+
+    const ROADMAP: &str = r#"# Roadmap
+
+### Phase 1: Foundation
+
+**Goal:** Build the foundation.
+
+**Requirements:**
+- DISP-01: Display stuff
+
+---
+
+### Phase 2: Navigation
+
+**Goal:** Navigate.
+
+**Requirements:**
+- NAV-01: Move around
+- NAV-02: Focus indicators
+
+---
+"#;
+
+    fn selected_key(app: &App) -> Option<TreeItemKey> {
+        app.state
+            .tree_state
+            .selected()
+            .and_then(|idx| app.tree_items.get(idx))
+            .map(TreeItem::key)
+    }
+
+    fn app_with_roadmap(roadmap: &str) -> (tempfile::TempDir, App) {
+        let dir = tempdir().unwrap();
+        std::fs::write(dir.path().join("PROJECT.md"), "# Test Project\n").unwrap();
+        std::fs::write(dir.path().join("ROADMAP.md"), roadmap).unwrap();
+        let app = App::new(Some(dir.path().to_path_buf())).unwrap();
+        (dir, app)
+    }
+
+    #[test]
+    fn test_reload_preserves_selection_by_identity() {
+        let (dir, mut app) = app_with_roadmap(ROADMAP);
+        app.state.toggle_expansion(PhaseId::new(2));
+        app.tree_items = build_tree_items(
+            &app.data,
+            &app.state.expanded_phases,
+            &app.state.expanded_plans,
+            &app.state.collapsed_milestones,
+            app.state.research_expanded,
+        );
+        let idx = find_item_index(
+            &app.tree_items,
+            &TreeItemKey::Requirement {
+                phase_num: PhaseId::new(2),
+                id: "NAV-02".to_string(),
+            },
+        )
+        .unwrap();
+        app.state.tree_state.select(Some(idx));
+
+        // Insert a requirement ahead of the selection, shifting flat indices
+        let edited = ROADMAP.replace(
+            "- DISP-01: Display stuff",
+            "- DISP-01: Display stuff\n- DISP-02: More stuff",
+        );
+        std::fs::write(dir.path().join("ROADMAP.md"), edited).unwrap();
+        app.reload();
+
+        assert!(app.state.is_expanded(PhaseId::new(2)));
+        assert_eq!(
+            selected_key(&app),
+            Some(TreeItemKey::Requirement {
+                phase_num: PhaseId::new(2),
+                id: "NAV-02".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_reload_clamps_when_selection_removed() {
+        let (dir, mut app) = app_with_roadmap(ROADMAP);
+        let last = app.tree_items.len() - 1;
+        app.state.tree_state.select(Some(last));
+
+        let truncated = ROADMAP.split("### Phase 2").next().unwrap();
+        std::fs::write(dir.path().join("ROADMAP.md"), truncated).unwrap();
+        app.reload();
+
+        assert_eq!(
+            app.state.tree_state.selected(),
+            Some(app.tree_items.len() - 1)
+        );
+    }
+
+    #[test]
+    fn test_reload_preserves_current_search_match() {
+        let (dir, mut app) = app_with_roadmap(ROADMAP);
+        app.state.search_query = "Phase".to_string();
+        app.update_search_matches();
+        app.state.current_match = 1;
+        let match_key = app.tree_items[app.state.search_matches[1]].key();
+        assert_eq!(match_key, TreeItemKey::Phase(PhaseId::new(2)));
+
+        let edited = ROADMAP.replace(
+            "### Phase 1: Foundation",
+            "### Phase 0: Setup\n\n**Goal:** Set up.\n\n---\n\n### Phase 1: Foundation",
+        );
+        std::fs::write(dir.path().join("ROADMAP.md"), edited).unwrap();
+        app.reload();
+
+        let idx = app.state.search_matches[app.state.current_match];
+        assert_eq!(
+            app.tree_items[idx].key(),
+            TreeItemKey::Phase(PhaseId::new(2))
+        );
+    }
+
+    #[test]
+    fn test_switch_project_keeps_each_projects_tree_state() {
+        let root = tempdir().unwrap();
+        for project in ["api", "web"] {
+            let planning = root.path().join(project).join(".planning");
+            std::fs::create_dir_all(&planning).unwrap();
+            std::fs::write(planning.join("ROADMAP.md"), ROADMAP).unwrap();
+        }
+        let mut app = App::new_workspace(root.path()).unwrap();
+        assert!(app.planning_dir.ends_with("api/.planning"));
+
+        app.state.toggle_expansion(PhaseId::new(2));
+        app.state.search_query = "Phase".to_string();
+        app.tree_items = build_tree_items(
+            &app.data,
+            &app.state.expanded_phases,
+            &app.state.expanded_plans,
+            &app.state.collapsed_milestones,
+            app.state.research_expanded,
+        );
+        app.state.tree_state.select(Some(app.tree_items.len() - 1));
+        let api_selection = selected_key(&app);
+
+        assert!(app.switch_project(1));
+        assert!(app.planning_dir.ends_with("web/.planning"));
+        assert!(!app.state.is_expanded(PhaseId::new(2)));
+        assert!(app.state.search_query.is_empty());
+        assert_eq!(app.state.tree_state.selected(), Some(0));
+
+        assert!(app.switch_project(0));
+        assert!(app.state.is_expanded(PhaseId::new(2)));
+        assert_eq!(selected_key(&app), api_selection);
+        assert!(!app.switch_project(0), "already showing project 0");
+    }
+
+    #[test]
+    fn test_graph_navigation_selects_tree_item() {
+        let roadmap = ROADMAP.replace(
+            "**Goal:** Navigate.",
+            "**Goal:** Navigate.\n**Depends on:** Phase 1",
+        );
+        let (_dir, mut app) = app_with_roadmap(&roadmap);
+        let phase_1 = find_item_index(&app.tree_items, &TreeItemKey::Phase(PhaseId::new(1)));
+        app.state.tree_state.select(phase_1);
+
+        update(&mut app.state, Message::ShowGraph, app.tree_items.len());
+        app.open_graph();
+        assert_eq!(app.state.graph.kind, GraphKind::Phases);
+        assert_eq!(
+            app.state.graph.graph().nodes[app.state.graph.selected].phase,
+            PhaseId::new(1)
+        );
+
+        // l moves to the dependent phase rather than expanding the tree's phase
+        let message = app.maybe_convert_to_expand_message(Message::NavigateRight);
+        assert!(update(&mut app.state, message, app.tree_items.len()));
+        app.select_graph_node_in_tree();
+        assert_eq!(
+            selected_key(&app),
+            Some(TreeItemKey::Phase(PhaseId::new(2)))
+        );
+    }
+}
@@ -0,0 +1,398 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::activity::git;
+use crate::data::{
+    parse_requirements, parse_roadmap, parse_state, PlanningData, RequirementStatus,
+};
+
+/// Bumped when `ProgressSample` changes meaning, discarding old cache files
+const CACHE_VERSION: u32 = 2;
+
+/// Cache file, inside the repository's git directory
+const CACHE_FILE: &str = "kata-tui/burnup.json";
+
+const FIELD_SEP: char = '\x1f';
+
+/// Progress as recorded in the planning files at one commit
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProgressSample {
+    pub requirements_complete: u16,
+    pub total_requirements: u16,
+    pub phases_complete: u8,
+    pub total_phases: u8,
+}
+
+/// One commit that changed STATE.md, REQUIREMENTS.md or ROADMAP.md
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BurnupPoint {
+    /// Full commit hash
+    pub hash: String,
+    /// Author date as YYYY-MM-DD
+    pub date: String,
+    /// Author date as a Unix timestamp
+    pub timestamp: i64,
+    pub sample: ProgressSample,
+}
+
+/// Progress over time, oldest commit first
+#[derive(Debug, Clone, Default)]
+pub struct Burnup {
+    pub points: Vec<BurnupPoint>,
+    /// Set when git is missing or the project is not a repository
+    pub error: Option<String>,
+    /// Set while the history is being read in the background
+    pub loading: bool,
+}
+
+/// Samples already extracted, by repository-relative planning directory, then commit hash
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BurnupCache {
+    version: u32,
+    projects: BTreeMap<String, BTreeMap<String, ProgressSample>>,
+}
+
+/// Rebuild progress over time from every commit that touched STATE.md, REQUIREMENTS.md
+/// or ROADMAP.md
+///
+/// Each revision is run through the regular parsers. Samples are cached per commit
+/// hash in the git directory, so only commits made since the last run are parsed.
+pub fn load_burnup(root: &Path, planning_dir: &Path) -> Burnup {
+    match history(root, planning_dir) {
+        Ok(points) => Burnup {
+            points,
+            ..Default::default()
+        },
+        Err(error) => Burnup {
+            error: Some(error),
+            ..Default::default()
+        },
+    }
+}
+
+fn history(root: &Path, planning_dir: &Path) -> Result<Vec<BurnupPoint>, String> {
+    let planning = planning_dir
+        .strip_prefix(root)
+        .unwrap_or(planning_dir)
+        .to_string_lossy()
+        .into_owned();
+    let state_path = format!("{}/STATE.md", planning);
+    let requirements_path = format!("{}/REQUIREMENTS.md", planning);
+    let roadmap_path = format!("{}/ROADMAP.md", planning);
+
+    let format = format!("--format=%H{}%at{}%ad", FIELD_SEP, FIELD_SEP);
+    let log = git(
+        root,
+        [
+            "log",
+            "--reverse",
+            "--date=short",
+            &format,
+            "--",
+            &state_path,
+            &requirements_path,
+            &roadmap_path,
+        ],
+    )?;
+
+    // Cache keys are relative to the repository root, which `root` may be below
+    let prefix = git(root, ["rev-parse", "--show-prefix"])?;
+    let key = format!("{}{}", prefix.trim(), planning);
+    let cache_path = cache_path(root);
+    let mut cache = cache_path.as_deref().map(read_cache).unwrap_or_default();
+    let samples = cache.projects.entry(key).or_default();
+    let mut changed = false;
+
+    let mut points = Vec::new();
+    for line in log.lines() {
+        let mut fields = line.split(FIELD_SEP);
+        let (Some(hash), Some(timestamp), Some(date)) =
+            (fields.next(), fields.next(), fields.next())
+        else {
+            continue;
+        };
+        let sample = match samples.get(hash) {
+            Some(sample) => *sample,
+            None => {
+                let state = show(root, hash, &state_path);
+                let requirements = show(root, hash, &requirements_path);
+                let sample = progress_sample(state.as_deref(), requirements.as_deref(), || {
+                    show(root, hash, &roadmap_path)
+                });
+                samples.insert(hash.to_string(), sample);
+                changed = true;
+                sample
+            }
+        };
+        points.push(BurnupPoint {
+            hash: hash.to_string(),
+            date: date.to_string(),
+            timestamp: timestamp.parse().unwrap_or_default(),
+            sample,
+        });
+    }
+
+    // The cache only saves time; a read-only git directory just means parsing again
+    if let (true, Some(path)) = (changed, cache_path) {
+        let _ = write_cache(&path, &cache);
+    }
+
+    Ok(points)
+}
+
+/// A file's content at `hash`, or None if it did not exist then
+fn show(root: &Path, hash: &str, path: &str) -> Option<String> {
+    // `./` makes the path relative to `root` rather than the repository root
+    git(root, ["show", &format!("{}:./{}", hash, path)]).ok()
+}
+
+/// Progress from one revision of STATE.md, REQUIREMENTS.md and ROADMAP.md
+///
+/// Requirement counts come from REQUIREMENTS.md when it lists any requirements,
+/// otherwise from the STATE.md metrics table. Phase counts come from the metrics
+/// table too; without one, ROADMAP.md is read (only then) and its checked phases
+/// counted.
+fn progress_sample(
+    state: Option<&str>,
+    requirements: Option<&str>,
+    roadmap: impl FnOnce() -> Option<String>,
+) -> ProgressSample {
+    let state = state
+        .map(|content| parse_state(Path::new("STATE.md"), content).0)
+        .unwrap_or_default();
+    let statuses = requirements
+        .map(|content| parse_requirements(Path::new("REQUIREMENTS.md"), content).statuses())
+        .unwrap_or_default();
+
+    let (requirements_complete, total_requirements) = if statuses.is_empty() {
+        (state.requirements_complete, state.total_requirements)
+    } else {
+        let complete = statuses
+            .values()
+            .filter(|&&status| status == RequirementStatus::Complete)
+            .count();
+        (complete as u16, statuses.len() as u16)
+    };
+
+    let (phases_complete, total_phases) = if state.total_phases > 0 {
+        (state.phases_complete, state.total_phases)
+    } else {
+        let data = PlanningData {
+            roadmap: roadmap()
+                .map(|content| parse_roadmap(Path::new("ROADMAP.md"), &content).0)
+                .unwrap_or_default(),
+            ..Default::default()
+        };
+        let (total, complete) = data.phase_totals();
+        (complete as u8, total as u8)
+    };
+
+    ProgressSample {
+        requirements_complete,
+        total_requirements,
+        phases_complete,
+        total_phases,
+    }
+}
+
+fn cache_path(root: &Path) -> Option<PathBuf> {
+    let path = git(root, ["rev-parse", "--git-path", CACHE_FILE]).ok()?;
+    Some(root.join(path.trim()))
+}
+
+/// The cache at `path`; missing, unreadable or outdated caches start empty
+fn read_cache(path: &Path) -> BurnupCache {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str::<BurnupCache>(&content).ok())
+        .filter(|cache| cache.version == CACHE_VERSION)
+        .unwrap_or(BurnupCache {
+            version: CACHE_VERSION,
+            projects: BTreeMap::new(),
+        })
+}
+
+fn write_cache(path: &Path, cache: &BurnupCache) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string(cache).map_err(std::io::Error::other)?;
+    std::fs::write(path, json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+    use tempfile::tempdir;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {:?} failed", args);
+    }
+
+    fn commit(dir: &Path, file: &str, content: &str, date: &str) {
+        let path = dir.join(file);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, content).unwrap();
+        git(dir, &["add", file]);
+        git(
+            dir,
+            &[
+                "commit",
+                "-q",
+                "-m",
+                file,
+                &format!("--date={}T12:00:00", date),
+            ],
+        );
+    }
+
+    fn state(phases_complete: u8) -> String {
+        format!(
+            "| Metric | Value |\n|--------|-------|\n| Total Phases | 4 |\n| Phases Complete | {} |\n",
+            phases_complete
+        )
+    }
+
+    #[test]
+    fn test_burnup_from_history() {
+        let dir = tempdir().unwrap();
+        git(dir.path(), &["init", "-q"]);
+        commit(dir.path(), ".planning/STATE.md", &state(0), "2026-01-05");
+        commit(
+            dir.path(),
+            ".planning/REQUIREMENTS.md",
+            "- [ ] **NAV-01**: Move\n- [ ] **NAV-02**: Jump\n- [ ] **DISP-01**: Show\n",
+            "2026-01-06",
+        );
+        commit(dir.path(), "src/main.rs", "fn main() {}", "2026-01-07");
+        commit(
+            dir.path(),
+            ".planning/REQUIREMENTS.md",
+            "- [x] **NAV-01**: Move\n- [x] **NAV-02**: Jump\n- [ ] **DISP-01**: Show\n",
+            "2026-01-09",
+        );
+        commit(dir.path(), ".planning/STATE.md", &state(1), "2026-01-10");
+
+        let burnup = load_burnup(dir.path(), &dir.path().join(".planning"));
+
+        assert_eq!(burnup.error, None);
+        let dates: Vec<&str> = burnup.points.iter().map(|p| p.date.as_str()).collect();
+        assert_eq!(
+            dates,
+            vec!["2026-01-05", "2026-01-06", "2026-01-09", "2026-01-10"]
+        );
+        let progress: Vec<(u16, u16, u8)> = burnup
+            .points
+            .iter()
+            .map(|p| {
+                let s = p.sample;
+                (
+                    s.requirements_complete,
+                    s.total_requirements,
+                    s.phases_complete,
+                )
+            })
+            .collect();
+        assert_eq!(progress, vec![(0, 0, 0), (0, 3, 0), (2, 3, 0), (2, 3, 1)]);
+        assert!(burnup.points[0].timestamp < burnup.points[3].timestamp);
+    }
+
+    #[test]
+    fn test_phases_counted_from_roadmap_without_state_metrics() {
+        let dir = tempdir().unwrap();
+        git(dir.path(), &["init", "-q"]);
+        let roadmap = |checked: &str| {
+            format!(
+                "# Roadmap\n\n- [{}] **Phase 1: Foundation**\n- [ ] **Phase 2: Navigation**\n- [ ] **Phase 3: Polish**\n",
+                checked
+            )
+        };
+        commit(
+            dir.path(),
+            ".planning/ROADMAP.md",
+            &roadmap(" "),
+            "2026-01-05",
+        );
+        commit(dir.path(), ".planning/STATE.md", "# State\n", "2026-01-06");
+        commit(
+            dir.path(),
+            ".planning/ROADMAP.md",
+            &roadmap("x"),
+            "2026-01-07",
+        );
+
+        let burnup = load_burnup(dir.path(), &dir.path().join(".planning"));
+
+        let phases: Vec<(u8, u8)> = burnup
+            .points
+            .iter()
+            .map(|p| (p.sample.phases_complete, p.sample.total_phases))
+            .collect();
+        assert_eq!(phases, vec![(0, 3), (0, 3), (1, 3)]);
+    }
+
+    #[test]
+    fn test_samples_are_cached_per_commit() {
+        let dir = tempdir().unwrap();
+        git(dir.path(), &["init", "-q"]);
+        commit(dir.path(), ".planning/STATE.md", &state(2), "2026-01-05");
+        let planning = dir.path().join(".planning");
+
+        let first = load_burnup(dir.path(), &planning);
+        let cache_file = dir.path().join(".git").join(CACHE_FILE);
+        let mut cache = read_cache(&cache_file);
+        let samples = cache.projects.get_mut(".planning").unwrap();
+        assert_eq!(samples.len(), 1);
+
+        // A doctored cache entry proves the revision is not parsed again
+        let hash = first.points[0].hash.clone();
+        samples.get_mut(&hash).unwrap().phases_complete = 3;
+        write_cache(&cache_file, &cache).unwrap();
+
+        let second = load_burnup(dir.path(), &planning);
+        assert_eq!(first.points[0].sample.phases_complete, 2);
+        assert_eq!(second.points[0].sample.phases_complete, 3);
+    }
+
+    #[test]
+    fn test_project_below_repository_root_keys_cache_by_prefix() {
+        let dir = tempdir().unwrap();
+        git(dir.path(), &["init", "-q"]);
+        commit(
+            dir.path(),
+            "apps/web/.planning/STATE.md",
+            &state(1),
+            "2026-01-05",
+        );
+        commit(dir.path(), ".planning/STATE.md", &state(2), "2026-01-06");
+        let root = dir.path().join("apps/web");
+
+        let burnup = load_burnup(&root, &root.join(".planning"));
+
+        assert_eq!(burnup.error, None);
+        assert_eq!(burnup.points.len(), 1);
+        assert_eq!(burnup.points[0].sample.phases_complete, 1);
+        let cache = read_cache(&dir.path().join(".git").join(CACHE_FILE));
+        let keys: Vec<&str> = cache.projects.keys().map(String::as_str).collect();
+        assert_eq!(keys, vec!["apps/web/.planning"]);
+    }
+
+    #[test]
+    fn test_not_a_repository_is_reported() {
+        let dir = tempdir().unwrap();
+        let burnup = load_burnup(dir.path(), &dir.path().join(".planning"));
+
+        assert!(burnup.points.is_empty());
+        assert!(burnup.error.unwrap().contains("not a git repository"));
+    }
+}
@@ -0,0 +1,267 @@
+use std::fmt;
+
+use crate::components::tree_view::TreeItem;
+use crate::data::{Phase, PhaseId, Plan, PlanningData};
+use crate::search::FuzzyMatcher;
+
+/// A Kata workflow action that can be run from the dashboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KataAction {
+    DiscussPhase,
+    ResearchPhase,
+    PlanPhase,
+    ExecutePhase,
+    VerifyWork,
+    Progress,
+}
+
+impl KataAction {
+    /// Actions that operate on a phase, in workflow order
+    pub const PHASE_ACTIONS: [KataAction; 5] = [
+        KataAction::DiscussPhase,
+        KataAction::ResearchPhase,
+        KataAction::PlanPhase,
+        KataAction::ExecutePhase,
+        KataAction::VerifyWork,
+    ];
+
+    /// Command name as passed to the `kata` executable (e.g., "plan-phase")
+    pub fn name(&self) -> &'static str {
+        match self {
+            KataAction::DiscussPhase => "discuss-phase",
+            KataAction::ResearchPhase => "research-phase",
+            KataAction::PlanPhase => "plan-phase",
+            KataAction::ExecutePhase => "execute-phase",
+            KataAction::VerifyWork => "verify-work",
+            KataAction::Progress => "progress",
+        }
+    }
+
+    /// One-line description shown in the palette
+    pub fn description(&self) -> &'static str {
+        match self {
+            KataAction::DiscussPhase => "Gather context before planning",
+            KataAction::ResearchPhase => "Research how to implement the phase",
+            KataAction::PlanPhase => "Create PLAN.md files for the phase",
+            KataAction::ExecutePhase => "Execute the phase's plans",
+            KataAction::VerifyWork => "Verify the phase delivered its goal",
+            KataAction::Progress => "Show project progress and next steps",
+        }
+    }
+
+    /// The next action for a phase, judged from its plans and summaries
+    ///
+    /// No PLAN files yet → plan it; plans without a SUMMARY → execute them;
+    /// every plan summarized → verify the result.
+    pub fn suggested_for(phase: &Phase) -> KataAction {
+        if phase.plans.is_empty() {
+            KataAction::PlanPhase
+        } else if !phase.plans.iter().all(Plan::is_complete) {
+            KataAction::ExecutePhase
+        } else {
+            KataAction::VerifyWork
+        }
+    }
+}
+
+/// An action with its arguments filled in, ready to run or copy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KataCommand {
+    pub action: KataAction,
+    /// Target phase for phase actions
+    pub phase: Option<PhaseId>,
+}
+
+impl KataCommand {
+    pub fn new(action: KataAction, phase: Option<PhaseId>) -> Self {
+        Self { action, phase }
+    }
+
+    /// Arguments for the `kata` executable (e.g., `["plan-phase", "3"]`)
+    pub fn args(&self) -> Vec<String> {
+        let mut args = vec![self.action.name().to_string()];
+        if let Some(phase) = self.phase {
+            args.push(phase.to_string());
+        }
+        args
+    }
+
+    /// Slash command form, as typed into an assistant session (e.g., "/kata:plan-phase 3")
+    pub fn slash_command(&self) -> String {
+        format!("/kata:{}", self)
+    }
+}
+
+impl fmt::Display for KataCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.phase {
+            Some(phase) => write!(f, "{} {}", self.action.name(), phase),
+            None => write!(f, "{}", self.action.name()),
+        }
+    }
+}
+
+/// Phase a tree item belongs to, if any
+///
+/// Phases of archived milestones are read-only, so they get no phase actions.
+fn selected_phase<'a>(item: Option<&'a TreeItem>, data: &'a PlanningData) -> Option<&'a Phase> {
+    let number = match item? {
+        TreeItem::Phase(phase) => {
+            let archived = data
+                .roadmap
+                .milestones
+                .iter()
+                .any(|m| m.archived_phases.iter().any(|p| p.number == phase.number));
+            return (!archived).then_some(phase);
+        }
+        TreeItem::Milestone { .. } | TreeItem::Research(_) => return None,
+        TreeItem::Requirement { phase_num, .. }
+        | TreeItem::Plan { phase_num, .. }
+        | TreeItem::Task { phase_num, .. } => *phase_num,
+        TreeItem::Document { phase_num, .. } => (*phase_num)?,
+        TreeItem::Project(_) => data.state.current_phase?,
+    };
+    data.roadmap.phases.iter().find(|p| p.number == number)
+}
+
+/// Commands available for the selected tree item, most relevant first
+///
+/// Phase actions are pre-filled with the selected phase (the phase a requirement
+/// or plan belongs to, or STATE.md's current phase for the project node). The
+/// suggested next action comes first, followed by the other phase actions in
+/// workflow order and finally `progress`.
+pub fn available_commands(selected: Option<&TreeItem>, data: &PlanningData) -> Vec<KataCommand> {
+    let mut commands = Vec::new();
+
+    if let Some(phase) = selected_phase(selected, data) {
+        let suggested = KataAction::suggested_for(phase);
+        commands.push(KataCommand::new(suggested, Some(phase.number)));
+        commands.extend(
+            KataAction::PHASE_ACTIONS
+                .iter()
+                .filter(|&&action| action != suggested)
+                .map(|&action| KataCommand::new(action, Some(phase.number))),
+        );
+    }
+
+    commands.push(KataCommand::new(KataAction::Progress, None));
+    commands
+}
+
+/// The command `x` runs directly: the first of `available_commands`
+pub fn suggested_command(selected: Option<&TreeItem>, data: &PlanningData) -> KataCommand {
+    available_commands(selected, data)[0]
+}
+
+/// Indices of `commands` matching `query`, best match first
+///
+/// Matches against the command and its description; ties keep the original
+/// (relevance) order, so an empty query lists every command unchanged.
+pub fn rank_commands(
+    matcher: &mut FuzzyMatcher,
+    query: &str,
+    commands: &[KataCommand],
+) -> Vec<usize> {
+    let mut scored: Vec<(usize, u32)> = commands
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, command)| {
+            let haystack = format!("{} {}", command, command.action.description());
+            matcher.score(query, &haystack).map(|score| (idx, score))
+        })
+        .collect();
+
+    scored.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+    scored.into_iter().map(|(idx, _)| idx).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::PlanSummary;
+
+    fn phase_with_plans(number: u16, plans: usize, summarized: usize) -> Phase {
+        Phase {
+            number: PhaseId::new(number),
+            plans: (0..plans)
+                .map(|i| Plan {
+                    id: format!("{:02}-{:02}", number, i + 1),
+                    summary: (i < summarized).then(PlanSummary::default),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_suggestion_follows_plan_progress() {
+        assert_eq!(
+            KataAction::suggested_for(&phase_with_plans(3, 0, 0)),
+            KataAction::PlanPhase
+        );
+        assert_eq!(
+            KataAction::suggested_for(&phase_with_plans(3, 2, 1)),
+            KataAction::ExecutePhase
+        );
+        assert_eq!(
+            KataAction::suggested_for(&phase_with_plans(3, 2, 2)),
+            KataAction::VerifyWork
+        );
+    }
+
+    #[test]
+    fn test_commands_prefill_phase_of_selected_item() {
+        let mut data = PlanningData::default();
+        data.roadmap.phases.push(phase_with_plans(2, 1, 0));
+        let plan = Box::new(data.roadmap.phases[0].plans[0].clone());
+        let item = TreeItem::Plan {
+            phase_num: PhaseId::new(2),
+            plan,
+        };
+
+        let commands = available_commands(Some(&item), &data);
+
+        assert_eq!(commands[0].to_string(), "execute-phase 2");
+        assert_eq!(commands[0].args(), vec!["execute-phase", "2"]);
+        assert_eq!(commands[0].slash_command(), "/kata:execute-phase 2");
+        assert_eq!(commands.len(), KataAction::PHASE_ACTIONS.len() + 1);
+        assert_eq!(commands.last().unwrap().to_string(), "progress");
+    }
+
+    #[test]
+    fn test_project_node_uses_current_phase() {
+        let mut data = PlanningData::default();
+        data.roadmap.phases.push(phase_with_plans(4, 0, 0));
+        let project = TreeItem::Project("Kata".to_string());
+
+        assert_eq!(
+            suggested_command(Some(&project), &data),
+            KataCommand::new(KataAction::Progress, None)
+        );
+
+        data.state.current_phase = Some(PhaseId::new(4));
+        assert_eq!(
+            suggested_command(Some(&project), &data).to_string(),
+            "plan-phase 4"
+        );
+    }
+
+    #[test]
+    fn test_rank_commands_fuzzy_filters_and_orders() {
+        let mut matcher = FuzzyMatcher::new();
+        let commands = available_commands(
+            Some(&TreeItem::Phase(phase_with_plans(1, 0, 0))),
+            &PlanningData::default(),
+        );
+
+        assert_eq!(
+            rank_commands(&mut matcher, "", &commands),
+            (0..commands.len()).collect::<Vec<_>>()
+        );
+
+        let ranked = rank_commands(&mut matcher, "verify", &commands);
+        assert_eq!(commands[ranked[0]].action, KataAction::VerifyWork);
+        assert!(rank_commands(&mut matcher, "zzz", &commands).is_empty());
+    }
+}
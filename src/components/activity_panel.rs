@@ -0,0 +1,118 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
+};
+
+use crate::activity::{ActivityFeed, ActivityTarget};
+use crate::components::popup_area;
+
+/// Width of the phase/plan column ("Plan 02.1-03")
+const TARGET_WIDTH: usize = 12;
+
+/// Overlay listing recent commits to .planning/ and the files plans modify
+pub struct ActivityPanel<'a> {
+    feed: &'a ActivityFeed,
+    scroll: u16,
+}
+
+impl<'a> ActivityPanel<'a> {
+    pub fn new(feed: &'a ActivityFeed, scroll: u16) -> Self {
+        Self { feed, scroll }
+    }
+}
+
+/// Plans in yellow, phases in magenta
+fn target_color(target: &ActivityTarget) -> Color {
+    match target {
+        ActivityTarget::Phase(_) => Color::Magenta,
+        ActivityTarget::Plan { .. } => Color::Yellow,
+    }
+}
+
+impl ActivityPanel<'_> {
+    /// Furthest the panel can scroll in `area` before its last line reaches the bottom
+    pub fn max_scroll(feed: &ActivityFeed, area: Rect) -> u16 {
+        let popup_area = popup_area(area, 80, 70);
+        let rows = Self::paragraph(feed).line_count(popup_area.width);
+        u16::try_from(rows)
+            .unwrap_or(u16::MAX)
+            .saturating_sub(popup_area.height)
+    }
+
+    fn paragraph(feed: &ActivityFeed) -> Paragraph<'static> {
+        let mut lines = Vec::new();
+        if feed.loading {
+            lines.push(Line::from(Span::styled(
+                "Reading git history...",
+                Style::default().fg(Color::DarkGray),
+            )));
+        } else if let Some(error) = &feed.error {
+            lines.push(Line::from(Span::styled(
+                format!("Could not read git history: {}", error),
+                Style::default().fg(Color::Red),
+            )));
+        } else if feed.commits.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "No commits touch .planning/ or the plans' files yet",
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+
+        for commit in &feed.commits {
+            let target = match &commit.target {
+                Some(target) => Span::styled(
+                    format!("{:<width$}", target.label(), width = TARGET_WIDTH),
+                    Style::default()
+                        .fg(target_color(target))
+                        .add_modifier(Modifier::BOLD),
+                ),
+                None => Span::raw(" ".repeat(TARGET_WIDTH)),
+            };
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{} ", commit.date),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::styled(
+                    format!("{} ", commit.hash),
+                    Style::default().fg(Color::Cyan),
+                ),
+                target,
+                Span::raw(format!("  {}", commit.subject)),
+                Span::styled(
+                    format!("  ({})", commit.author),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "j/k to scroll, a or Esc to close",
+            Style::default().fg(Color::DarkGray),
+        )));
+
+        Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .title(format!(" Activity ({}) ", feed.commits.len()))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan)),
+            )
+            .style(Style::default().bg(Color::Black))
+    }
+}
+
+impl Widget for ActivityPanel<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let popup_area = popup_area(area, 80, 70);
+        Clear.render(popup_area, buf);
+
+        Self::paragraph(self.feed)
+            .scroll((self.scroll, 0))
+            .render(popup_area, buf);
+    }
+}
@@ -0,0 +1,65 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Paragraph, Widget},
+};
+
+use crate::markdown::render_inline;
+
+/// Most blocker lines shown before the rest are summarised
+pub const MAX_BANNER_LINES: usize = 3;
+
+/// Lines the banner needs for `blockers`, 0 when there are none
+pub fn banner_height(blockers: &[String]) -> u16 {
+    blockers.len().min(MAX_BANNER_LINES) as u16
+}
+
+/// Banner across the top of the screen listing STATE.md blockers
+pub struct BlockersBanner<'a> {
+    blockers: &'a [String],
+}
+
+impl<'a> BlockersBanner<'a> {
+    pub fn new(blockers: &'a [String]) -> Self {
+        Self { blockers }
+    }
+}
+
+impl Widget for BlockersBanner<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let label = Style::default()
+            .fg(Color::White)
+            .bg(Color::Red)
+            .add_modifier(Modifier::BOLD);
+        let hidden = self.blockers.len().saturating_sub(MAX_BANNER_LINES);
+        // The last line makes room for the count of blockers not shown
+        let shown = if hidden > 0 {
+            MAX_BANNER_LINES - 1
+        } else {
+            MAX_BANNER_LINES
+        };
+
+        let mut lines: Vec<Line> = self
+            .blockers
+            .iter()
+            .take(shown)
+            .map(|blocker| {
+                let mut spans = vec![Span::styled(" BLOCKED ", label), Span::raw(" ")];
+                spans.extend(render_inline(blocker));
+                Line::from(spans)
+            })
+            .collect();
+        if hidden > 0 {
+            lines.push(Line::from(vec![
+                Span::styled(" BLOCKED ", label),
+                Span::raw(format!(" +{} more in STATE.md", hidden + 1)),
+            ]));
+        }
+
+        Paragraph::new(lines)
+            .style(Style::default().fg(Color::White).bg(Color::Rgb(90, 0, 0)))
+            .render(area, buf);
+    }
+}
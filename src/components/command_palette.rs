@@ -0,0 +1,96 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Flex, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
+};
+
+use crate::state::CommandPaletteState;
+
+/// Command palette popup: filter input plus ranked Kata commands
+pub struct CommandPalette<'a> {
+    palette: &'a CommandPaletteState,
+}
+
+impl<'a> CommandPalette<'a> {
+    pub fn new(palette: &'a CommandPaletteState) -> Self {
+        Self { palette }
+    }
+
+    /// Popup near the top of the screen, sized to the number of matches
+    fn popup_area(&self, area: Rect) -> Rect {
+        // Borders + input line + separator + one line per match (at least one)
+        let height = (self.palette.matches.len().max(1) as u16 + 4).min(area.height);
+        let vertical = Layout::vertical([Constraint::Length(height)])
+            .flex(Flex::Start)
+            .vertical_margin(2);
+        let horizontal = Layout::horizontal([Constraint::Percentage(60)]).flex(Flex::Center);
+        let [area] = vertical.areas(area);
+        let [area] = horizontal.areas(area);
+        area
+    }
+}
+
+impl Widget for CommandPalette<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let popup_area = self.popup_area(area);
+        Clear.render(popup_area, buf);
+
+        let mut lines = vec![
+            Line::from(Span::styled(
+                format!(":{}", self.palette.query),
+                Style::default().fg(Color::Cyan),
+            )),
+            Line::from(Span::styled(
+                "─".repeat(popup_area.width.saturating_sub(2) as usize),
+                Style::default().fg(Color::DarkGray),
+            )),
+        ];
+
+        if self.palette.matches.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "No matching commands",
+                Style::default().fg(Color::Red),
+            )));
+        }
+
+        for (pos, &idx) in self.palette.matches.iter().enumerate() {
+            let command = &self.palette.commands[idx];
+            let highlighted = pos == self.palette.selected;
+            let base = if highlighted {
+                Style::default().bg(Color::DarkGray)
+            } else {
+                Style::default()
+            };
+
+            let mut spans = vec![
+                Span::styled(if highlighted { "> " } else { "  " }, base),
+                Span::styled(
+                    command.to_string(),
+                    base.fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("  {}", command.action.description()),
+                    base.fg(Color::Gray),
+                ),
+            ];
+            // Commands are listed most relevant first, so index 0 is the suggestion
+            if idx == 0 {
+                spans.push(Span::styled("  (suggested)", base.fg(Color::Green)));
+            }
+            lines.push(Line::from(spans));
+        }
+
+        let palette = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .title(" Kata Command (Enter run, Ctrl-Y copy, Esc cancel) ")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan)),
+            )
+            .style(Style::default().bg(Color::Black));
+
+        palette.render(popup_area, buf);
+    }
+}
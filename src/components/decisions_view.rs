@@ -0,0 +1,178 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{
+        Block, Borders, Cell, Clear, Paragraph, Row, StatefulWidget, Table, TableState, Widget,
+        Wrap,
+    },
+};
+
+use crate::components::popup_area;
+use crate::data::Decision;
+use crate::decisions::{decision_cell, decision_source, DecisionColumn};
+use crate::state::DecisionsViewState;
+
+/// Overlay table of key decisions from PROJECT.md and STATE.md
+pub struct DecisionsView<'a> {
+    decisions: &'a [&'a Decision],
+    view: &'a DecisionsViewState,
+}
+
+impl<'a> DecisionsView<'a> {
+    pub fn new(decisions: &'a [&'a Decision], view: &'a DecisionsViewState) -> Self {
+        Self { decisions, view }
+    }
+
+    fn header(&self) -> Row<'static> {
+        let cells = DecisionColumn::ALL.iter().map(|&column| {
+            let mut title = column.title().to_string();
+            if column == self.view.sort {
+                title.push_str(if self.view.descending { " ▼" } else { " ▲" });
+            }
+            Cell::from(title)
+        });
+        Row::new(cells).style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+    }
+
+    /// Full text of the highlighted decision, wrapped
+    fn render_detail(decision: &Decision, area: Rect, buf: &mut Buffer) {
+        let popup_area = popup_area(area, 60, 50);
+        Clear.render(popup_area, buf);
+
+        let mut lines = vec![
+            Line::from(Span::styled(
+                decision.decision.clone(),
+                Style::default().add_modifier(Modifier::BOLD),
+            )),
+            Line::from(vec![
+                Span::styled("Outcome: ", Style::default().fg(Color::Gray)),
+                Span::styled(
+                    decision.outcome.clone(),
+                    Style::default().fg(outcome_color(&decision.outcome)),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("Source: ", Style::default().fg(Color::Gray)),
+                Span::styled(decision_source(decision), Style::default().fg(Color::Cyan)),
+            ]),
+            Line::from(""),
+        ];
+        if decision.rationale.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "No rationale recorded",
+                Style::default().fg(Color::DarkGray),
+            )));
+        } else {
+            lines.push(Line::from(decision.rationale.clone()));
+        }
+
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: true })
+            .block(
+                Block::default()
+                    .title(" Decision (Esc to close) ")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan)),
+            )
+            .style(Style::default().bg(Color::Black))
+            .render(popup_area, buf);
+    }
+}
+
+/// Color for an outcome such as "Implemented" or "— Pending (Phase 3)"
+fn outcome_color(outcome: &str) -> Color {
+    let outcome = outcome.to_lowercase();
+    if outcome.contains("pending") {
+        Color::Yellow
+    } else if outcome.contains("implemented") || outcome.contains("good") {
+        Color::Green
+    } else if outcome.contains("revisit") || outcome.contains("reverted") {
+        Color::Red
+    } else {
+        Color::Reset
+    }
+}
+
+impl Widget for DecisionsView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let popup_area = popup_area(area, 90, 80);
+        Clear.render(popup_area, buf);
+
+        let block = Block::default()
+            .title(format!(
+                " Key Decisions ({}/{}) ",
+                self.view.rows.len(),
+                self.decisions.len()
+            ))
+            .title_bottom(" Tab sort, Shift-Tab reverse, Enter details, Esc close ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::Black));
+        let inner = block.inner(popup_area);
+        block.render(popup_area, buf);
+
+        let [filter_area, table_area] =
+            Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(inner);
+
+        let filter = if self.view.query.is_empty() {
+            Span::styled("Type to filter", Style::default().fg(Color::DarkGray))
+        } else {
+            Span::styled(
+                format!("Filter: {}", self.view.query),
+                Style::default().fg(Color::Cyan),
+            )
+        };
+        Paragraph::new(Line::from(filter)).render(filter_area, buf);
+
+        if self.view.rows.is_empty() {
+            let message = if self.decisions.is_empty() {
+                "No Key Decisions table in PROJECT.md or STATE.md"
+            } else {
+                "No matching decisions"
+            };
+            Paragraph::new(Span::styled(message, Style::default().fg(Color::Red)))
+                .render(table_area, buf);
+            return;
+        }
+
+        let rows = self.view.rows.iter().map(|&idx| {
+            let decision = self.decisions[idx];
+            Row::new(DecisionColumn::ALL.iter().map(|&column| {
+                let cell = Cell::from(decision_cell(decision, column));
+                match column {
+                    DecisionColumn::Outcome => {
+                        cell.style(Style::default().fg(outcome_color(&decision.outcome)))
+                    }
+                    DecisionColumn::Source => cell.style(Style::default().fg(Color::DarkGray)),
+                    _ => cell,
+                }
+            }))
+        });
+        let widths = [
+            Constraint::Percentage(25),
+            Constraint::Percentage(15),
+            Constraint::Fill(1),
+            Constraint::Length(14),
+        ];
+        let table = Table::new(rows, widths)
+            .header(self.header())
+            .column_spacing(2)
+            .highlight_style(Style::default().bg(Color::DarkGray))
+            .highlight_symbol("> ");
+
+        let mut table_state = TableState::default().with_selected(Some(self.view.selected));
+        StatefulWidget::render(table, table_area, buf, &mut table_state);
+
+        if self.view.detail_open {
+            if let Some(&idx) = self.view.rows.get(self.view.selected) {
+                Self::render_detail(self.decisions[idx], area, buf);
+            }
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use std::collections::{HashMap, HashSet};
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph, Widget},
+};
+
+use crate::components::popup_area;
+use crate::graph::{DependencyGraph, GraphKind, Slot};
+use crate::state::GraphViewState;
+
+const BOX_WIDTH: u16 = 22;
+const BOX_HEIGHT: u16 = 4;
+const ROW_GAP: u16 = 1;
+const COLUMN_GAP: u16 = 6;
+
+/// Problems listed under the graph before the rest are summarized
+const MAX_PROBLEM_LINES: usize = 4;
+
+// Line directions leaving a connector cell
+const UP: u8 = 1;
+const DOWN: u8 = 2;
+const LEFT: u8 = 4;
+const RIGHT: u8 = 8;
+
+/// Overlay drawing phase or plan dependencies as layered boxes and arrows
+pub struct DependencyGraphView<'a> {
+    view: &'a GraphViewState,
+}
+
+impl<'a> DependencyGraphView<'a> {
+    pub fn new(view: &'a GraphViewState) -> Self {
+        Self { view }
+    }
+}
+
+fn column_x(layer: usize) -> u16 {
+    layer as u16 * (BOX_WIDTH + COLUMN_GAP)
+}
+
+fn slot_y(pos: usize) -> u16 {
+    pos as u16 * (BOX_HEIGHT + ROW_GAP)
+}
+
+/// Row connectors attach to: the box's first text line
+fn connector_y(pos: usize) -> u16 {
+    slot_y(pos) + 1
+}
+
+/// Box-drawing character joining the given directions
+fn connector_symbol(directions: u8) -> &'static str {
+    match directions {
+        d if d == UP | DOWN | LEFT | RIGHT => "┼",
+        d if d == UP | DOWN | RIGHT => "├",
+        d if d == UP | DOWN | LEFT => "┤",
+        d if d == LEFT | RIGHT | DOWN => "┬",
+        d if d == LEFT | RIGHT | UP => "┴",
+        d if d == DOWN | RIGHT => "┌",
+        d if d == DOWN | LEFT => "┐",
+        d if d == UP | RIGHT => "└",
+        d if d == UP | LEFT => "┘",
+        d if d & (UP | DOWN) != 0 && d & (LEFT | RIGHT) == 0 => "│",
+        _ => "─",
+    }
+}
+
+/// Draw the whole graph onto a buffer of its own size
+fn render_canvas(view: &GraphViewState) -> Buffer {
+    let graph = view.graph();
+    let width = column_x(graph.layers.len()).saturating_sub(COLUMN_GAP);
+    let height = graph
+        .layers
+        .iter()
+        .map(|slots| slot_y(slots.len()).saturating_sub(ROW_GAP))
+        .max()
+        .unwrap_or(0);
+    let mut canvas = Buffer::empty(Rect::new(0, 0, width, height));
+
+    let mut connectors: HashMap<(u16, u16), u8> = HashMap::new();
+    let mut arrows: HashSet<(u16, u16)> = HashSet::new();
+    let mut add = |x: u16, y: u16, directions: u8| {
+        *connectors.entry((x, y)).or_default() |= directions;
+    };
+
+    for segment in &graph.segments {
+        let x0 = column_x(segment.layer) + BOX_WIDTH;
+        let x1 = column_x(segment.layer + 1) - 1;
+        let bend = x0 + COLUMN_GAP / 2;
+        let (y0, y1) = (connector_y(segment.from), connector_y(segment.to));
+
+        for x in x0..bend {
+            add(x, y0, LEFT | RIGHT);
+        }
+        let (leave, arrive) = match y1.cmp(&y0) {
+            std::cmp::Ordering::Greater => (DOWN, UP),
+            std::cmp::Ordering::Less => (UP, DOWN),
+            std::cmp::Ordering::Equal => (RIGHT, LEFT),
+        };
+        add(bend, y0, LEFT | leave);
+        for y in y0.min(y1) + 1..y0.max(y1) {
+            add(bend, y, UP | DOWN);
+        }
+        add(bend, y1, arrive | RIGHT);
+        for x in bend + 1..=x1 {
+            add(x, y1, LEFT | RIGHT);
+        }
+        if segment.arrow {
+            arrows.insert((x1, y1));
+        }
+    }
+
+    for (layer, slots) in graph.layers.iter().enumerate() {
+        for (pos, slot) in slots.iter().enumerate() {
+            let x = column_x(layer);
+            match *slot {
+                Slot::Through => {
+                    for dx in 0..BOX_WIDTH {
+                        add(x + dx, connector_y(pos), LEFT | RIGHT);
+                    }
+                }
+                Slot::Node(node) => {
+                    let area = Rect::new(x, slot_y(pos), BOX_WIDTH, BOX_HEIGHT);
+                    render_node(graph, node, node == view.selected, area, &mut canvas);
+                }
+            }
+        }
+    }
+
+    let line_style = Style::default().fg(Color::DarkGray);
+    for ((x, y), directions) in connectors {
+        let symbol = if arrows.contains(&(x, y)) {
+            "▶"
+        } else {
+            connector_symbol(directions)
+        };
+        if let Some(cell) = canvas.cell_mut((x, y)) {
+            cell.set_symbol(symbol).set_style(line_style);
+        }
+    }
+
+    canvas
+}
+
+fn render_node(
+    graph: &DependencyGraph,
+    index: usize,
+    selected: bool,
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    let node = &graph.nodes[index];
+    let mut border = Style::default().fg(node.status.color());
+    let mut block = Block::default().borders(Borders::ALL);
+    if selected {
+        border = border.add_modifier(Modifier::BOLD);
+        block = block.border_type(BorderType::Thick);
+    }
+    if graph.in_cycle(index) {
+        block = block.title_bottom(Span::styled(" cycle ", Style::default().fg(Color::Red)));
+    }
+
+    let mut title = Style::default().add_modifier(Modifier::BOLD);
+    if selected {
+        title = title.bg(Color::DarkGray);
+    }
+    Paragraph::new(vec![
+        Line::from(Span::styled(node.title.clone(), title)),
+        Line::from(Span::styled(
+            node.subtitle.clone(),
+            Style::default().fg(Color::Gray),
+        )),
+    ])
+    .block(block.border_style(border))
+    .render(area, buf);
+}
+
+/// Cycles and dangling references, most serious first
+fn problem_lines(graph: &DependencyGraph, kind: GraphKind) -> Vec<Line<'static>> {
+    let mut lines: Vec<Line> = graph
+        .cycles
+        .iter()
+        .map(|cycle| {
+            let mut names: Vec<&str> = cycle
+                .iter()
+                .map(|&n| graph.nodes[n].title.as_str())
+                .collect();
+            names.push(names[0]);
+            Line::from(Span::styled(
+                format!("Cycle: {}", names.join(" → ")),
+                Style::default().fg(Color::Red),
+            ))
+        })
+        .collect();
+
+    let missing = match kind {
+        GraphKind::Phases => "which is not in the roadmap",
+        GraphKind::Plans => "which has no PLAN.md",
+    };
+    lines.extend(graph.dangling.iter().map(|dangling| {
+        Line::from(Span::styled(
+            format!(
+                "{} depends on {}, {}",
+                graph.nodes[dangling.node].title, dangling.missing, missing
+            ),
+            Style::default().fg(Color::Yellow),
+        ))
+    }));
+
+    if lines.len() > MAX_PROBLEM_LINES {
+        let more = lines.len() - (MAX_PROBLEM_LINES - 1);
+        lines.truncate(MAX_PROBLEM_LINES - 1);
+        lines.push(Line::from(Span::styled(
+            format!("... and {} more", more),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    lines
+}
+
+impl Widget for DependencyGraphView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let popup_area = popup_area(area, 90, 80);
+        Clear.render(popup_area, buf);
+
+        let graph = self.view.graph();
+        let name = match self.view.kind {
+            GraphKind::Phases => "Phases",
+            GraphKind::Plans => "Plans",
+        };
+        let block = Block::default()
+            .title(format!(" Dependencies: {} ({}) ", name, graph.nodes.len()))
+            .title_bottom(" h/l dependencies/dependents, j/k move, Tab phases/plans, Esc close ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::Black));
+        let inner = block.inner(popup_area);
+        block.render(popup_area, buf);
+
+        if graph.nodes.is_empty() {
+            let message = match self.view.kind {
+                GraphKind::Phases => "No phases in ROADMAP.md",
+                GraphKind::Plans => "No PLAN.md files in the roadmap's phases",
+            };
+            Line::from(Span::styled(message, Style::default().fg(Color::DarkGray)))
+                .render(inner, buf);
+            return;
+        }
+
+        let problems = problem_lines(graph, self.view.kind);
+        let footer_height = if problems.is_empty() {
+            0
+        } else {
+            problems.len() as u16 + 1
+        };
+        let [graph_area, footer] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(footer_height)]).areas(inner);
+        Paragraph::new(problems).render(
+            Rect {
+                y: footer.y + 1,
+                ..footer
+            },
+            buf,
+        );
+
+        // Scroll just far enough to keep the selected box in view
+        let canvas = render_canvas(self.view);
+        let (layer, pos) = graph.position(self.view.selected).unwrap_or_default();
+        let offset_x = (column_x(layer) + BOX_WIDTH).saturating_sub(graph_area.width);
+        let offset_y = (slot_y(pos) + BOX_HEIGHT).saturating_sub(graph_area.height);
+        for y in 0..graph_area.height {
+            for x in 0..graph_area.width {
+                if let (Some(source), Some(target)) = (
+                    canvas.cell((x + offset_x, y + offset_y)),
+                    buf.cell_mut((graph_area.x + x, graph_area.y + y)),
+                ) {
+                    *target = source.clone();
+                    target.set_bg(Color::Black);
+                }
+            }
+        }
+    }
+}
@@ -6,8 +6,9 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph, Widget, Wrap},
 };
 
-use crate::components::tree_view::TreeItem;
-use crate::data::PlanningData;
+use crate::components::tree_view::{gauge, TreeItem};
+use crate::data::{Document, Milestone, PhaseId, PlanTask, PlanningData};
+use crate::markdown::{render_inline, render_markdown};
 
 /// Detail pane widget showing selected item content
 pub struct DetailPane<'a> {
@@ -36,11 +37,25 @@ impl<'a> DetailPane<'a> {
         match self.selected_item {
             None => Text::raw("No item selected"),
             Some(TreeItem::Project(_)) => self.build_project_content(),
+            Some(TreeItem::Milestone {
+                milestone,
+                completion,
+                ..
+            }) => self.build_milestone_content(milestone, *completion),
             Some(TreeItem::Phase(phase)) => self.build_phase_content(phase),
             Some(TreeItem::Requirement {
                 requirement,
                 phase_num,
             }) => self.build_requirement_content(requirement, *phase_num),
+            Some(TreeItem::Plan {
+                plan, phase_num, ..
+            }) => self.build_plan_content(plan, *phase_num),
+            Some(TreeItem::Task { task, plan_id, .. }) => self.build_task_content(task, plan_id),
+            Some(TreeItem::Research(_)) => self.build_research_content(),
+            Some(TreeItem::Document {
+                document,
+                phase_num,
+            }) => self.build_document_content(document, *phase_num),
         }
     }
 
@@ -59,7 +74,7 @@ impl<'a> DetailPane<'a> {
                 "Core Value: ",
                 Style::default().fg(Color::Yellow),
             )]));
-            lines.push(Line::from(project.description.clone()));
+            lines.extend(render_markdown(&project.description).lines);
             lines.push(Line::from(""));
         }
 
@@ -68,7 +83,7 @@ impl<'a> DetailPane<'a> {
                 "Problem: ",
                 Style::default().fg(Color::Yellow),
             )]));
-            lines.push(Line::from(project.problem.clone()));
+            lines.extend(render_markdown(&project.problem).lines);
             lines.push(Line::from(""));
         }
 
@@ -77,7 +92,144 @@ impl<'a> DetailPane<'a> {
                 "Solution: ",
                 Style::default().fg(Color::Yellow),
             )]));
-            lines.push(Line::from(project.solution.clone()));
+            lines.extend(render_markdown(&project.solution).lines);
+            lines.push(Line::from(""));
+        }
+
+        lines.extend(self.build_state_lines());
+        Text::from(lines)
+    }
+
+    /// Where the project stands, from STATE.md
+    fn build_state_lines(&self) -> Vec<Line<'static>> {
+        let state = &self.data.state;
+        let label = Style::default().fg(Color::Yellow);
+        let mut lines = Vec::new();
+
+        if !state.milestone.is_empty() {
+            lines.push(Line::from(vec![
+                Span::styled("Milestone: ", label),
+                Span::raw(state.milestone.clone()),
+            ]));
+        }
+        if state.progress > 0 {
+            lines.push(Line::from(vec![
+                Span::styled("Progress: ", label),
+                Span::styled(
+                    gauge(state.progress as f32, 20),
+                    Style::default().fg(Color::Green),
+                ),
+                Span::raw(format!(" {}%", state.progress)),
+            ]));
+        }
+        if !state.last_activity.is_empty() {
+            let mut spans = vec![Span::styled("Last activity: ", label)];
+            spans.extend(render_inline(&state.last_activity));
+            lines.push(Line::from(spans));
+        }
+
+        if !state.blockers.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![Span::styled(
+                "Blockers:",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )]));
+            for blocker in &state.blockers {
+                let mut spans = vec![Span::raw("  - ")];
+                spans.extend(render_inline(blocker));
+                lines.push(Line::from(spans));
+            }
+        }
+
+        if !state.next_session.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![Span::styled(
+                "Context for Next Session:",
+                label,
+            )]));
+            lines.extend(render_markdown(&state.next_session).lines);
+        }
+
+        lines
+    }
+
+    fn build_milestone_content(&self, milestone: &Milestone, completion: f32) -> Text<'static> {
+        let label = Style::default().fg(Color::Yellow);
+        let status_color = milestone.status.color();
+
+        let mut lines = vec![
+            Line::from(vec![Span::styled(
+                milestone.title(),
+                Style::default().add_modifier(Modifier::BOLD),
+            )]),
+            Line::from(""),
+        ];
+
+        let mut status = vec![
+            Span::styled("Status: ", label),
+            Span::styled(milestone.status.label(), Style::default().fg(status_color)),
+        ];
+        if let Some(shipped) = &milestone.shipped {
+            status.push(Span::raw(format!(" ({})", shipped)));
+        }
+        lines.push(Line::from(status));
+        lines.push(Line::from(vec![
+            Span::styled("Progress: ", label),
+            Span::styled(gauge(completion, 20), Style::default().fg(status_color)),
+            Span::styled(
+                format!(" {:.0}%", completion),
+                Style::default().fg(status_color),
+            ),
+        ]));
+        if let Some(archive) = &milestone.archive {
+            lines.push(Line::from(vec![
+                Span::styled("Archive: ", label),
+                Span::styled(
+                    archive.display().to_string(),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::styled(" (read-only)", Style::default().fg(Color::DarkGray)),
+            ]));
+        }
+        if let Some(range) = &milestone.git_range {
+            lines.push(Line::from(vec![
+                Span::styled("Git range: ", label),
+                Span::raw(range.clone()),
+            ]));
+        }
+        lines.push(Line::from(""));
+
+        if !milestone.delivered.is_empty() {
+            lines.push(Line::from(vec![Span::styled("Delivered:", label)]));
+            lines.extend(render_markdown(&milestone.delivered).lines);
+            lines.push(Line::from(""));
+        }
+
+        lines.push(Line::from(vec![Span::styled("Phases:", label)]));
+        for phase in milestone.phases_in(&self.data.roadmap.phases) {
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(
+                    format!("{:3.0}%", phase.completion_percentage()),
+                    Style::default().fg(phase.status.color()),
+                ),
+                Span::raw(format!(" Phase {}: {}", phase.number, phase.name)),
+            ]));
+        }
+
+        for (title, items) in [
+            ("Key accomplishments:", &milestone.accomplishments),
+            ("Stats:", &milestone.stats),
+        ] {
+            if !items.is_empty() {
+                lines.push(Line::from(""));
+                lines.push(Line::from(vec![Span::styled(title, label)]));
+                for item in items {
+                    let mut spans = vec![Span::raw("  - ")];
+                    spans.extend(render_inline(item));
+                    lines.push(Line::from(spans));
+                }
+            }
         }
 
         Text::from(lines)
@@ -122,8 +274,29 @@ impl<'a> DetailPane<'a> {
             "Goal: ",
             Style::default().fg(Color::Yellow),
         )]));
-        lines.push(Line::from(phase.goal.clone()));
+        lines.extend(render_markdown(&phase.goal).lines);
         lines.push(Line::from(""));
+
+        // Resolved dependencies, or the prose as written when none resolved (e.g. "None")
+        if !phase.dependencies.is_empty() {
+            lines.push(Line::from(vec![Span::styled(
+                "Depends on:",
+                Style::default().fg(Color::Yellow),
+            )]));
+            for &number in &phase.dependencies {
+                lines.push(Line::from(format!("  {}", self.phase_label(number))));
+            }
+            lines.push(Line::from(""));
+        } else if !phase.dependencies_text.is_empty() {
+            let mut spans = vec![Span::styled(
+                "Depends on: ",
+                Style::default().fg(Color::Yellow),
+            )];
+            spans.extend(render_inline(&phase.dependencies_text));
+            lines.push(Line::from(spans));
+            lines.push(Line::from(""));
+        }
+
         lines.push(Line::from(vec![Span::styled(
             "Requirements:",
             Style::default().fg(Color::Yellow),
@@ -136,14 +309,15 @@ impl<'a> DetailPane<'a> {
                 crate::data::RequirementStatus::Pending => "[ ]",
             };
             let status_color = req.status.color();
-            lines.push(Line::from(vec![
+            let mut spans = vec![
                 Span::raw("  "),
                 Span::styled(status, Style::default().fg(status_color)),
                 Span::raw(" "),
                 Span::styled(req.id.clone(), Style::default().fg(Color::Cyan)),
                 Span::raw(": "),
-                Span::raw(req.description.clone()),
-            ]));
+            ];
+            spans.extend(render_inline(&req.description));
+            lines.push(Line::from(spans));
         }
 
         // Add completion stats
@@ -159,13 +333,218 @@ impl<'a> DetailPane<'a> {
             Span::raw(format!("{}/{} requirements complete", complete, total)),
         ]));
 
+        if !phase.success_criteria.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![Span::styled(
+                "Success Criteria:",
+                Style::default().fg(Color::Yellow),
+            )]));
+            for (i, criterion) in phase.success_criteria.iter().enumerate() {
+                let mut spans = vec![Span::raw(format!("  {}. ", i + 1))];
+                spans.extend(render_inline(criterion));
+                lines.push(Line::from(spans));
+            }
+        }
+
+        if !phase.plan_checklist.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![Span::styled(
+                "Plans:",
+                Style::default().fg(Color::Yellow),
+            )]));
+            for item in &phase.plan_checklist {
+                let (mark, color) = if item.checked {
+                    ("[x]", Color::Green)
+                } else {
+                    ("[ ]", Color::DarkGray)
+                };
+                let mut spans = vec![
+                    Span::raw("  "),
+                    Span::styled(mark, Style::default().fg(color)),
+                    Span::raw(" "),
+                    Span::styled(item.id.clone(), Style::default().fg(Color::Cyan)),
+                ];
+                if !item.description.is_empty() {
+                    spans.push(Span::raw(" — "));
+                    spans.extend(render_inline(&item.description));
+                }
+                lines.push(Line::from(spans));
+            }
+        }
+
+        if !phase.research_notes.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![Span::styled(
+                "Research Notes:",
+                Style::default().fg(Color::Yellow),
+            )]));
+            lines.extend(render_markdown(&phase.research_notes).lines);
+        }
+
+        if !phase.documents.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![Span::styled(
+                "Documents:",
+                Style::default().fg(Color::Yellow),
+            )]));
+            for document in &phase.documents {
+                lines.push(document_line(document));
+            }
+        }
+
+        // Sections elsewhere (e.g. research pitfalls) that mention this phase
+        let references: Vec<Line<'static>> = self
+            .data
+            .research
+            .iter()
+            .chain(
+                self.data
+                    .roadmap
+                    .phases
+                    .iter()
+                    .filter(|p| p.number != phase.number)
+                    .flat_map(|p| &p.documents),
+            )
+            .flat_map(|document| {
+                document.mentions_of(phase.number).map(move |mention| {
+                    let mut spans = vec![
+                        Span::raw("  "),
+                        Span::styled(document.file_name(), Style::default().fg(Color::Cyan)),
+                    ];
+                    if let Some(heading) = &mention.heading {
+                        spans.push(Span::raw(" › "));
+                        spans.extend(render_inline(heading));
+                    }
+                    spans.push(Span::styled(
+                        format!(" (line {})", mention.line),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                    Line::from(spans)
+                })
+            })
+            .collect();
+        if !references.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![Span::styled(
+                "Referenced in:",
+                Style::default().fg(Color::Yellow),
+            )]));
+            lines.extend(references);
+        }
+
+        Text::from(lines)
+    }
+
+    fn build_research_content(&self) -> Text<'static> {
+        let mut lines = vec![
+            Line::from(vec![Span::styled(
+                "Research",
+                Style::default().add_modifier(Modifier::BOLD),
+            )]),
+            Line::from(""),
+        ];
+        for document in &self.data.research {
+            lines.push(document_line(document));
+        }
+        Text::from(lines)
+    }
+
+    fn build_document_content(
+        &self,
+        document: &Document,
+        phase_num: Option<PhaseId>,
+    ) -> Text<'static> {
+        let label = Style::default().fg(Color::Yellow);
+
+        let mut lines = vec![
+            Line::from(vec![Span::styled(
+                document.title.clone(),
+                Style::default().add_modifier(Modifier::BOLD),
+            )]),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("Kind: ", label),
+                Span::raw(document.kind.label()),
+            ]),
+        ];
+        if let Some(number) = phase_num {
+            lines.push(Line::from(vec![
+                Span::styled("Phase: ", label),
+                Span::raw(self.phase_label(number)),
+            ]));
+        }
+        lines.push(Line::from(vec![
+            Span::styled("File: ", label),
+            Span::styled(document.file_name(), Style::default().fg(Color::Cyan)),
+        ]));
+
+        if !document.outline.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![Span::styled("Outline:", label)]));
+            for heading in &document.outline {
+                let indent = "  ".repeat(heading.level as usize);
+                let mut spans = vec![Span::raw(indent)];
+                spans.extend(render_inline(&heading.text));
+                spans.push(Span::styled(
+                    format!("  :{}", heading.line),
+                    Style::default().fg(Color::DarkGray),
+                ));
+                lines.push(Line::from(spans));
+            }
+        }
+
+        // Phases this document talks about, other than its own
+        let mut mentioned: Vec<PhaseId> = document
+            .mentions
+            .iter()
+            .map(|m| m.phase)
+            .filter(|&n| Some(n) != phase_num)
+            .collect();
+        mentioned.sort();
+        mentioned.dedup();
+        if !mentioned.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![Span::styled("Mentions:", label)]));
+            for number in mentioned {
+                lines.push(Line::from(format!("  {}", self.phase_label(number))));
+            }
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "─".repeat(40),
+            Style::default().fg(Color::DarkGray),
+        )));
+        lines.extend(render_markdown(&document.content).lines);
+
         Text::from(lines)
     }
 
+    /// "Phase N: Name" for a phase heading or checklist entry, or "Phase N" if unknown
+    fn phase_label(&self, number: PhaseId) -> String {
+        let roadmap = &self.data.roadmap;
+        let name = roadmap
+            .phases
+            .iter()
+            .find(|p| p.number == number)
+            .map(|p| p.name.as_str())
+            .or_else(|| {
+                roadmap
+                    .phase_checklist
+                    .iter()
+                    .find(|item| item.number == number)
+                    .map(|item| item.name.as_str())
+            });
+        match name {
+            Some(name) => format!("Phase {}: {}", number, name),
+            None => format!("Phase {}", number),
+        }
+    }
+
     fn build_requirement_content(
         &self,
         req: &crate::data::Requirement,
-        phase_num: u8,
+        phase_num: PhaseId,
     ) -> Text<'static> {
         let status_text = match req.status {
             crate::data::RequirementStatus::Complete => "Complete",
@@ -179,7 +558,7 @@ impl<'a> DetailPane<'a> {
             crate::data::RequirementStatus::Pending => Color::Gray,
         };
 
-        Text::from(vec![
+        let mut lines = vec![
             Line::from(vec![Span::styled(
                 req.id.clone(),
                 Style::default()
@@ -201,9 +580,284 @@ impl<'a> DetailPane<'a> {
                 "Description:",
                 Style::default().fg(Color::Yellow),
             )]),
-            Line::from(req.description.clone()),
-        ])
+        ];
+        lines.extend(render_markdown(&req.description).lines);
+
+        // Report disagreements between REQUIREMENTS.md checklist and traceability table
+        let conflicts: Vec<_> = self
+            .data
+            .requirements
+            .conflicts()
+            .into_iter()
+            .filter(|c| c.id() == req.id)
+            .collect();
+        if !conflicts.is_empty() {
+            lines.push(Line::from(""));
+            for conflict in conflicts {
+                lines.push(Line::from(vec![Span::styled(
+                    format!("Warning: {}", conflict),
+                    Style::default().fg(Color::Red),
+                )]));
+            }
+        }
+
+        Text::from(lines)
     }
+
+    fn build_plan_content(&self, plan: &crate::data::Plan, phase_num: PhaseId) -> Text<'static> {
+        let label = Style::default().fg(Color::Yellow);
+
+        let mut lines = vec![
+            Line::from(vec![Span::styled(
+                format!("Plan {}: {}", plan.id, plan.title()),
+                Style::default().add_modifier(Modifier::BOLD),
+            )]),
+            Line::from(""),
+        ];
+
+        let (status_text, status_color) = match &plan.summary {
+            Some(summary) => (
+                match &summary.status {
+                    Some(status) => format!("Executed (summary: {})", status),
+                    None => "Executed".to_string(),
+                },
+                Color::Green,
+            ),
+            None => ("Not executed (no SUMMARY.md)".to_string(), Color::DarkGray),
+        };
+        lines.push(Line::from(vec![
+            Span::styled("Status: ", label),
+            Span::styled(status_text, Style::default().fg(status_color)),
+        ]));
+        lines.push(Line::from(vec![
+            Span::styled("Phase: ", label),
+            Span::raw(format!("{}", phase_num)),
+        ]));
+
+        match &plan.frontmatter {
+            Some(fm) => {
+                lines.push(Line::from(vec![
+                    Span::styled("Wave: ", label),
+                    Span::raw(format!("{}", fm.wave)),
+                    Span::styled("  Type: ", label),
+                    Span::raw(fm.plan_type.clone()),
+                    Span::styled("  Autonomous: ", label),
+                    Span::raw(if fm.autonomous { "yes" } else { "no" }),
+                ]));
+                if !fm.depends_on.is_empty() {
+                    lines.push(Line::from(vec![
+                        Span::styled("Depends on: ", label),
+                        Span::raw(fm.depends_on.join(", ")),
+                    ]));
+                }
+            }
+            None => lines.push(Line::from(vec![Span::styled(
+                "Warning: no frontmatter",
+                Style::default().fg(Color::Red),
+            )])),
+        }
+        lines.push(Line::from(""));
+
+        if !plan.objective.is_empty() {
+            lines.push(Line::from(vec![Span::styled("Objective:", label)]));
+            lines.extend(render_markdown(&plan.objective).lines);
+            lines.push(Line::from(""));
+        }
+
+        if !plan.context.is_empty() {
+            lines.push(Line::from(vec![Span::styled("Context:", label)]));
+            for reference in &plan.context {
+                lines.push(Line::from(vec![
+                    Span::raw("  @"),
+                    Span::styled(reference.clone(), Style::default().fg(Color::Cyan)),
+                ]));
+            }
+            lines.push(Line::from(""));
+        }
+
+        let done = plan.tasks.iter().filter(|task| task.completed).count();
+        lines.push(Line::from(vec![
+            Span::styled("Tasks:", label),
+            Span::styled(
+                format!(" {}/{} done", done, plan.tasks.len()),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]));
+        for task in &plan.tasks {
+            lines.push(task_line(task));
+        }
+
+        if let Some(fm) = &plan.frontmatter {
+            if !fm.files_modified.is_empty() {
+                lines.push(Line::from(""));
+                lines.push(Line::from(vec![Span::styled("Files:", label)]));
+                for file in &fm.files_modified {
+                    lines.push(Line::from(vec![
+                        Span::raw("  "),
+                        Span::styled(file.clone(), Style::default().fg(Color::Cyan)),
+                    ]));
+                }
+            }
+            if !fm.must_haves.truths.is_empty() {
+                lines.push(Line::from(""));
+                lines.push(Line::from(vec![Span::styled("Must haves:", label)]));
+                for truth in &fm.must_haves.truths {
+                    lines.push(Line::from(format!("  - {}", truth)));
+                }
+            }
+        }
+
+        if let Some(checks) = &plan.must_have_checks {
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![
+                Span::styled("Must-have checks:", label),
+                Span::styled(
+                    format!(" {}/{} passed", checks.passed_count(), checks.checks.len()),
+                    Style::default().fg(if checks.passed() {
+                        Color::Green
+                    } else {
+                        Color::Red
+                    }),
+                ),
+            ]));
+            for check in &checks.checks {
+                let mut spans = vec![
+                    match check.passed() {
+                        true => Span::styled("  ✓ ", Style::default().fg(Color::Green)),
+                        false => Span::styled("  ✗ ", Style::default().fg(Color::Red)),
+                    },
+                    Span::styled(check.path.clone(), Style::default().fg(Color::Cyan)),
+                    Span::styled(
+                        format!(" {}", check.expectation),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                ];
+                if let Some(failure) = &check.failure {
+                    spans.push(Span::styled(
+                        format!(" — {}", failure),
+                        Style::default().fg(Color::Red),
+                    ));
+                }
+                lines.push(Line::from(spans));
+            }
+        }
+
+        for (title, body) in [
+            ("Verification:", &plan.verification),
+            ("Success criteria:", &plan.success_criteria),
+        ] {
+            if !body.is_empty() {
+                lines.push(Line::from(""));
+                lines.push(Line::from(vec![Span::styled(title, label)]));
+                lines.extend(render_markdown(body).lines);
+            }
+        }
+
+        Text::from(lines)
+    }
+
+    fn build_task_content(&self, task: &PlanTask, plan_id: &str) -> Text<'static> {
+        let label = Style::default().fg(Color::Yellow);
+
+        let mut lines = vec![
+            Line::from(vec![Span::styled(
+                task.name.clone(),
+                Style::default().add_modifier(Modifier::BOLD),
+            )]),
+            Line::from(""),
+        ];
+
+        let (status_text, status_color) = if task.completed {
+            ("Done (per SUMMARY.md)", Color::Green)
+        } else {
+            ("Not done", Color::DarkGray)
+        };
+        lines.push(Line::from(vec![
+            Span::styled("Status: ", label),
+            Span::styled(status_text, Style::default().fg(status_color)),
+        ]));
+        let type_style = if task.is_checkpoint() {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default()
+        };
+        lines.push(Line::from(vec![
+            Span::styled("Plan: ", label),
+            Span::raw(plan_id.to_string()),
+            Span::styled("  Type: ", label),
+            Span::styled(task.task_type.clone(), type_style),
+        ]));
+
+        if !task.files.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![
+                Span::styled("Files:", label),
+                Span::styled(" (f to open)", Style::default().fg(Color::DarkGray)),
+            ]));
+            for file in &task.files {
+                lines.push(Line::from(vec![
+                    Span::raw("  "),
+                    Span::styled(file.clone(), Style::default().fg(Color::Cyan)),
+                ]));
+            }
+        }
+
+        for (title, body) in [
+            ("Action:", &task.action),
+            ("Verify:", &task.verify),
+            ("Done when:", &task.done),
+        ] {
+            if !body.is_empty() {
+                lines.push(Line::from(""));
+                lines.push(Line::from(vec![Span::styled(title, label)]));
+                lines.extend(render_markdown(body).lines);
+            }
+        }
+
+        Text::from(lines)
+    }
+}
+
+/// "[x] Task 1: Name (type)" checklist row for a plan's task
+fn task_line(task: &PlanTask) -> Line<'static> {
+    let (status_icon, status_color) = if task.completed {
+        ("[x]", Color::Green)
+    } else {
+        ("[ ]", Color::DarkGray)
+    };
+    let type_color = if task.is_checkpoint() {
+        Color::Yellow
+    } else {
+        Color::DarkGray
+    };
+    Line::from(vec![
+        Span::raw("  "),
+        Span::styled(status_icon, Style::default().fg(status_color)),
+        Span::raw(" "),
+        Span::raw(task.name.clone()),
+        Span::styled(
+            format!(" ({})", task.task_type),
+            Style::default().fg(type_color),
+        ),
+    ])
+}
+
+/// "Kind: Title  (FILE.md, N headings)" summary of a document
+fn document_line(document: &Document) -> Line<'static> {
+    Line::from(vec![
+        Span::raw("  "),
+        Span::styled(document.kind.label(), Style::default().fg(Color::Blue)),
+        Span::raw(": "),
+        Span::raw(document.title.clone()),
+        Span::styled(
+            format!(
+                "  ({}, {} headings)",
+                document.file_name(),
+                document.outline.len()
+            ),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ])
 }
 
 impl Widget for DetailPane<'_> {
@@ -214,19 +868,12 @@ impl Widget for DetailPane<'_> {
             Style::default().fg(Color::DarkGray)
         };
 
-        let title: &str = match self.selected_item {
-            Some(TreeItem::Project(_)) => " Project Details ",
-            Some(TreeItem::Phase(p)) => {
-                // Use a static string since we can't create dynamic titles easily
-                match p.number {
-                    1 => " Phase 1 ",
-                    2 => " Phase 2 ",
-                    3 => " Phase 3 ",
-                    4 => " Phase 4 ",
-                    5 => " Phase 5 ",
-                    _ => " Phase ",
-                }
+        let title: String = match self.selected_item {
+            Some(TreeItem::Project(_)) => " Project Details ".to_string(),
+            Some(TreeItem::Milestone { milestone, .. }) => {
+                format!(" Milestone {} ", milestone.version)
             }
+            Some(TreeItem::Phase(p)) => format!(" Phase {} ", p.number),
             Some(TreeItem::Requirement { requirement, .. }) => {
                 // For simplicity, use a generic title
                 if requirement.id.starts_with("DISP") {
@@ -246,8 +893,13 @@ impl Widget for DetailPane<'_> {
                 } else {
                     " Requirement "
                 }
+                .to_string()
             }
-            None => " Details ",
+            Some(TreeItem::Plan { plan, .. }) => format!(" Plan {} ", plan.id),
+            Some(TreeItem::Task { plan_id, .. }) => format!(" Plan {} Task ", plan_id),
+            Some(TreeItem::Research(_)) => " Research ".to_string(),
+            Some(TreeItem::Document { document, .. }) => format!(" {} ", document.file_name()),
+            None => " Details ".to_string(),
         };
 
         let block = Block::default()
@@ -258,7 +910,7 @@ impl Widget for DetailPane<'_> {
         let content = self.build_content();
         let paragraph = Paragraph::new(content)
             .block(block)
-            .wrap(Wrap { trim: true })
+            .wrap(Wrap { trim: false })
             .scroll((self.scroll, 0));
 
         paragraph.render(area, buf);
@@ -0,0 +1,103 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget, Wrap},
+};
+
+use crate::components::popup_area;
+use crate::lint::{Diagnostic, Severity};
+
+/// Overlay listing lint diagnostics for the loaded .planning/ directory
+pub struct DiagnosticsPanel<'a> {
+    diagnostics: &'a [Diagnostic],
+    scroll: u16,
+}
+
+impl<'a> DiagnosticsPanel<'a> {
+    pub fn new(diagnostics: &'a [Diagnostic], scroll: u16) -> Self {
+        Self {
+            diagnostics,
+            scroll,
+        }
+    }
+}
+
+/// Color for a diagnostic severity
+pub fn severity_color(severity: Severity) -> Color {
+    match severity {
+        Severity::Error => Color::Red,
+        Severity::Warning => Color::Yellow,
+    }
+}
+
+impl DiagnosticsPanel<'_> {
+    /// Furthest the panel can scroll in `area` before its last line reaches the bottom
+    pub fn max_scroll(diagnostics: &[Diagnostic], area: Rect) -> u16 {
+        let popup_area = popup_area(area, 80, 70);
+        // line_count counts the borders' rows but wraps at the width it is given
+        let rows = Self::paragraph(diagnostics).line_count(popup_area.width.saturating_sub(2));
+        u16::try_from(rows)
+            .unwrap_or(u16::MAX)
+            .saturating_sub(popup_area.height)
+    }
+
+    fn paragraph(diagnostics: &[Diagnostic]) -> Paragraph<'static> {
+        let mut lines = Vec::new();
+        if diagnostics.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "No problems found in .planning/",
+                Style::default().fg(Color::Green),
+            )));
+        }
+
+        for diagnostic in diagnostics {
+            let location = match diagnostic.line {
+                Some(line) => format!("{}:{}", diagnostic.file.display(), line),
+                None => diagnostic.file.display().to_string(),
+            };
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{:<8}", diagnostic.severity.to_string()),
+                    Style::default()
+                        .fg(severity_color(diagnostic.severity))
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(location, Style::default().fg(Color::Cyan)),
+                Span::styled(
+                    format!("  [{}]", diagnostic.code),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]));
+            lines.push(Line::from(format!("        {}", diagnostic.message)));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "j/k to scroll, d or Esc to close",
+            Style::default().fg(Color::DarkGray),
+        )));
+
+        Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .title(format!(" Diagnostics ({}) ", diagnostics.len()))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan)),
+            )
+            .style(Style::default().bg(Color::Black))
+            .wrap(Wrap { trim: false })
+    }
+}
+
+impl Widget for DiagnosticsPanel<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let popup_area = popup_area(area, 80, 70);
+        Clear.render(popup_area, buf);
+
+        Self::paragraph(self.diagnostics)
+            .scroll((self.scroll, 0))
+            .render(popup_area, buf);
+    }
+}
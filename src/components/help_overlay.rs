@@ -1,11 +1,13 @@
 use ratatui::{
     buffer::Buffer,
-    layout::{Constraint, Flex, Layout, Rect},
+    layout::Rect,
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph, Widget},
 };
 
+use crate::components::popup_area;
+
 /// Help overlay showing all keybindings
 pub struct HelpOverlay;
 
@@ -13,15 +15,6 @@ impl HelpOverlay {
     pub fn new() -> Self {
         Self
     }
-
-    /// Calculate centered popup area
-    fn popup_area(area: Rect, percent_x: u16, percent_y: u16) -> Rect {
-        let vertical = Layout::vertical([Constraint::Percentage(percent_y)]).flex(Flex::Center);
-        let horizontal = Layout::horizontal([Constraint::Percentage(percent_x)]).flex(Flex::Center);
-        let [area] = vertical.areas(area);
-        let [area] = horizontal.areas(area);
-        area
-    }
 }
 
 impl Default for HelpOverlay {
@@ -32,7 +25,7 @@ impl Default for HelpOverlay {
 
 impl Widget for HelpOverlay {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let popup_area = Self::popup_area(area, 60, 70);
+        let popup_area = popup_area(area, 60, 70);
         Clear.render(popup_area, buf);
 
         let help_lines = vec![
@@ -50,20 +43,39 @@ impl Widget for HelpOverlay {
             Line::from("  h / Left      Collapse / Move to tree"),
             Line::from("  l / Right     Expand / Move to detail"),
             Line::from("  Enter         Toggle expand / Select"),
-            Line::from("  Tab           Switch pane focus"),
+            Line::from("  Tab           Switch pane focus (tree/detail/output)"),
             Line::from(""),
             Line::from(vec![Span::styled(
                 "Scrolling",
                 Style::default().add_modifier(Modifier::BOLD),
             )]),
-            Line::from("  PageUp        Scroll detail up"),
-            Line::from("  PageDown      Scroll detail down"),
+            Line::from("  PageUp        Scroll detail/output up"),
+            Line::from("  PageDown      Scroll detail/output down"),
+            Line::from(""),
+            Line::from(vec![Span::styled(
+                "Commands",
+                Style::default().add_modifier(Modifier::BOLD),
+            )]),
+            Line::from("  x             Run suggested command for selection"),
+            Line::from("  :             Command palette (Ctrl-Y copies)"),
+            Line::from("  Ctrl-C        Cancel running command"),
+            Line::from("  o             Toggle output pane"),
             Line::from(""),
             Line::from(vec![Span::styled(
                 "Actions",
                 Style::default().add_modifier(Modifier::BOLD),
             )]),
             Line::from("  /             Search / Filter"),
+            Line::from("  d             Lint diagnostics"),
+            Line::from("  D             Key decisions table"),
+            Line::from("  e             Open source in $EDITOR"),
+            Line::from("  f             Open the selected task's files"),
+            Line::from("  s             Kata settings (config.json)"),
+            Line::from("  p             Switch workspace project"),
+            Line::from("  a             Recent git activity"),
+            Line::from("  P             Progress over time"),
+            Line::from("  G             Dependency graph"),
+            Line::from("  w             Waves of the phase's plans"),
             Line::from("  ?             Toggle this help"),
             Line::from("  q / Esc       Quit (or close overlay)"),
             Line::from(""),
@@ -1,11 +1,44 @@
+pub mod activity_panel;
+pub mod blockers_banner;
+pub mod command_palette;
+pub mod decisions_view;
+pub mod dependency_graph;
 pub mod detail_pane;
+pub mod diagnostics_panel;
 pub mod help_overlay;
+pub mod output_pane;
+pub mod progress_chart;
+pub mod project_list;
 pub mod search_input;
+pub mod settings_view;
 pub mod status_bar;
 pub mod tree_view;
+pub mod wave_view;
 
+use ratatui::layout::{Constraint, Flex, Layout, Rect};
+
+pub use activity_panel::ActivityPanel;
+pub use blockers_banner::BlockersBanner;
+pub use command_palette::CommandPalette;
+pub use decisions_view::DecisionsView;
+pub use dependency_graph::DependencyGraphView;
 pub use detail_pane::DetailPane;
+pub use diagnostics_panel::DiagnosticsPanel;
 pub use help_overlay::HelpOverlay;
+pub use output_pane::OutputPane;
+pub use progress_chart::ProgressChart;
+pub use project_list::ProjectList;
 pub use search_input::SearchInput;
+pub use settings_view::SettingsView;
 pub use status_bar::StatusBar;
 pub use tree_view::TreeView;
+pub use wave_view::WaveView;
+
+/// Centered popup covering the given percentages of `area`, for overlays
+pub(crate) fn popup_area(area: Rect, percent_x: u16, percent_y: u16) -> Rect {
+    let vertical = Layout::vertical([Constraint::Percentage(percent_y)]).flex(Flex::Center);
+    let horizontal = Layout::horizontal([Constraint::Percentage(percent_x)]).flex(Flex::Center);
+    let [area] = vertical.areas(area);
+    let [area] = horizontal.areas(area);
+    area
+}
@@ -0,0 +1,157 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Widget},
+};
+
+use crate::runner::OutputStream;
+use crate::state::CommandOutputState;
+
+/// Output pane widget showing streamed command output and its exit status
+pub struct OutputPane<'a> {
+    output: &'a CommandOutputState,
+    focused: bool,
+}
+
+impl<'a> OutputPane<'a> {
+    pub fn new(output: &'a CommandOutputState, focused: bool) -> Self {
+        Self { output, focused }
+    }
+
+    /// Status badge for the title bar (running / exit code / cancelled)
+    fn status_span(&self) -> Span<'static> {
+        match &self.output.status {
+            None if self.output.running => {
+                Span::styled(" running ", Style::default().fg(Color::Yellow))
+            }
+            None => Span::raw(""),
+            Some(status) if status.success() => {
+                Span::styled(format!(" ✓ {} ", status), Style::default().fg(Color::Green))
+            }
+            Some(status) => {
+                Span::styled(format!(" ✗ {} ", status), Style::default().fg(Color::Red))
+            }
+        }
+    }
+}
+
+impl Widget for OutputPane<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let border_style = if self.focused {
+            Style::default().fg(Color::Cyan)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+
+        let title = if self.output.command.is_empty() {
+            " Output ".to_string()
+        } else {
+            format!(" Output: {} ", self.output.command)
+        };
+
+        let mut status = vec![self.status_span()];
+        if self.output.scroll_back > 0 {
+            status.insert(
+                0,
+                Span::styled(
+                    format!(" ↓ {} newer ", self.output.scroll_back),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            );
+        }
+
+        let block = Block::default()
+            .title(title)
+            .title(Line::from(status).right_aligned())
+            .borders(Borders::ALL)
+            .border_style(border_style);
+
+        // Show the window ending `scroll_back` lines above the newest output
+        let height = block.inner(area).height as usize;
+        let total = self.output.lines.len();
+        let end = total.saturating_sub(self.output.scroll_back);
+        let start = end.saturating_sub(height);
+
+        let lines: Vec<Line> = if total == 0 {
+            vec![Line::from(Span::styled(
+                "No output yet",
+                Style::default()
+                    .fg(Color::DarkGray)
+                    .add_modifier(Modifier::ITALIC),
+            ))]
+        } else {
+            self.output
+                .lines
+                .range(start..end)
+                .map(|(stream, text)| match stream {
+                    OutputStream::Stdout => Line::raw(text.clone()),
+                    OutputStream::Stderr => {
+                        Line::styled(text.clone(), Style::default().fg(Color::LightRed))
+                    }
+                })
+                .collect()
+        };
+
+        Paragraph::new(lines).block(block).render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::{CommandOutput, CommandStatus};
+
+    fn render(output: &CommandOutputState, width: u16, height: u16) -> Vec<String> {
+        let area = Rect::new(0, 0, width, height);
+        let mut buf = Buffer::empty(area);
+        OutputPane::new(output, false).render(area, &mut buf);
+        (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| buf[(x, y)].symbol().to_string())
+                    .collect::<String>()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_shows_tail_and_exit_status() {
+        let mut output = CommandOutputState::default();
+        output.start("kata verify".to_string());
+        for i in 1..=5 {
+            output.apply(CommandOutput::Line {
+                stream: OutputStream::Stdout,
+                text: format!("line {}", i),
+            });
+        }
+        output.apply(CommandOutput::Finished(CommandStatus::Exited(Some(2))));
+
+        let rows = render(&output, 40, 4);
+
+        assert!(rows[0].contains("Output: kata verify"));
+        assert!(rows[0].contains("exit 2"));
+        assert!(rows[1].contains("line 4"));
+        assert!(rows[2].contains("line 5"));
+    }
+
+    #[test]
+    fn test_scroll_back_shows_older_lines() {
+        let mut output = CommandOutputState::default();
+        output.start("kata".to_string());
+        for i in 1..=5 {
+            output.apply(CommandOutput::Line {
+                stream: OutputStream::Stdout,
+                text: format!("line {}", i),
+            });
+        }
+        output.scroll_up(3);
+
+        let rows = render(&output, 40, 4);
+
+        assert!(rows[0].contains("running"));
+        assert!(rows[1].contains("line 1"));
+        assert!(rows[2].contains("line 2"));
+    }
+}
@@ -0,0 +1,236 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    symbols::Marker,
+    text::{Line, Span},
+    widgets::{
+        Axis, Block, Borders, Chart, Clear, Dataset, GraphType, Paragraph, Sparkline, Widget, Wrap,
+    },
+};
+
+use crate::burnup::{Burnup, BurnupPoint, ProgressSample};
+use crate::components::popup_area;
+
+/// Below this many rows inside the popup, sparklines replace the chart
+const MIN_CHART_HEIGHT: u16 = 12;
+
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// Overlay charting requirements and phases complete over the project's git history
+pub struct ProgressChart<'a> {
+    burnup: &'a Burnup,
+}
+
+impl<'a> ProgressChart<'a> {
+    pub fn new(burnup: &'a Burnup) -> Self {
+        Self { burnup }
+    }
+
+    /// Line chart with days since the first commit on the x axis
+    fn render_chart(points: &[BurnupPoint], area: Rect, buf: &mut Buffer) {
+        let first = points[0].timestamp;
+        let series = |value: fn(&ProgressSample) -> f64| -> Vec<(f64, f64)> {
+            points
+                .iter()
+                .map(|p| {
+                    (
+                        (p.timestamp - first) as f64 / SECONDS_PER_DAY,
+                        value(&p.sample),
+                    )
+                })
+                .collect()
+        };
+        let requirements_total = series(|s| s.total_requirements as f64);
+        let requirements_complete = series(|s| s.requirements_complete as f64);
+        let phases_complete = series(|s| s.phases_complete as f64);
+
+        let days = requirements_total.last().map_or(0.0, |&(x, _)| x).max(1.0);
+        let top = points
+            .iter()
+            .map(|p| {
+                p.sample
+                    .total_requirements
+                    .max(p.sample.total_phases as u16)
+            })
+            .max()
+            .unwrap_or(0)
+            .max(1) as f64;
+
+        let datasets = vec![
+            Dataset::default()
+                .name("Requirements (scope)")
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(Color::DarkGray))
+                .data(&requirements_total),
+            Dataset::default()
+                .name("Requirements complete")
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(Color::Green))
+                .data(&requirements_complete),
+            Dataset::default()
+                .name("Phases complete")
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(Color::Cyan))
+                .data(&phases_complete),
+        ];
+
+        let axis_style = Style::default().fg(Color::DarkGray);
+        let last = &points[points.len() - 1];
+        let chart = Chart::new(datasets)
+            .x_axis(
+                Axis::default()
+                    .style(axis_style)
+                    .bounds([0.0, days])
+                    .labels([points[0].date.clone(), last.date.clone()]),
+            )
+            .y_axis(
+                Axis::default()
+                    .style(axis_style)
+                    .bounds([0.0, top])
+                    .labels(["0".to_string(), format!("{}", top)]),
+            )
+            .hidden_legend_constraints((Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)));
+        chart.render(area, buf);
+    }
+
+    /// Labelled sparklines, one value per commit, for small terminals
+    fn render_sparklines(points: &[BurnupPoint], area: Rect, buf: &mut Buffer) {
+        // Most recent commits when there are more than columns
+        let points = &points[points.len().saturating_sub(area.width as usize)..];
+        let last = points[points.len() - 1].sample;
+        let [req_label, req_line, phase_label, phase_line] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+            Constraint::Fill(1),
+        ])
+        .areas(area);
+
+        let requirements: Vec<u64> = points
+            .iter()
+            .map(|p| p.sample.requirements_complete as u64)
+            .collect();
+        let phases: Vec<u64> = points
+            .iter()
+            .map(|p| p.sample.phases_complete as u64)
+            .collect();
+
+        Line::from(format!(
+            "Requirements complete {}/{}",
+            last.requirements_complete, last.total_requirements
+        ))
+        .render(req_label, buf);
+        Sparkline::default()
+            .data(&requirements)
+            .max(
+                points
+                    .iter()
+                    .map(|p| p.sample.total_requirements as u64)
+                    .max()
+                    .unwrap_or(1)
+                    .max(1),
+            )
+            .style(Style::default().fg(Color::Green))
+            .render(req_line, buf);
+
+        Line::from(format!(
+            "Phases complete {}/{}",
+            last.phases_complete, last.total_phases
+        ))
+        .render(phase_label, buf);
+        Sparkline::default()
+            .data(&phases)
+            .max(
+                points
+                    .iter()
+                    .map(|p| p.sample.total_phases as u64)
+                    .max()
+                    .unwrap_or(1)
+                    .max(1),
+            )
+            .style(Style::default().fg(Color::Cyan))
+            .render(phase_line, buf);
+    }
+}
+
+/// "12/20 requirements, 2/5 phases complete (34 commits since 2026-01-05)"
+fn summary_line(points: &[BurnupPoint]) -> Line<'static> {
+    let last = points[points.len() - 1].sample;
+    Line::from(vec![
+        Span::styled(
+            format!(
+                "{}/{} requirements, {}/{} phases complete",
+                last.requirements_complete,
+                last.total_requirements,
+                last.phases_complete,
+                last.total_phases
+            ),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!(
+                "  ({} {} since {})",
+                points.len(),
+                if points.len() == 1 {
+                    "commit"
+                } else {
+                    "commits"
+                },
+                points[0].date
+            ),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ])
+}
+
+impl Widget for ProgressChart<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let popup_area = popup_area(area, 80, 70);
+        Clear.render(popup_area, buf);
+
+        let block = Block::default()
+            .title(" Progress ")
+            .title_bottom(" P or Esc to close ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::Black));
+        let inner = block.inner(popup_area);
+        block.render(popup_area, buf);
+
+        let points = &self.burnup.points;
+        if points.is_empty() {
+            let message = match &self.burnup.error {
+                _ if self.burnup.loading => Span::styled(
+                    "Reading git history...",
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Some(error) => Span::styled(
+                    format!("Could not read git history: {}", error),
+                    Style::default().fg(Color::Red),
+                ),
+                None => Span::styled(
+                    "No commits to STATE.md, REQUIREMENTS.md or ROADMAP.md yet",
+                    Style::default().fg(Color::DarkGray),
+                ),
+            };
+            Paragraph::new(Line::from(message))
+                .wrap(Wrap { trim: false })
+                .render(inner, buf);
+            return;
+        }
+
+        let [summary, body] =
+            Layout::vertical([Constraint::Length(2), Constraint::Fill(1)]).areas(inner);
+        summary_line(points).render(summary, buf);
+
+        if body.height < MIN_CHART_HEIGHT {
+            Self::render_sparklines(points, body, buf);
+        } else {
+            Self::render_chart(points, body, buf);
+        }
+    }
+}
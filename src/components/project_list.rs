@@ -0,0 +1,104 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Cell, Clear, Row, StatefulWidget, Table, TableState, Widget},
+};
+
+use crate::components::popup_area;
+use crate::state::ProjectListState;
+use crate::workspace::ProjectSummary;
+
+/// Overlay listing workspace projects with their STATE.md progress
+pub struct ProjectList<'a> {
+    list: &'a ProjectListState,
+}
+
+impl<'a> ProjectList<'a> {
+    pub fn new(list: &'a ProjectListState) -> Self {
+        Self { list }
+    }
+}
+
+/// "Phase 3 (2/5 done)", or just the count when STATE.md names no phase
+fn phase_text(project: &ProjectSummary) -> String {
+    let done = format!("{}/{} done", project.phases_complete, project.total_phases);
+    match project.current_phase {
+        Some(phase) => format!("Phase {} ({})", phase, done),
+        None => done,
+    }
+}
+
+/// Ten-cell progress bar with the percentage
+fn progress_text(progress: u8) -> String {
+    let filled = (progress.min(100) as usize + 5) / 10;
+    format!(
+        "{}{} {:3}%",
+        "█".repeat(filled),
+        "░".repeat(10 - filled),
+        progress
+    )
+}
+
+impl Widget for ProjectList<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let popup_area = popup_area(area, 80, 60);
+        Clear.render(popup_area, buf);
+
+        let block = Block::default()
+            .title(format!(" Projects ({}) ", self.list.projects.len()))
+            .title_bottom(" j/k move, Enter switch, Esc close ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::Black));
+        let inner = block.inner(popup_area);
+        block.render(popup_area, buf);
+
+        let rows = self
+            .list
+            .projects
+            .iter()
+            .enumerate()
+            .map(|(index, project)| {
+                let marker = if index == self.list.active {
+                    "●"
+                } else {
+                    " "
+                };
+                let progress_color = if project.progress >= 100 {
+                    Color::Green
+                } else {
+                    Color::Yellow
+                };
+                Row::new([
+                    Cell::from(marker).style(Style::default().fg(Color::Green)),
+                    Cell::from(project.name.clone())
+                        .style(Style::default().add_modifier(Modifier::BOLD)),
+                    Cell::from(phase_text(project)),
+                    Cell::from(progress_text(project.progress))
+                        .style(Style::default().fg(progress_color)),
+                    Cell::from(project.status.clone()).style(Style::default().fg(Color::Cyan)),
+                ])
+            });
+        let header = Row::new(["", "Project", "Phase", "Progress", "Status"]).style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        );
+        let widths = [
+            Constraint::Length(1),
+            Constraint::Fill(1),
+            Constraint::Length(20),
+            Constraint::Length(15),
+            Constraint::Length(14),
+        ];
+        let table = Table::new(rows, widths)
+            .header(header)
+            .column_spacing(2)
+            .highlight_style(Style::default().bg(Color::DarkGray))
+            .highlight_symbol("> ");
+
+        let mut table_state = TableState::default().with_selected(Some(self.list.selected));
+        StatefulWidget::render(table, inner, buf, &mut table_state);
+    }
+}
@@ -0,0 +1,143 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{
+        Block, Borders, Cell, Clear, Paragraph, Row, StatefulWidget, Table, TableState, Widget,
+        Wrap,
+    },
+};
+use serde_json::Value;
+
+use crate::components::popup_area;
+use crate::data::{Config, SettingKind, SETTINGS};
+
+/// Overlay table of Kata workflow settings from config.json
+pub struct SettingsView<'a> {
+    /// None when the project has no config.json (defaults are shown)
+    config: Option<&'a Config>,
+    selected: usize,
+}
+
+impl<'a> SettingsView<'a> {
+    pub fn new(config: Option<&'a Config>, selected: usize) -> Self {
+        Self { config, selected }
+    }
+
+    /// Lines below the table: the highlighted setting's choices, then file-wide problems
+    fn footer(&self) -> Vec<Line<'static>> {
+        let mut lines = Vec::new();
+        if let Some(setting) = SETTINGS.get(self.selected) {
+            let choices = match setting.kind {
+                SettingKind::Bool => "true, false".to_string(),
+                SettingKind::Choice(choices) => choices.join(", "),
+            };
+            lines.push(Line::from(vec![
+                Span::styled("Values: ", Style::default().fg(Color::Yellow)),
+                Span::raw(choices),
+            ]));
+            if let Some(issue) = self.config.and_then(|c| c.issue(setting.key)) {
+                lines.push(Line::from(Span::styled(
+                    issue.message.clone(),
+                    Style::default().fg(Color::Red),
+                )));
+            }
+        }
+
+        match self.config {
+            None => lines.push(Line::from(Span::styled(
+                "No config.json yet; changing a setting creates it",
+                Style::default().fg(Color::DarkGray),
+            ))),
+            Some(config) => {
+                for issue in config
+                    .issues
+                    .iter()
+                    .filter(|issue| issue.unknown || issue.key.is_empty())
+                {
+                    lines.push(Line::from(Span::styled(
+                        issue.message.clone(),
+                        Style::default().fg(Color::Yellow),
+                    )));
+                }
+            }
+        }
+        lines
+    }
+}
+
+/// A setting's value as shown in the table
+fn value_text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+impl Widget for SettingsView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let popup_area = popup_area(area, 80, 70);
+        Clear.render(popup_area, buf);
+
+        let block = Block::default()
+            .title(" Settings (config.json) ")
+            .title_bottom(" j/k move, Enter/Space change, Esc close ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::Black));
+        let inner = block.inner(popup_area);
+        block.render(popup_area, buf);
+
+        let footer = self.footer();
+        let [table_area, footer_area] = Layout::vertical([
+            Constraint::Min(0),
+            Constraint::Length(footer.len() as u16 + 1),
+        ])
+        .areas(inner);
+
+        let default_config = Config::default();
+        let config = self.config.unwrap_or(&default_config);
+        let rows = SETTINGS.iter().map(|setting| {
+            let value = config.value(setting.key);
+            let value_style = if config.issue(setting.key).is_some() {
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+            } else {
+                match value {
+                    Value::Bool(true) => Style::default().fg(Color::Green),
+                    Value::Bool(false) => Style::default().fg(Color::DarkGray),
+                    _ => Style::default().fg(Color::Magenta),
+                }
+            };
+            Row::new([
+                Cell::from(setting.key).style(Style::default().fg(Color::Cyan)),
+                Cell::from(value_text(&value)).style(value_style),
+                Cell::from(setting.description),
+            ])
+        });
+        let header = Row::new(["Setting", "Value", "Description"]).style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        );
+        let widths = [
+            Constraint::Length(20),
+            Constraint::Length(14),
+            Constraint::Fill(1),
+        ];
+        let table = Table::new(rows, widths)
+            .header(header)
+            .column_spacing(2)
+            .highlight_style(Style::default().bg(Color::DarkGray))
+            .highlight_symbol("> ");
+
+        let mut table_state = TableState::default().with_selected(Some(self.selected));
+        StatefulWidget::render(table, table_area, buf, &mut table_state);
+
+        let mut lines = vec![Line::from("")];
+        lines.extend(footer);
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: true })
+            .render(footer_area, buf);
+    }
+}
@@ -6,20 +6,31 @@ use ratatui::{
     widgets::{Paragraph, Widget},
 };
 
+use crate::components::diagnostics_panel::severity_color;
 use crate::data::PlanningState;
+use crate::lint::Diagnostic;
 use crate::state::FocusedPane;
 
 /// Status bar widget showing current state and keybinding hints
 pub struct StatusBar<'a> {
     state: &'a PlanningState,
     focused_pane: FocusedPane,
+    notice: Option<&'a str>,
+    diagnostics: &'a [Diagnostic],
 }
 
 impl<'a> StatusBar<'a> {
-    pub fn new(state: &'a PlanningState, focused_pane: FocusedPane) -> Self {
+    pub fn new(
+        state: &'a PlanningState,
+        focused_pane: FocusedPane,
+        notice: Option<&'a str>,
+        diagnostics: &'a [Diagnostic],
+    ) -> Self {
         Self {
             state,
             focused_pane,
+            notice,
+            diagnostics,
         }
     }
 }
@@ -29,23 +40,51 @@ impl Widget for StatusBar<'_> {
         let pane_indicator = match self.focused_pane {
             FocusedPane::Tree => "Tree",
             FocusedPane::Detail => "Detail",
+            FocusedPane::Output => "Output",
         };
 
-        let phase_info = if self.state.current_phase > 0 {
-            format!(
+        let phase_info = match self.state.current_phase {
+            Some(phase) => format!(
                 "Phase {} | {} | {}",
-                self.state.current_phase, self.state.current_phase_name, self.state.status
-            )
-        } else {
-            "No project loaded".to_string()
+                phase, self.state.current_phase_name, self.state.status
+            ),
+            None => "No project loaded".to_string(),
         };
 
-        let line = Line::from(vec![
+        // A notice (e.g. "Copied ...") temporarily replaces the keybinding hints
+        if let Some(notice) = self.notice {
+            let line = Line::from(vec![
+                Span::styled(
+                    format!(" {} ", pane_indicator),
+                    Style::default().fg(Color::Black).bg(Color::Cyan),
+                ),
+                Span::raw(" "),
+                Span::styled(notice.to_string(), Style::default().fg(Color::Yellow)),
+            ]);
+            Paragraph::new(line)
+                .style(Style::default().bg(Color::DarkGray))
+                .render(area, buf);
+            return;
+        }
+
+        let mut spans = vec![
             Span::styled(
                 format!(" {} ", pane_indicator),
                 Style::default().fg(Color::Black).bg(Color::Cyan),
             ),
             Span::raw(" "),
+        ];
+
+        // Lint badge, colored by the worst severity
+        if let Some(worst) = self.diagnostics.iter().map(|d| d.severity).max() {
+            spans.push(Span::styled(
+                format!("⚠ {}", self.diagnostics.len()),
+                Style::default().fg(severity_color(worst)),
+            ));
+            spans.push(Span::raw(" "));
+        }
+
+        spans.extend([
             Span::styled(phase_info, Style::default().fg(Color::White)),
             Span::raw(" | "),
             Span::styled("q", Style::default().fg(Color::Yellow)),
@@ -54,7 +93,14 @@ impl Widget for StatusBar<'_> {
             Span::raw(":nav "),
             Span::styled("Tab", Style::default().fg(Color::Yellow)),
             Span::raw(":switch "),
+            Span::styled("x", Style::default().fg(Color::Yellow)),
+            Span::raw(":run "),
+            Span::styled(":", Style::default().fg(Color::Yellow)),
+            Span::raw(":commands "),
+            Span::styled("d", Style::default().fg(Color::Yellow)),
+            Span::raw(":lint "),
         ]);
+        let line = Line::from(spans);
 
         let paragraph = Paragraph::new(line).style(Style::default().bg(Color::DarkGray));
 
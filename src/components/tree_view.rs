@@ -1,4 +1,5 @@
 use std::collections::HashSet;
+use std::path::PathBuf;
 
 use ratatui::{
     buffer::Buffer,
@@ -8,22 +9,93 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, ListState, StatefulWidget},
 };
 
-use crate::data::{Phase, PlanningData, Requirement};
+use crate::data::{Document, Milestone, Phase, PhaseId, Plan, PlanTask, PlanningData, Requirement};
 
 /// Tree item types for the hierarchical view
 #[derive(Debug, Clone)]
 pub enum TreeItem {
     Project(String),
+    Milestone {
+        milestone: Box<Milestone>,
+        /// Completion percentage across the milestone's phases
+        completion: f32,
+    },
     Phase(Phase),
     Requirement {
-        phase_num: u8,
+        phase_num: PhaseId,
         requirement: Requirement,
     },
+    Plan {
+        phase_num: PhaseId,
+        plan: Box<Plan>,
+    },
+    /// A `<task>` of an expanded plan
+    Task {
+        phase_num: PhaseId,
+        /// ID of the owning plan (e.g., "02-01")
+        plan_id: String,
+        /// Position among the plan's tasks
+        index: usize,
+        task: Box<PlanTask>,
+    },
+    /// Top-level node for the `research/` documents, with their count
+    Research(usize),
+    Document {
+        /// Owning phase, or None for `research/` documents
+        phase_num: Option<PhaseId>,
+        document: Box<Document>,
+    },
+}
+
+/// Stable identity of a tree item
+///
+/// Flat indices shift whenever the tree is rebuilt; keys survive reloads so
+/// selection and search position can be restored.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TreeItemKey {
+    Project,
+    Milestone(String),
+    Phase(PhaseId),
+    Requirement { phase_num: PhaseId, id: String },
+    Plan { phase_num: PhaseId, id: String },
+    Task { plan_id: String, index: usize },
+    Research,
+    Document(PathBuf),
 }
 
 impl TreeItem {
+    /// Get the stable identity of this item
+    pub fn key(&self) -> TreeItemKey {
+        match self {
+            TreeItem::Project(_) => TreeItemKey::Project,
+            TreeItem::Milestone { milestone, .. } => {
+                TreeItemKey::Milestone(milestone.version.clone())
+            }
+            TreeItem::Phase(phase) => TreeItemKey::Phase(phase.number),
+            TreeItem::Requirement {
+                phase_num,
+                requirement,
+            } => TreeItemKey::Requirement {
+                phase_num: *phase_num,
+                id: requirement.id.clone(),
+            },
+            TreeItem::Plan {
+                phase_num, plan, ..
+            } => TreeItemKey::Plan {
+                phase_num: *phase_num,
+                id: plan.id.clone(),
+            },
+            TreeItem::Task { plan_id, index, .. } => TreeItemKey::Task {
+                plan_id: plan_id.clone(),
+                index: *index,
+            },
+            TreeItem::Research(_) => TreeItemKey::Research,
+            TreeItem::Document { document, .. } => TreeItemKey::Document(document.path.clone()),
+        }
+    }
+
     /// Get the phase number if this item is a Phase
-    pub fn phase_number(&self) -> Option<u8> {
+    pub fn phase_number(&self) -> Option<PhaseId> {
         match self {
             TreeItem::Phase(phase) => Some(phase.number),
             _ => None,
@@ -32,18 +104,54 @@ impl TreeItem {
 
     /// Convert to a ratatui ListItem for rendering
     pub fn to_list_item(&self) -> ListItem<'static> {
+        ListItem::new(self.to_line())
+    }
+
+    /// Render as a single line, without tree-view expand indicators
+    pub fn to_line(&self) -> Line<'static> {
         match self {
-            TreeItem::Project(name) => ListItem::new(Line::from(vec![
+            TreeItem::Project(name) => Line::from(vec![
                 Span::styled("  ", Style::default()),
                 Span::styled(name.clone(), Style::default().add_modifier(Modifier::BOLD)),
-            ])),
+            ]),
+            TreeItem::Milestone {
+                milestone,
+                completion,
+                ..
+            } => {
+                let mut spans = vec![
+                    Span::raw("  "),
+                    Span::styled(
+                        milestone.title(),
+                        Style::default()
+                            .fg(milestone.status.color())
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::raw(" "),
+                    Span::styled(
+                        gauge(*completion, 10),
+                        Style::default().fg(milestone.status.color()),
+                    ),
+                    Span::styled(
+                        format!(" {:3.0}%", completion),
+                        Style::default().fg(milestone.status.color()),
+                    ),
+                ];
+                if milestone.is_archived() {
+                    spans.push(Span::styled(
+                        " (archived)",
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+                Line::from(spans)
+            }
             TreeItem::Phase(phase) => {
                 let status_icon = match phase.status {
                     crate::data::PhaseStatus::Complete => "[x]",
                     crate::data::PhaseStatus::InProgress => "[~]",
                     crate::data::PhaseStatus::Pending => "[ ]",
                 };
-                ListItem::new(Line::from(vec![
+                let mut spans = vec![
                     Span::raw("  "),
                     Span::styled(status_icon, Style::default().fg(phase.status.color())),
                     Span::raw(" "),
@@ -51,7 +159,9 @@ impl TreeItem {
                         format!("Phase {}: {}", phase.number, phase.name),
                         Style::default().add_modifier(Modifier::BOLD),
                     ),
-                ]))
+                ];
+                spans.extend(must_haves_badge(phase.must_haves_passed()));
+                Line::from(spans)
             }
             TreeItem::Requirement { requirement, .. } => {
                 let status_icon = match requirement.status {
@@ -59,19 +169,86 @@ impl TreeItem {
                     crate::data::RequirementStatus::InProgress => "[~]",
                     crate::data::RequirementStatus::Pending => "[ ]",
                 };
-                ListItem::new(Line::from(vec![
+                Line::from(vec![
                     Span::raw("    "),
                     Span::styled(status_icon, Style::default().fg(requirement.status.color())),
                     Span::raw(" "),
                     Span::styled(requirement.id.clone(), Style::default().fg(Color::Cyan)),
                     Span::raw(": "),
                     Span::raw(truncate_text(&requirement.description, 30)),
-                ]))
+                ])
+            }
+            TreeItem::Plan { plan, .. } => {
+                let (status_icon, status_color) = if plan.is_complete() {
+                    ("[x]", Color::Green)
+                } else {
+                    ("[ ]", Color::DarkGray)
+                };
+                let mut spans = vec![
+                    Span::raw("    "),
+                    Span::styled(status_icon, Style::default().fg(status_color)),
+                    Span::raw(" "),
+                    Span::styled(plan.id.clone(), Style::default().fg(Color::Magenta)),
+                    Span::raw(": "),
+                    Span::raw(truncate_text(plan.title(), 30)),
+                ];
+                spans.extend(must_haves_badge(
+                    plan.must_have_checks.as_ref().map(|checks| checks.passed()),
+                ));
+                Line::from(spans)
+            }
+            TreeItem::Task { task, .. } => {
+                let (status_icon, status_color) = if task.completed {
+                    ("[x]", Color::Green)
+                } else {
+                    ("[ ]", Color::DarkGray)
+                };
+                let mut spans = vec![
+                    Span::raw("      "),
+                    Span::styled(status_icon, Style::default().fg(status_color)),
+                    Span::raw(" "),
+                    Span::raw(truncate_text(&task.name, 34)),
+                ];
+                if task.is_checkpoint() {
+                    spans.push(Span::styled(" ⏸", Style::default().fg(Color::Yellow)));
+                }
+                Line::from(spans)
             }
+            TreeItem::Research(count) => Line::from(vec![
+                Span::raw("  "),
+                Span::styled("Research", Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(
+                    format!(" ({})", count),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]),
+            TreeItem::Document { document, .. } => Line::from(vec![
+                Span::raw("    "),
+                Span::styled("[≡]", Style::default().fg(Color::Blue)),
+                Span::raw(" "),
+                Span::styled(document.kind.label(), Style::default().fg(Color::Blue)),
+                Span::raw(": "),
+                Span::raw(truncate_text(&document.title, 30)),
+            ]),
         }
     }
 }
 
+/// Pass/fail badge for must_haves checks, if any were run
+fn must_haves_badge(passed: Option<bool>) -> Option<Span<'static>> {
+    match passed? {
+        true => Some(Span::styled(" ✓", Style::default().fg(Color::Green))),
+        false => Some(Span::styled(" ✗", Style::default().fg(Color::Red))),
+    }
+}
+
+/// Progress bar of `width` cells for a 0-100 percentage
+pub fn gauge(percentage: f32, width: usize) -> String {
+    let filled = ((percentage / 100.0) * width as f32).round() as usize;
+    let filled = filled.min(width);
+    format!("{}{}", "█".repeat(filled), "░".repeat(width - filled))
+}
+
 /// Truncate text to max_len characters with ellipsis (UTF-8 safe)
 fn truncate_text(text: &str, max_len: usize) -> String {
     let char_count = text.chars().count();
@@ -87,38 +264,123 @@ fn truncate_text(text: &str, max_len: usize) -> String {
 }
 
 /// Build flat list of tree items from planning data, respecting expand state
-pub fn build_tree_items(data: &PlanningData, expanded: &HashSet<u8>) -> Vec<TreeItem> {
+///
+/// With milestones, phases are grouped under them (Milestone → Phase →
+/// Requirement/Plan/Document); phases outside every milestone are listed first.
+/// `research/` documents sit under a Research node right after the project.
+pub fn build_tree_items(
+    data: &PlanningData,
+    expanded: &HashSet<PhaseId>,
+    expanded_plans: &HashSet<String>,
+    collapsed_milestones: &HashSet<String>,
+    research_expanded: bool,
+) -> Vec<TreeItem> {
     let mut items = Vec::new();
+    let roadmap = &data.roadmap;
 
     // Add project as root
     if !data.project.name.is_empty() {
         items.push(TreeItem::Project(data.project.name.clone()));
     }
 
-    // Add phases and their requirements (only if expanded)
-    for phase in &data.roadmap.phases {
-        items.push(TreeItem::Phase(phase.clone()));
-
-        // Only include requirements if this phase is expanded
-        if expanded.contains(&phase.number) {
-            for req in &phase.requirements {
-                items.push(TreeItem::Requirement {
-                    phase_num: phase.number,
-                    requirement: req.clone(),
+    if !data.research.is_empty() {
+        items.push(TreeItem::Research(data.research.len()));
+        if research_expanded {
+            for document in &data.research {
+                items.push(TreeItem::Document {
+                    phase_num: None,
+                    document: Box::new(document.clone()),
                 });
             }
         }
     }
 
+    let ungrouped = roadmap.phases.iter().filter(|phase| {
+        !roadmap
+            .milestones
+            .iter()
+            .any(|m| m.phases.contains(&phase.number))
+    });
+    for phase in ungrouped {
+        push_phase(&mut items, phase, expanded, expanded_plans);
+    }
+
+    for milestone in &roadmap.milestones {
+        items.push(TreeItem::Milestone {
+            milestone: Box::new(milestone.clone()),
+            completion: milestone.completion_percentage(&roadmap.phases),
+        });
+
+        if !collapsed_milestones.contains(&milestone.version) {
+            for phase in milestone.phases_in(&roadmap.phases) {
+                push_phase(&mut items, phase, expanded, expanded_plans);
+            }
+        }
+    }
+
     items
 }
 
-/// Get set of phase numbers that have requirements
-pub fn phases_with_requirements(data: &PlanningData) -> HashSet<u8> {
+/// Add a phase and, if expanded, its requirements, plans (with tasks of expanded plans)
+/// and documents
+fn push_phase(
+    items: &mut Vec<TreeItem>,
+    phase: &Phase,
+    expanded: &HashSet<PhaseId>,
+    expanded_plans: &HashSet<String>,
+) {
+    items.push(TreeItem::Phase(phase.clone()));
+
+    // Only include children if this phase is expanded
+    if expanded.contains(&phase.number) {
+        for req in &phase.requirements {
+            items.push(TreeItem::Requirement {
+                phase_num: phase.number,
+                requirement: req.clone(),
+            });
+        }
+        for plan in &phase.plans {
+            items.push(TreeItem::Plan {
+                phase_num: phase.number,
+                plan: Box::new(plan.clone()),
+            });
+            if expanded_plans.contains(&plan.id) {
+                for (index, task) in plan.tasks.iter().enumerate() {
+                    items.push(TreeItem::Task {
+                        phase_num: phase.number,
+                        plan_id: plan.id.clone(),
+                        index,
+                        task: Box::new(task.clone()),
+                    });
+                }
+            }
+        }
+        for document in &phase.documents {
+            items.push(TreeItem::Document {
+                phase_num: Some(phase.number),
+                document: Box::new(document.clone()),
+            });
+        }
+    }
+}
+
+/// Find the index of the item with the given key
+pub fn find_item_index(items: &[TreeItem], key: &TreeItemKey) -> Option<usize> {
+    items.iter().position(|item| &item.key() == key)
+}
+
+/// Get set of phase numbers that have children (requirements, plans or documents)
+pub fn phases_with_children(data: &PlanningData) -> HashSet<PhaseId> {
+    let archived = data
+        .roadmap
+        .milestones
+        .iter()
+        .flat_map(|m| &m.archived_phases);
     data.roadmap
         .phases
         .iter()
-        .filter(|p| !p.requirements.is_empty())
+        .chain(archived)
+        .filter(|p| !p.requirements.is_empty() || !p.plans.is_empty() || !p.documents.is_empty())
         .map(|p| p.number)
         .collect()
 }
@@ -127,27 +389,36 @@ pub fn phases_with_requirements(data: &PlanningData) -> HashSet<u8> {
 pub struct TreeView<'a> {
     items: &'a [TreeItem],
     focused: bool,
-    expanded: &'a HashSet<u8>,
-    phases_with_children: &'a HashSet<u8>,
+    expanded: &'a HashSet<PhaseId>,
+    expanded_plans: &'a HashSet<String>,
+    phases_with_children: &'a HashSet<PhaseId>,
+    collapsed_milestones: &'a HashSet<String>,
+    research_expanded: bool,
 }
 
 impl<'a> TreeView<'a> {
     pub fn new(
         items: &'a [TreeItem],
         focused: bool,
-        expanded: &'a HashSet<u8>,
-        phases_with_children: &'a HashSet<u8>,
+        expanded: &'a HashSet<PhaseId>,
+        expanded_plans: &'a HashSet<String>,
+        phases_with_children: &'a HashSet<PhaseId>,
+        collapsed_milestones: &'a HashSet<String>,
+        research_expanded: bool,
     ) -> Self {
         Self {
             items,
             focused,
             expanded,
+            expanded_plans,
             phases_with_children,
+            collapsed_milestones,
+            research_expanded,
         }
     }
 
     /// Get the expand indicator for a phase
-    fn expand_icon(&self, phase_num: u8) -> &'static str {
+    fn expand_icon(&self, phase_num: PhaseId) -> &'static str {
         if !self.phases_with_children.contains(&phase_num) {
             "  " // No children, no indicator
         } else if self.expanded.contains(&phase_num) {
@@ -173,45 +444,21 @@ impl StatefulWidget for TreeView<'_> {
             .borders(Borders::ALL)
             .border_style(border_style);
 
-        // Build list items with expand awareness for phases
+        // Items after the first milestone are nested one level under it
+        let mut nested = false;
+
+        // Build list items with expand awareness for milestones and phases
         let list_items: Vec<ListItem> = self
             .items
             .iter()
-            .map(|item| match item {
-                TreeItem::Phase(phase) => {
-                    let status_icon = match phase.status {
-                        crate::data::PhaseStatus::Complete => "[x]",
-                        crate::data::PhaseStatus::InProgress => "[~]",
-                        crate::data::PhaseStatus::Pending => "[ ]",
-                    };
-                    let expand_icon = self.expand_icon(phase.number);
-
-                    // Progress percentage with color coding
-                    let percentage = phase.completion_percentage();
-                    let progress_color = if percentage >= 100.0 {
-                        Color::Green
-                    } else if percentage > 0.0 {
-                        Color::Yellow
-                    } else {
-                        Color::DarkGray
-                    };
-
-                    ListItem::new(Line::from(vec![
-                        Span::raw(expand_icon),
-                        Span::styled(status_icon, Style::default().fg(phase.status.color())),
-                        Span::raw(" "),
-                        Span::styled(
-                            format!("Phase {}: {}", phase.number, phase.name),
-                            Style::default().add_modifier(Modifier::BOLD),
-                        ),
-                        Span::raw(" "),
-                        Span::styled(
-                            format!("[{:3.0}%]", percentage),
-                            Style::default().fg(progress_color),
-                        ),
-                    ]))
+            .map(|item| {
+                let mut line = self.item_line(item);
+                if matches!(item, TreeItem::Milestone { .. }) {
+                    nested = true;
+                } else if nested {
+                    line.spans.insert(0, Span::raw("  "));
                 }
-                _ => item.to_list_item(),
+                ListItem::new(line)
             })
             .collect();
 
@@ -228,11 +475,86 @@ impl StatefulWidget for TreeView<'_> {
     }
 }
 
+impl TreeView<'_> {
+    /// Line for an item, with expand indicators for milestones, phases, plans and research
+    fn item_line(&self, item: &TreeItem) -> Line<'static> {
+        match item {
+            TreeItem::Research(_) => {
+                let mut line = item.to_line();
+                line.spans[0] = Span::raw(if self.research_expanded {
+                    "▼ "
+                } else {
+                    "▶ "
+                });
+                line
+            }
+            TreeItem::Milestone { milestone, .. } => {
+                let mut line = item.to_line();
+                line.spans[0] =
+                    Span::raw(if self.collapsed_milestones.contains(&milestone.version) {
+                        "▶ "
+                    } else {
+                        "▼ "
+                    });
+                line
+            }
+            TreeItem::Phase(phase) => {
+                let status_icon = match phase.status {
+                    crate::data::PhaseStatus::Complete => "[x]",
+                    crate::data::PhaseStatus::InProgress => "[~]",
+                    crate::data::PhaseStatus::Pending => "[ ]",
+                };
+                let expand_icon = self.expand_icon(phase.number);
+
+                // Progress percentage with color coding
+                let percentage = phase.completion_percentage();
+                let progress_color = if percentage >= 100.0 {
+                    Color::Green
+                } else if percentage > 0.0 {
+                    Color::Yellow
+                } else {
+                    Color::DarkGray
+                };
+
+                Line::from(vec![
+                    Span::raw(expand_icon),
+                    Span::styled(status_icon, Style::default().fg(phase.status.color())),
+                    Span::raw(" "),
+                    Span::styled(
+                        format!("Phase {}: {}", phase.number, phase.name),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ),
+                    Span::raw(" "),
+                    Span::styled(
+                        format!("[{:3.0}%]", percentage),
+                        Style::default().fg(progress_color),
+                    ),
+                ])
+            }
+            TreeItem::Plan { plan, .. } if !plan.tasks.is_empty() => {
+                let mut line = item.to_line();
+                let expanded = self.expanded_plans.contains(&plan.id);
+                line.spans[0] = Span::raw(if expanded { "  ▼ " } else { "  ▶ " });
+                line
+            }
+            _ => item.to_line(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::data::{PhaseStatus, Project, Requirement, RequirementStatus, Roadmap};
 
+    fn mock_plan(id: &str) -> Plan {
+        Plan {
+            id: id.to_string(),
+            objective: "Do the thing".to_string(),
+            ..Default::default()
+        }
+    }
+
     fn mock_data() -> PlanningData {
         PlanningData {
             project: Project {
@@ -242,24 +564,29 @@ mod tests {
             roadmap: Roadmap {
                 phases: vec![
                     Phase {
-                        number: 1,
+                        number: PhaseId::new(1),
                         name: "Phase One".to_string(),
                         goal: "Test goal".to_string(),
                         requirements: vec![Requirement {
                             id: "REQ-01".to_string(),
                             description: "Test req".to_string(),
                             status: RequirementStatus::Pending,
+                            span: None,
                         }],
                         status: PhaseStatus::Pending,
                         dependencies: vec![],
+                        plans: vec![],
+                        ..Default::default()
                     },
                     Phase {
-                        number: 2,
+                        number: PhaseId::new(2),
                         name: "Phase Two".to_string(),
                         goal: "Test goal 2".to_string(),
                         requirements: vec![],
                         status: PhaseStatus::Pending,
                         dependencies: vec![],
+                        plans: vec![],
+                        ..Default::default()
                     },
                 ],
                 ..Default::default()
@@ -273,7 +600,7 @@ mod tests {
         let data = mock_data();
         let expanded = HashSet::new(); // All collapsed
 
-        let items = build_tree_items(&data, &expanded);
+        let items = build_tree_items(&data, &expanded, &HashSet::new(), &HashSet::new(), false);
 
         // Should have: Project + 2 Phases = 3 items (no requirements)
         assert_eq!(items.len(), 3);
@@ -283,22 +610,209 @@ mod tests {
     fn test_build_tree_expanded_shows_requirements() {
         let data = mock_data();
         let mut expanded = HashSet::new();
-        expanded.insert(1); // Expand phase 1
+        expanded.insert(PhaseId::new(1)); // Expand phase 1
 
-        let items = build_tree_items(&data, &expanded);
+        let items = build_tree_items(&data, &expanded, &HashSet::new(), &HashSet::new(), false);
 
         // Should have: Project + Phase1 + Req + Phase2 = 4 items
         assert_eq!(items.len(), 4);
     }
 
     #[test]
-    fn test_phases_with_requirements() {
+    fn test_find_item_index_by_key() {
         let data = mock_data();
+        let mut expanded = HashSet::new();
+        expanded.insert(PhaseId::new(1));
+
+        let items = build_tree_items(&data, &expanded, &HashSet::new(), &HashSet::new(), false);
+        let key = TreeItemKey::Requirement {
+            phase_num: PhaseId::new(1),
+            id: "REQ-01".to_string(),
+        };
 
-        let phases = phases_with_requirements(&data);
+        assert_eq!(find_item_index(&items, &key), Some(2));
+        assert_eq!(
+            find_item_index(&items, &TreeItemKey::Phase(PhaseId::new(2))),
+            Some(3)
+        );
+        assert_eq!(
+            find_item_index(&items, &TreeItemKey::Phase(PhaseId::new(9))),
+            None
+        );
+    }
+
+    #[test]
+    fn test_build_tree_expanded_shows_plans_after_requirements() {
+        let mut data = mock_data();
+        data.roadmap.phases[0].plans = vec![mock_plan("01-01"), mock_plan("01-02")];
+        let mut expanded = HashSet::new();
+        expanded.insert(PhaseId::new(1));
+
+        let items = build_tree_items(&data, &expanded, &HashSet::new(), &HashSet::new(), false);
+
+        // Project + Phase1 + Req + 2 Plans + Phase2 = 6 items
+        assert_eq!(items.len(), 6);
+        assert_eq!(
+            items[3].key(),
+            TreeItemKey::Plan {
+                phase_num: PhaseId::new(1),
+                id: "01-01".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_build_tree_expanded_plan_shows_tasks() {
+        let mut data = mock_data();
+        let mut plan = mock_plan("01-01");
+        // Same-named tasks are still told apart
+        plan.tasks = ["Verify", "Verify"]
+            .iter()
+            .map(|name| PlanTask {
+                name: name.to_string(),
+                ..Default::default()
+            })
+            .collect();
+        data.roadmap.phases[0].plans = vec![plan];
+        let expanded = HashSet::from([PhaseId::new(1)]);
+        let expanded_plans = HashSet::from(["01-01".to_string()]);
+
+        let items = build_tree_items(&data, &expanded, &expanded_plans, &HashSet::new(), false);
+
+        // Project + Phase1 + Req + Plan + 2 Tasks + Phase2 = 7 items
+        assert_eq!(items.len(), 7);
+        assert_ne!(items[4].key(), items[5].key());
+        assert_eq!(
+            items[5].key(),
+            TreeItemKey::Task {
+                plan_id: "01-01".to_string(),
+                index: 1
+            }
+        );
+
+        // Expansion follows the plan when one is added before it
+        data.roadmap.phases[0].plans.insert(0, mock_plan("01-00"));
+        let items = build_tree_items(&data, &expanded, &expanded_plans, &HashSet::new(), false);
+        assert_eq!(items.len(), 8);
+        assert_eq!(
+            items[6].key(),
+            TreeItemKey::Task {
+                plan_id: "01-01".to_string(),
+                index: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_build_tree_groups_phases_under_milestones() {
+        let mut data = mock_data();
+        data.roadmap.milestones = vec![
+            Milestone {
+                version: "v0.1".to_string(),
+                archived_phases: vec![Phase {
+                    number: PhaseId::new(0),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+            Milestone {
+                version: "v1.0".to_string(),
+                phases: vec![PhaseId::new(2)],
+                ..Default::default()
+            },
+        ];
+
+        let items = build_tree_items(
+            &data,
+            &HashSet::new(),
+            &HashSet::new(),
+            &HashSet::new(),
+            false,
+        );
+        let keys: Vec<TreeItemKey> = items.iter().map(TreeItem::key).collect();
+
+        // Ungrouped phase 1 first, then each milestone with its phases
+        assert_eq!(
+            keys,
+            vec![
+                TreeItemKey::Project,
+                TreeItemKey::Phase(PhaseId::new(1)),
+                TreeItemKey::Milestone("v0.1".to_string()),
+                TreeItemKey::Phase(PhaseId::new(0)),
+                TreeItemKey::Milestone("v1.0".to_string()),
+                TreeItemKey::Phase(PhaseId::new(2)),
+            ]
+        );
+
+        let collapsed = HashSet::from(["v0.1".to_string(), "v1.0".to_string()]);
+        assert_eq!(
+            build_tree_items(&data, &HashSet::new(), &HashSet::new(), &collapsed, false).len(),
+            4
+        );
+
+        // Collapse follows the milestone when one is added before it
+        data.roadmap.milestones.insert(
+            0,
+            Milestone {
+                version: "v0.0".to_string(),
+                ..Default::default()
+            },
+        );
+        let collapsed = HashSet::from(["v1.0".to_string()]);
+        let items = build_tree_items(&data, &HashSet::new(), &HashSet::new(), &collapsed, false);
+        assert_eq!(
+            items.last().map(TreeItem::key),
+            Some(TreeItemKey::Milestone("v1.0".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_build_tree_research_node_after_project() {
+        let mut data = mock_data();
+        let doc = |name: &str| {
+            Document::parse(
+                crate::data::DocumentKind::Stack,
+                std::path::Path::new(name),
+                "# Stack\n",
+            )
+        };
+        data.research = vec![doc("research/STACK.md")];
+        data.roadmap.phases[1].documents = vec![doc("phases/02-two/02-RESEARCH.md")];
+        let expanded = HashSet::from([PhaseId::new(2)]);
+
+        let collapsed = build_tree_items(&data, &expanded, &HashSet::new(), &HashSet::new(), false);
+        let keys: Vec<TreeItemKey> = collapsed.iter().map(TreeItem::key).collect();
+        assert_eq!(
+            keys,
+            vec![
+                TreeItemKey::Project,
+                TreeItemKey::Research,
+                TreeItemKey::Phase(PhaseId::new(1)),
+                TreeItemKey::Phase(PhaseId::new(2)),
+                TreeItemKey::Document("phases/02-two/02-RESEARCH.md".into()),
+            ]
+        );
+
+        let items = build_tree_items(&data, &expanded, &HashSet::new(), &HashSet::new(), true);
+        assert_eq!(
+            items[2].key(),
+            TreeItemKey::Document("research/STACK.md".into())
+        );
+        assert!(phases_with_children(&data).contains(&PhaseId::new(2)));
+    }
+
+    #[test]
+    fn test_phases_with_children() {
+        let mut data = mock_data();
+
+        let phases = phases_with_children(&data);
 
         // Only phase 1 has requirements
-        assert!(phases.contains(&1));
-        assert!(!phases.contains(&2));
+        assert!(phases.contains(&PhaseId::new(1)));
+        assert!(!phases.contains(&PhaseId::new(2)));
+
+        // Plans also count as children
+        data.roadmap.phases[1].plans = vec![mock_plan("02-01")];
+        assert!(phases_with_children(&data).contains(&PhaseId::new(2)));
     }
 }
@@ -0,0 +1,162 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
+};
+
+use crate::components::popup_area;
+use crate::state::WaveViewState;
+use crate::waves::{PhaseWaves, Readiness, WavePlan};
+
+/// Problems listed under the waves before the rest are summarized
+const MAX_PROBLEM_LINES: usize = 4;
+
+/// Overlay showing a phase's plans in columns by wave
+pub struct WaveView<'a> {
+    waves: Option<&'a PhaseWaves>,
+    view: &'a WaveViewState,
+}
+
+impl<'a> WaveView<'a> {
+    pub fn new(waves: Option<&'a PhaseWaves>, view: &'a WaveViewState) -> Self {
+        Self { waves, view }
+    }
+}
+
+fn readiness_symbol(readiness: Readiness) -> (&'static str, Color) {
+    match readiness {
+        Readiness::Complete => ("✓", Color::Green),
+        Readiness::Runnable => ("▶", Color::Yellow),
+        Readiness::Blocked => ("⏸", Color::Red),
+    }
+}
+
+/// A plan's lines in its wave column: marker and ID, title, and what it waits on
+fn plan_lines(plan: &WavePlan) -> Vec<Line<'static>> {
+    let (symbol, color) = readiness_symbol(plan.readiness);
+    let mut header = vec![
+        Span::styled(format!("{} ", symbol), Style::default().fg(color)),
+        Span::styled(
+            plan.id.clone(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+    ];
+    if !plan.problems.is_empty() {
+        header.push(Span::styled(" !", Style::default().fg(Color::Yellow)));
+    }
+
+    let mut lines = vec![
+        Line::from(header),
+        Line::from(Span::styled(
+            format!("  {}", plan.title),
+            Style::default().fg(Color::Gray),
+        )),
+    ];
+    if !plan.waiting_on.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!("  waits on {}", plan.waiting_on.join(", ")),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    lines.push(Line::default());
+    lines
+}
+
+/// Wave problems of every plan, prefixed with the plan ID
+fn problem_lines(waves: &PhaseWaves) -> Vec<Line<'static>> {
+    let mut lines: Vec<Line> = waves
+        .plans()
+        .flat_map(|plan| {
+            plan.problems.iter().map(|problem| {
+                Line::from(Span::styled(
+                    format!("{} {}", plan.id, problem),
+                    Style::default().fg(Color::Yellow),
+                ))
+            })
+        })
+        .collect();
+
+    if lines.len() > MAX_PROBLEM_LINES {
+        let more = lines.len() - (MAX_PROBLEM_LINES - 1);
+        lines.truncate(MAX_PROBLEM_LINES - 1);
+        lines.push(Line::from(Span::styled(
+            format!("... and {} more", more),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    lines
+}
+
+impl Widget for WaveView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let popup_area = popup_area(area, 90, 80);
+        Clear.render(popup_area, buf);
+
+        let title = match self.waves {
+            Some(waves) => format!(
+                " Waves: Phase {}: {} ({}/{}) ",
+                waves.phase,
+                waves.name,
+                self.view.selected + 1,
+                self.view.phases.len()
+            ),
+            None => " Waves ".to_string(),
+        };
+        let block = Block::default()
+            .title(title)
+            .title_bottom(
+                " ✓ complete  ▶ runnable  ⏸ blocked  ! wave problem · h/l phase, Esc close ",
+            )
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::Black));
+        let inner = block.inner(popup_area);
+        block.render(popup_area, buf);
+
+        let Some(waves) = self.waves else {
+            Line::from(Span::styled(
+                "No PLAN.md files in the roadmap's phases",
+                Style::default().fg(Color::DarkGray),
+            ))
+            .render(inner, buf);
+            return;
+        };
+
+        let problems = problem_lines(waves);
+        let footer_height = if problems.is_empty() {
+            0
+        } else {
+            problems.len() as u16 + 1
+        };
+        let [columns_area, footer] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(footer_height)]).areas(inner);
+        Paragraph::new(problems).render(
+            Rect {
+                y: footer.y + 1,
+                ..footer
+            },
+            buf,
+        );
+
+        let columns = Layout::horizontal(waves.waves.iter().map(|_| Constraint::Fill(1)))
+            .spacing(1)
+            .split(columns_area);
+        for (wave, &column) in waves.waves.iter().zip(columns.iter()) {
+            let heading = match wave.number {
+                Some(number) => format!(" Wave {} ", number),
+                None => " No wave ".to_string(),
+            };
+            let lines: Vec<Line> = wave.plans.iter().flat_map(plan_lines).collect();
+            Paragraph::new(lines)
+                .block(
+                    Block::default()
+                        .title(heading)
+                        .borders(Borders::TOP)
+                        .border_style(Style::default().fg(Color::DarkGray)),
+                )
+                .render(column, buf);
+        }
+    }
+}
@@ -0,0 +1,607 @@
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+
+/// A markdown block with the 1-based, inclusive lines it covers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block {
+    pub kind: BlockKind,
+    pub line: usize,
+    pub end_line: usize,
+}
+
+/// Line-oriented view of a markdown block, flattened from pulldown-cmark events
+///
+/// Parsers walk these instead of raw lines, so heading levels, bullet styles,
+/// emphasis variants and table layout don't matter to them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlockKind {
+    /// `#` to `######` or setext heading, with markup removed
+    Heading { level: u8, text: String },
+    /// One line of a paragraph
+    ///
+    /// Paragraphs are split at line breaks so label lines written without blank
+    /// lines between them (`**Phase:** 3` then `**Status:** ...`) are read separately.
+    Text(TextLine),
+    /// A list item's text (its first paragraph), at nesting `depth` 0 for top level
+    Item {
+        depth: usize,
+        /// Task list box: `Some(true)` for `[x]`, `Some(false)` for `[ ]`
+        checked: Option<bool>,
+        text: TextLine,
+    },
+    /// A table: header cells and body rows, as plain text
+    Table {
+        header: Vec<String>,
+        rows: Vec<TableRow>,
+    },
+    /// Fenced or indented code
+    Code,
+    /// `---`, `***` or `___`
+    Rule,
+    /// A line of an HTML block (e.g. `<details>`), trimmed
+    Html(String),
+}
+
+/// Text of a paragraph line or list item, split into an optional label and value
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextLine {
+    /// `Goal` for `**Goal:** value`, `**Goal**: value`, `__Goal:__ value`,
+    /// `*Goal:* value` or a bare `Goal:` line
+    pub label: Option<String>,
+    /// Markdown source after the label (the whole text when unlabelled)
+    pub value: String,
+    /// The whole text as markdown source
+    pub source: String,
+    /// The whole text with markup removed
+    pub plain: String,
+    /// Whether this line continues the paragraph of the previous `Text` block
+    pub continuation: bool,
+}
+
+/// A table body row with the line it is on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableRow {
+    pub cells: Vec<String>,
+    pub line: usize,
+}
+
+impl TableRow {
+    /// Cell in column `idx`, or "" when the row is short
+    pub fn cell(&self, idx: usize) -> &str {
+        self.cells.get(idx).map(String::as_str).unwrap_or_default()
+    }
+}
+
+/// Flatten a markdown document into blocks
+pub fn parse_blocks(content: &str) -> Vec<Block> {
+    let mut builder = BlockBuilder::new(content);
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_TASKLISTS | Options::ENABLE_STRIKETHROUGH;
+    for (event, range) in Parser::new_ext(content, options).into_offset_iter() {
+        builder.event(event, range);
+    }
+    builder.blocks
+}
+
+/// Text being collected for a paragraph line, list item or heading
+#[derive(Default)]
+struct Inline {
+    /// Byte range of the source text seen so far
+    start: Option<usize>,
+    end: usize,
+    plain: String,
+    /// Nesting of strong/emphasis spans; a label can only open at depth 0
+    emphasis_depth: usize,
+    /// Label text while the leading strong/emphasis span is open
+    label: Option<String>,
+    /// Source offset after the leading strong/emphasis span, once closed
+    label_end: Option<usize>,
+    continuation: bool,
+}
+
+impl Inline {
+    fn touch(&mut self, range: &std::ops::Range<usize>) {
+        self.start.get_or_insert(range.start);
+        self.end = self.end.max(range.end);
+    }
+
+    fn is_empty(&self) -> bool {
+        self.start.is_none()
+    }
+
+    /// Split into label and value against the document source
+    fn finish(self, content: &str) -> TextLine {
+        let start = self.start.unwrap_or(0);
+        let source = content[start..self.end.max(start)].trim();
+        let plain = collapse_whitespace(&self.plain);
+
+        // `**Label:** value` or `**Label**: value`
+        if let (Some(label), Some(label_end)) = (self.label.as_deref(), self.label_end) {
+            let after = content[label_end..self.end.max(label_end)].trim_start();
+            let label = label.trim();
+            let (label, after) = match label.strip_suffix(':') {
+                Some(label) => (label, Some(after)),
+                None => (label, after.strip_prefix(':')),
+            };
+            if let Some(after) = after {
+                return TextLine {
+                    label: Some(label.trim().to_string()),
+                    value: collapse_whitespace(after),
+                    source: collapse_whitespace(source),
+                    plain,
+                    continuation: self.continuation,
+                };
+            }
+        }
+
+        // A bare `Label:` line, such as the `Plans:` line above a plan checklist
+        let bare = plain
+            .strip_suffix(':')
+            .filter(|label| !label.is_empty() && !label.contains(':'));
+        TextLine {
+            label: bare.map(|label| label.trim().to_string()),
+            value: if bare.is_some() {
+                String::new()
+            } else {
+                collapse_whitespace(source)
+            },
+            source: collapse_whitespace(source),
+            plain,
+            continuation: self.continuation,
+        }
+    }
+}
+
+/// Join wrapped lines into one, trimming each
+fn collapse_whitespace(text: &str) -> String {
+    text.lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Where inline text is currently going
+enum Target {
+    None,
+    Heading(u8),
+    Paragraph,
+    /// First paragraph of a list item
+    Item,
+    TableCell,
+}
+
+/// A list item whose end hasn't been seen yet
+#[derive(Clone, Copy)]
+struct OpenItem {
+    depth: usize,
+    checked: Option<bool>,
+    /// Offset of the item's marker
+    start: usize,
+    /// Whether the item's text has been emitted
+    emitted: bool,
+}
+
+struct BlockBuilder<'a> {
+    content: &'a str,
+    /// Byte offset of the start of each line
+    line_starts: Vec<usize>,
+    blocks: Vec<Block>,
+    target: Target,
+    inline: Inline,
+    /// Start offset of the block being collected
+    block_start: usize,
+    list_depth: usize,
+    /// Open list items, innermost last
+    items: Vec<OpenItem>,
+    in_code: bool,
+    /// HTML block being collected: start offset and text
+    html: Option<(usize, String)>,
+    table: Option<(Vec<String>, Vec<TableRow>)>,
+    row: Vec<String>,
+}
+
+impl<'a> BlockBuilder<'a> {
+    fn new(content: &'a str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(content.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Self {
+            content,
+            line_starts,
+            blocks: Vec::new(),
+            target: Target::None,
+            inline: Inline::default(),
+            block_start: 0,
+            list_depth: 0,
+            items: Vec::new(),
+            in_code: false,
+            html: None,
+            table: None,
+            row: Vec::new(),
+        }
+    }
+
+    /// 1-based line of a byte offset
+    fn line_of(&self, offset: usize) -> usize {
+        self.line_starts.partition_point(|&start| start <= offset)
+    }
+
+    fn push(&mut self, kind: BlockKind, start: usize, end: usize) {
+        let line = self.line_of(start);
+        let end_line = self.line_of(end.saturating_sub(1).max(start));
+        self.blocks.push(Block {
+            kind,
+            line,
+            end_line,
+        });
+    }
+
+    fn event(&mut self, event: Event<'a>, range: std::ops::Range<usize>) {
+        if self.in_code {
+            if let Event::End(TagEnd::CodeBlock) = event {
+                self.in_code = false;
+                self.push(BlockKind::Code, self.block_start, range.end);
+            }
+            return;
+        }
+
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                self.target = Target::Heading(level as u8);
+                self.inline = Inline::default();
+                self.block_start = range.start;
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                if let Target::Heading(level) = self.target {
+                    let text = collapse_whitespace(&self.inline.plain);
+                    self.push(BlockKind::Heading { level, text }, range.start, range.end);
+                }
+                self.target = Target::None;
+            }
+            Event::Start(Tag::Paragraph) => {
+                // The first paragraph of a loose list item is the item's text
+                let item_pending = self.items.last().is_some_and(|item| !item.emitted);
+                self.target = if item_pending {
+                    Target::Item
+                } else {
+                    Target::Paragraph
+                };
+                self.inline = Inline::default();
+            }
+            Event::End(TagEnd::Paragraph) => self.finish_line(),
+            Event::SoftBreak | Event::HardBreak => match self.target {
+                Target::Paragraph => {
+                    self.finish_line();
+                    self.target = Target::Paragraph;
+                    self.inline.continuation = true;
+                }
+                Target::Item | Target::Heading(_) | Target::TableCell => {
+                    self.inline.plain.push('\n');
+                }
+                Target::None => {}
+            },
+            Event::Start(Tag::List(_)) => {
+                // A nested list ends the parent item's text
+                self.finish_item_text();
+                self.list_depth += 1;
+            }
+            Event::End(TagEnd::List(_)) => {
+                self.list_depth = self.list_depth.saturating_sub(1);
+            }
+            Event::Start(Tag::Item) => {
+                self.items.push(OpenItem {
+                    depth: self.list_depth.saturating_sub(1),
+                    checked: None,
+                    start: range.start,
+                    emitted: false,
+                });
+                // Tight list items have no paragraph around their text
+                self.target = Target::Item;
+                self.inline = Inline::default();
+            }
+            Event::End(TagEnd::Item) => {
+                self.finish_item_text();
+                self.items.pop();
+                self.target = if self.items.last().is_some_and(|item| !item.emitted) {
+                    Target::Item
+                } else {
+                    Target::None
+                };
+            }
+            Event::TaskListMarker(checked) => {
+                if let Some(item) = self.items.last_mut() {
+                    item.checked = Some(checked);
+                }
+            }
+            Event::Start(Tag::CodeBlock(_)) => {
+                self.finish_item_text();
+                self.in_code = true;
+                self.block_start = range.start;
+            }
+            Event::Rule => self.push(BlockKind::Rule, range.start, range.end),
+            Event::Start(Tag::HtmlBlock) => {
+                self.finish_item_text();
+                self.html = Some((range.start, String::new()));
+            }
+            Event::Html(html) => match self.html.as_mut() {
+                Some((_, text)) => text.push_str(&html),
+                None => self.html(&html, range.start),
+            },
+            Event::End(TagEnd::HtmlBlock) => {
+                if let Some((start, text)) = self.html.take() {
+                    self.html(&text, start);
+                }
+            }
+            Event::Start(Tag::Table(_)) => {
+                self.table = Some((Vec::new(), Vec::new()));
+                self.block_start = range.start;
+            }
+            Event::End(TagEnd::Table) => {
+                if let Some((header, rows)) = self.table.take() {
+                    self.push(
+                        BlockKind::Table { header, rows },
+                        self.block_start,
+                        range.end,
+                    );
+                }
+            }
+            Event::Start(Tag::TableHead) | Event::Start(Tag::TableRow) => self.row.clear(),
+            Event::End(TagEnd::TableHead) => {
+                if let Some((header, _)) = self.table.as_mut() {
+                    *header = std::mem::take(&mut self.row);
+                }
+            }
+            Event::End(TagEnd::TableRow) => {
+                let line = self.line_of(range.start);
+                if let Some((_, rows)) = self.table.as_mut() {
+                    rows.push(TableRow {
+                        cells: std::mem::take(&mut self.row),
+                        line,
+                    });
+                }
+            }
+            Event::Start(Tag::TableCell) => {
+                self.target = Target::TableCell;
+                self.inline = Inline::default();
+            }
+            Event::End(TagEnd::TableCell) => {
+                self.row.push(collapse_whitespace(&self.inline.plain));
+                self.target = Target::None;
+            }
+            Event::Start(Tag::Strong) | Event::Start(Tag::Emphasis) if self.collecting() => {
+                // A leading strong/emphasis span may be a label
+                if self.inline.is_empty() && self.inline.emphasis_depth == 0 {
+                    self.inline.label = Some(String::new());
+                }
+                self.inline.emphasis_depth += 1;
+                self.inline.touch(&range);
+            }
+            Event::End(TagEnd::Strong) | Event::End(TagEnd::Emphasis) if self.collecting() => {
+                self.inline.emphasis_depth = self.inline.emphasis_depth.saturating_sub(1);
+                if self.inline.emphasis_depth == 0
+                    && self.inline.label.is_some()
+                    && self.inline.label_end.is_none()
+                {
+                    self.inline.label_end = Some(range.end);
+                }
+            }
+            Event::Text(text) | Event::Code(text) | Event::InlineHtml(text)
+                if self.collecting() =>
+            {
+                self.inline.touch(&range);
+                self.inline.plain.push_str(&text);
+                if self.inline.label_end.is_none() {
+                    if let Some(label) = self.inline.label.as_mut() {
+                        label.push_str(&text);
+                    }
+                }
+            }
+            // Links, strikethrough and the like: their text arrives as Text events
+            Event::Start(_) | Event::End(_) if self.collecting() => self.inline.touch(&range),
+            _ => {}
+        }
+    }
+
+    fn collecting(&self) -> bool {
+        !matches!(self.target, Target::None)
+    }
+
+    /// Emit the paragraph line or item text collected so far
+    fn finish_line(&mut self) {
+        match self.target {
+            Target::Paragraph => {
+                let inline = std::mem::take(&mut self.inline);
+                if let Some(start) = inline.start {
+                    let end = inline.end;
+                    let text = inline.finish(self.content);
+                    self.push(BlockKind::Text(text), start, end);
+                }
+                self.target = Target::None;
+            }
+            Target::Item => self.finish_item_text(),
+            _ => {}
+        }
+    }
+
+    /// Emit the innermost list item's text, once
+    fn finish_item_text(&mut self) {
+        let Some(&OpenItem {
+            depth,
+            checked,
+            start,
+            emitted,
+        }) = self.items.last()
+        else {
+            return;
+        };
+        if emitted || !matches!(self.target, Target::Item) {
+            return;
+        }
+        let inline = std::mem::take(&mut self.inline);
+        let end = inline.end.max(start);
+        let text = inline.finish(self.content);
+        self.push(
+            BlockKind::Item {
+                depth,
+                checked,
+                text,
+            },
+            start,
+            end,
+        );
+        if let Some(item) = self.items.last_mut() {
+            item.emitted = true;
+        }
+        self.target = Target::None;
+    }
+
+    /// Split an HTML block into tag lines, re-reading any markdown inside it
+    ///
+    /// Without a blank line after `<summary>...</summary>`, CommonMark swallows
+    /// the following list into the HTML block; reading those lines as markdown
+    /// keeps the phases inside `<details>` groups.
+    fn html(&mut self, html: &str, start: usize) {
+        let first_line = self.line_of(start);
+        let mut markdown: Vec<&str> = Vec::new();
+        let mut markdown_line = first_line;
+
+        for (idx, line) in html.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.starts_with('<') {
+                self.flush_embedded(&markdown, markdown_line);
+                markdown.clear();
+                if !trimmed.is_empty() {
+                    self.blocks.push(Block {
+                        kind: BlockKind::Html(trimmed.to_string()),
+                        line: first_line + idx,
+                        end_line: first_line + idx,
+                    });
+                }
+            } else {
+                if markdown.is_empty() {
+                    markdown_line = first_line + idx;
+                }
+                markdown.push(line);
+            }
+        }
+        self.flush_embedded(&markdown, markdown_line);
+    }
+
+    fn flush_embedded(&mut self, lines: &[&str], first_line: usize) {
+        if lines.iter().all(|l| l.trim().is_empty()) {
+            return;
+        }
+        for mut block in parse_blocks(&lines.join("\n")) {
+            block.line += first_line - 1;
+            block.end_line += first_line - 1;
+            if let BlockKind::Table { rows, .. } = &mut block.kind {
+                for row in rows {
+                    row.line += first_line - 1;
+                }
+            }
+            self.blocks.push(block);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(content: &str) -> Vec<BlockKind> {
+        parse_blocks(content).into_iter().map(|b| b.kind).collect()
+    }
+
+    fn label(block: &BlockKind) -> Option<(&str, &str)> {
+        match block {
+            BlockKind::Text(text) | BlockKind::Item { text, .. } => {
+                Some((text.label.as_deref()?, text.value.as_str()))
+            }
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_label_variants() {
+        let blocks = kinds(
+            "**Goal:** One **bold** thing\n__Status__: Executing\n*Plan:* 02-01\n\nPlans:\n\n**Not a label** here\n",
+        );
+        let labels: Vec<_> = blocks.iter().map(label).collect();
+        assert_eq!(
+            labels,
+            vec![
+                Some(("Goal", "One **bold** thing")),
+                Some(("Status", "Executing")),
+                Some(("Plan", "02-01")),
+                Some(("Plans", "")),
+                None,
+            ]
+        );
+        assert!(matches!(&blocks[1], BlockKind::Text(t) if t.continuation));
+    }
+
+    #[test]
+    fn test_list_items_with_any_marker() {
+        let blocks = parse_blocks(
+            "* [x] Phase 1: One\n+ REQ-01: Two\n   wrapped\n\n1) First\n   - nested\n",
+        );
+        let items: Vec<_> = blocks
+            .iter()
+            .map(|b| match &b.kind {
+                BlockKind::Item {
+                    depth,
+                    checked,
+                    text,
+                } => (*depth, *checked, text.value.as_str(), b.line),
+                other => panic!("unexpected {:?}", other),
+            })
+            .collect();
+        assert_eq!(
+            items,
+            vec![
+                (0, Some(true), "Phase 1: One", 1),
+                (0, None, "REQ-01: Two wrapped", 2),
+                (0, None, "First", 5),
+                (1, None, "nested", 6),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_table_rows_and_lines() {
+        let blocks = parse_blocks("# T\n\n| Value | Metric |\n|---|---|\n| 5 | Total Phases |\n");
+        assert_eq!(
+            blocks[1].kind,
+            BlockKind::Table {
+                header: vec!["Value".into(), "Metric".into()],
+                rows: vec![TableRow {
+                    cells: vec!["5".into(), "Total Phases".into()],
+                    line: 5,
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn test_markdown_inside_html_block_is_read() {
+        let blocks = parse_blocks(
+            "<details>\n<summary>v0.1 Preview</summary>\n- [x] Phase 1: One\n</details>\n",
+        );
+        assert_eq!(
+            blocks[1].kind,
+            BlockKind::Html("<summary>v0.1 Preview</summary>".into())
+        );
+        assert!(matches!(
+            &blocks[2],
+            Block {
+                kind: BlockKind::Item {
+                    checked: Some(true),
+                    ..
+                },
+                line: 3,
+                ..
+            }
+        ));
+        assert_eq!(blocks[3].kind, BlockKind::Html("</details>".into()));
+    }
+}
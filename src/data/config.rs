@@ -0,0 +1,500 @@
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::{eyre, Context};
+use color_eyre::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// How much Kata asks before acting (`mode`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Mode {
+    #[default]
+    Interactive,
+    Yolo,
+}
+
+/// How thorough planning is (`depth`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Depth {
+    Quick,
+    #[default]
+    Standard,
+    Comprehensive,
+}
+
+/// Which models agents run on (`model_profile`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ModelProfile {
+    Quality,
+    #[default]
+    Balanced,
+    Budget,
+}
+
+/// The `display` section
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct DisplayConfig {
+    pub statusline: bool,
+}
+
+impl Default for DisplayConfig {
+    fn default() -> Self {
+        Self { statusline: true }
+    }
+}
+
+/// The `workflow` section: optional agents around planning and execution
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct WorkflowConfig {
+    pub research: bool,
+    pub plan_check: bool,
+    pub verifier: bool,
+}
+
+impl Default for WorkflowConfig {
+    fn default() -> Self {
+        Self {
+            research: true,
+            plan_check: true,
+            verifier: true,
+        }
+    }
+}
+
+/// Kata workflow settings from `.planning/config.json`
+///
+/// Missing or invalid values fall back to Kata's defaults.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct KataConfig {
+    pub mode: Mode,
+    pub depth: Depth,
+    pub parallelization: bool,
+    pub commit_docs: bool,
+    pub pr_workflow: bool,
+    pub model_profile: ModelProfile,
+    pub display: DisplayConfig,
+    pub workflow: WorkflowConfig,
+}
+
+impl Default for KataConfig {
+    fn default() -> Self {
+        Self {
+            mode: Mode::default(),
+            depth: Depth::default(),
+            parallelization: true,
+            commit_docs: true,
+            pr_workflow: false,
+            model_profile: ModelProfile::default(),
+            display: DisplayConfig::default(),
+            workflow: WorkflowConfig::default(),
+        }
+    }
+}
+
+/// What values a setting accepts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingKind {
+    Bool,
+    /// One of these strings
+    Choice(&'static [&'static str]),
+}
+
+/// A known config.json setting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Setting {
+    /// Dotted key path (e.g., "workflow.research")
+    pub key: &'static str,
+    pub kind: SettingKind,
+    pub description: &'static str,
+}
+
+impl Setting {
+    /// Whether `value` is acceptable for this setting
+    pub fn accepts(&self, value: &Value) -> bool {
+        match self.kind {
+            SettingKind::Bool => value.is_boolean(),
+            SettingKind::Choice(choices) => value.as_str().is_some_and(|v| choices.contains(&v)),
+        }
+    }
+
+    /// The value after `current` when editing in place: booleans flip, choices
+    /// advance and wrap, and invalid values reset to the first choice
+    pub fn next_value(&self, current: &Value) -> Value {
+        match self.kind {
+            SettingKind::Bool => Value::Bool(!current.as_bool().unwrap_or(false)),
+            SettingKind::Choice(choices) => {
+                let next = current
+                    .as_str()
+                    .and_then(|v| choices.iter().position(|&c| c == v))
+                    .map_or(0, |pos| (pos + 1) % choices.len());
+                Value::from(choices[next])
+            }
+        }
+    }
+}
+
+/// Settings kata-tui knows about, in display order
+pub const SETTINGS: &[Setting] = &[
+    Setting {
+        key: "mode",
+        kind: SettingKind::Choice(&["interactive", "yolo"]),
+        description: "Confirm each step (interactive) or run without asking (yolo)",
+    },
+    Setting {
+        key: "depth",
+        kind: SettingKind::Choice(&["quick", "standard", "comprehensive"]),
+        description: "How many phases and plans planning produces",
+    },
+    Setting {
+        key: "parallelization",
+        kind: SettingKind::Bool,
+        description: "Execute independent plans in the same wave in parallel",
+    },
+    Setting {
+        key: "commit_docs",
+        kind: SettingKind::Bool,
+        description: "Commit .planning/ documents as they change",
+    },
+    Setting {
+        key: "pr_workflow",
+        kind: SettingKind::Bool,
+        description: "Work on a branch per phase and open pull requests",
+    },
+    Setting {
+        key: "model_profile",
+        kind: SettingKind::Choice(&["quality", "balanced", "budget"]),
+        description: "Trade model quality against cost for agents",
+    },
+    Setting {
+        key: "display.statusline",
+        kind: SettingKind::Bool,
+        description: "Show Kata's status line",
+    },
+    Setting {
+        key: "workflow.research",
+        kind: SettingKind::Bool,
+        description: "Research the domain before planning a phase",
+    },
+    Setting {
+        key: "workflow.plan_check",
+        kind: SettingKind::Bool,
+        description: "Check plans against the phase goal before execution",
+    },
+    Setting {
+        key: "workflow.verifier",
+        kind: SettingKind::Bool,
+        description: "Verify the phase goal was met after execution",
+    },
+];
+
+/// Look up a known setting by dotted key
+pub fn find_setting(key: &str) -> Option<&'static Setting> {
+    SETTINGS.iter().find(|setting| setting.key == key)
+}
+
+/// Something wrong with a config.json entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConfigIssue {
+    /// Dotted key path, or empty for problems with the whole file
+    pub key: String,
+    /// Whether the key is unknown, as opposed to having an invalid value
+    pub unknown: bool,
+    pub message: String,
+}
+
+/// `.planning/config.json`: typed settings plus the JSON they were read from
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Config {
+    pub path: PathBuf,
+    pub settings: KataConfig,
+    /// The parsed file, so values can be shown as written
+    #[serde(skip)]
+    pub raw: Map<String, Value>,
+    pub issues: Vec<ConfigIssue>,
+}
+
+impl Config {
+    /// Parse config.json content, flagging invalid values and unknown keys
+    ///
+    /// Never fails: unreadable JSON is reported as an issue and yields defaults.
+    pub fn parse(path: &Path, content: &str) -> Self {
+        let mut issues = Vec::new();
+        let raw = match serde_json::from_str::<Value>(content) {
+            Ok(Value::Object(map)) => map,
+            Ok(_) => {
+                issues.push(file_issue("config.json must be a JSON object".to_string()));
+                Map::new()
+            }
+            Err(e) => {
+                issues.push(file_issue(format!("config.json is not valid JSON: {}", e)));
+                Map::new()
+            }
+        };
+
+        // Only valid values reach the typed model, so it always deserializes
+        let mut valid = Map::new();
+        for setting in SETTINGS {
+            let Some(value) = lookup(&raw, setting.key) else {
+                continue;
+            };
+            if setting.accepts(value) {
+                insert(&mut valid, setting.key, value.clone());
+            } else {
+                issues.push(ConfigIssue {
+                    key: setting.key.to_string(),
+                    unknown: false,
+                    message: format!(
+                        "invalid value {} (expected {})",
+                        value,
+                        expected(setting.kind)
+                    ),
+                });
+            }
+        }
+        unknown_keys("", &raw, &mut issues);
+
+        Self {
+            path: path.to_path_buf(),
+            settings: serde_json::from_value(Value::Object(valid)).unwrap_or_default(),
+            raw,
+            issues,
+        }
+    }
+
+    /// A setting's value as written, or its default if the file leaves it out
+    pub fn value(&self, key: &str) -> Value {
+        lookup(&self.raw, key).cloned().unwrap_or_else(|| {
+            let defaults = match serde_json::to_value(&self.settings) {
+                Ok(Value::Object(map)) => map,
+                _ => Map::new(),
+            };
+            lookup(&defaults, key).cloned().unwrap_or(Value::Null)
+        })
+    }
+
+    /// The issue reported for `key`, if any
+    pub fn issue(&self, key: &str) -> Option<&ConfigIssue> {
+        self.issues.iter().find(|issue| issue.key == key)
+    }
+}
+
+fn file_issue(message: String) -> ConfigIssue {
+    ConfigIssue {
+        key: String::new(),
+        unknown: false,
+        message,
+    }
+}
+
+/// Human-readable list of accepted values
+fn expected(kind: SettingKind) -> String {
+    match kind {
+        SettingKind::Bool => "true or false".to_string(),
+        SettingKind::Choice(choices) => choices.join(", "),
+    }
+}
+
+/// Flag keys under `prefix` that are neither settings nor sections containing settings
+fn unknown_keys(prefix: &str, map: &Map<String, Value>, issues: &mut Vec<ConfigIssue>) {
+    for (name, value) in map {
+        let key = if prefix.is_empty() {
+            name.clone()
+        } else {
+            format!("{}.{}", prefix, name)
+        };
+        if find_setting(&key).is_some() {
+            continue;
+        }
+        let section = format!("{}.", key);
+        let is_section = SETTINGS.iter().any(|s| s.key.starts_with(&section));
+        match value {
+            Value::Object(children) if is_section => unknown_keys(&key, children, issues),
+            _ => issues.push(ConfigIssue {
+                message: format!("unknown setting {}", key),
+                key,
+                unknown: true,
+            }),
+        }
+    }
+}
+
+/// Value at a dotted key path
+fn lookup<'a>(map: &'a Map<String, Value>, key: &str) -> Option<&'a Value> {
+    let (section, rest) = match key.split_once('.') {
+        Some((section, rest)) => (section, Some(rest)),
+        None => (key, None),
+    };
+    let value = map.get(section)?;
+    match rest {
+        None => Some(value),
+        Some(rest) => lookup(value.as_object()?, rest),
+    }
+}
+
+/// Set a dotted key path, creating (or replacing non-object) sections as needed
+fn insert(map: &mut Map<String, Value>, key: &str, value: Value) {
+    match key.split_once('.') {
+        None => {
+            map.insert(key.to_string(), value);
+        }
+        Some((section, rest)) => {
+            let entry = map
+                .entry(section)
+                .or_insert_with(|| Value::Object(Map::new()));
+            if !entry.is_object() {
+                *entry = Value::Object(Map::new());
+            }
+            if let Value::Object(child) = entry {
+                insert(child, rest, value);
+            }
+        }
+    }
+}
+
+/// Change one setting in config.json, keeping every other key as written
+///
+/// The file is re-read first so concurrent edits by Kata are not lost, and written
+/// to a temporary file that is then renamed over it, so readers never see a
+/// partial file. A missing file is created.
+pub fn write_setting(path: &Path, key: &str, value: Value) -> Result<()> {
+    let mut raw = match std::fs::read_to_string(path) {
+        Ok(content) => match serde_json::from_str(&content)
+            .wrap_err_with(|| format!("Failed to parse {}", path.display()))?
+        {
+            Value::Object(map) => map,
+            _ => return Err(eyre!("{} is not a JSON object", path.display())),
+        },
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Map::new(),
+        Err(e) => return Err(e).wrap_err_with(|| format!("Failed to read {}", path.display())),
+    };
+    insert(&mut raw, key, value);
+
+    let content = serde_json::to_string_pretty(&Value::Object(raw))? + "\n";
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    // Hidden, so the planning file watcher ignores it
+    let tmp = path.with_file_name(format!(".{}.tmp", name));
+    std::fs::write(&tmp, content).wrap_err_with(|| format!("Failed to write {}", tmp.display()))?;
+    std::fs::rename(&tmp, path).wrap_err_with(|| format!("Failed to replace {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    const CONFIG: &str = r#"{
+  "mode": "yolo",
+  "depth": "deep",
+  "parallelization": true,
+  "model_profile": "quality",
+  "display": {
+    "statusline": "on",
+    "theme": "dark"
+  },
+  "workflow": {
+    "research": false
+  },
+  "custom": 1
+}
+"#;
+
+    #[test]
+    fn test_parse_typed_settings_with_defaults() {
+        let config = Config::parse(Path::new("config.json"), CONFIG);
+
+        assert_eq!(config.settings.mode, Mode::Yolo);
+        assert_eq!(config.settings.model_profile, ModelProfile::Quality);
+        assert!(!config.settings.workflow.research);
+        // Missing and invalid values fall back to defaults
+        assert!(config.settings.workflow.verifier);
+        assert_eq!(config.settings.depth, Depth::Standard);
+        assert!(config.settings.display.statusline);
+        // Values are shown as written
+        assert_eq!(config.value("depth"), Value::from("deep"));
+        assert_eq!(config.value("workflow.plan_check"), Value::Bool(true));
+    }
+
+    #[test]
+    fn test_parse_flags_invalid_and_unknown() {
+        let config = Config::parse(Path::new("config.json"), CONFIG);
+
+        let flagged: Vec<(&str, bool)> = config
+            .issues
+            .iter()
+            .map(|issue| (issue.key.as_str(), issue.unknown))
+            .collect();
+        assert_eq!(
+            flagged,
+            vec![
+                ("depth", false),
+                ("display.statusline", false),
+                ("display.theme", true),
+                ("custom", true),
+            ]
+        );
+        assert_eq!(
+            config.issue("depth").unwrap().message,
+            "invalid value \"deep\" (expected quick, standard, comprehensive)"
+        );
+
+        let broken = Config::parse(Path::new("config.json"), "{ nope");
+        assert_eq!(broken.settings, KataConfig::default());
+        assert_eq!(broken.issues.len(), 1);
+    }
+
+    #[test]
+    fn test_choices_match_typed_model() {
+        for setting in SETTINGS {
+            if let SettingKind::Choice(choices) = setting.kind {
+                for choice in choices {
+                    let mut map = Map::new();
+                    insert(&mut map, setting.key, Value::from(*choice));
+                    let config =
+                        Config::parse(Path::new("config.json"), &Value::Object(map).to_string());
+                    assert_eq!(config.issues, vec![], "{} = {}", setting.key, choice);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_next_value_cycles() {
+        let depth = find_setting("depth").unwrap();
+        assert_eq!(
+            depth.next_value(&Value::from("comprehensive")),
+            Value::from("quick")
+        );
+        assert_eq!(depth.next_value(&Value::from("deep")), Value::from("quick"));
+
+        let research = find_setting("workflow.research").unwrap();
+        assert_eq!(research.next_value(&Value::Bool(true)), Value::Bool(false));
+    }
+
+    #[test]
+    fn test_write_setting_preserves_other_keys() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("config.json");
+        std::fs::write(&path, CONFIG).unwrap();
+
+        write_setting(&path, "workflow.verifier", Value::Bool(false)).unwrap();
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        let config = Config::parse(&path, &written);
+        assert!(!config.settings.workflow.verifier);
+        assert!(!config.settings.workflow.research);
+        assert_eq!(config.value("custom"), Value::from(1));
+        assert_eq!(config.value("display.theme"), Value::from("dark"));
+        // Key order is kept
+        assert!(written.find("\"mode\"").unwrap() < written.find("\"custom\"").unwrap());
+        assert!(!dir.path().join(".config.json.tmp").exists());
+    }
+}
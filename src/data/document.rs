@@ -0,0 +1,248 @@
+use std::path::{Path, PathBuf};
+
+use pulldown_cmark::{Event, HeadingLevel, Parser, Tag, TagEnd};
+use serde::Serialize;
+
+use super::{PhaseId, SourceSpan};
+
+/// Kind of supporting document, from its file name
+///
+/// Ordered the way documents are listed: phase documents first, then the
+/// `research/` files in the order a reader would take them in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DocumentKind {
+    /// `phases/NN-slug/NN-RESEARCH.md`
+    Research,
+    /// `phases/NN-slug/NN-VERIFICATION.md`
+    Verification,
+    /// `research/SUMMARY.md`
+    Summary,
+    /// `research/FEATURES.md`
+    Features,
+    /// `research/STACK.md`
+    Stack,
+    /// `research/ARCHITECTURE.md`
+    Architecture,
+    /// `research/PITFALLS.md`
+    Pitfalls,
+    /// Any other markdown file in `research/`
+    Notes,
+}
+
+impl DocumentKind {
+    /// Kind of a file in a phase directory (e.g., "02-RESEARCH.md")
+    pub fn from_phase_file(name: &str) -> Option<Self> {
+        if name.ends_with("-RESEARCH.md") {
+            Some(Self::Research)
+        } else if name.ends_with("-VERIFICATION.md") {
+            Some(Self::Verification)
+        } else {
+            None
+        }
+    }
+
+    /// Kind of a markdown file in `research/` (e.g., "PITFALLS.md")
+    pub fn from_research_file(name: &str) -> Option<Self> {
+        let stem = name.strip_suffix(".md")?;
+        Some(match stem.to_uppercase().as_str() {
+            "SUMMARY" => Self::Summary,
+            "FEATURES" => Self::Features,
+            "STACK" => Self::Stack,
+            "ARCHITECTURE" => Self::Architecture,
+            "PITFALLS" => Self::Pitfalls,
+            _ => Self::Notes,
+        })
+    }
+
+    /// Human-readable label for this kind
+    pub fn label(&self) -> &'static str {
+        match self {
+            DocumentKind::Research => "Research",
+            DocumentKind::Verification => "Verification",
+            DocumentKind::Summary => "Summary",
+            DocumentKind::Features => "Features",
+            DocumentKind::Stack => "Stack",
+            DocumentKind::Architecture => "Architecture",
+            DocumentKind::Pitfalls => "Pitfalls",
+            DocumentKind::Notes => "Notes",
+        }
+    }
+}
+
+/// A heading in a document's outline
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OutlineHeading {
+    /// Heading level, 1 for `#` through 6
+    pub level: u8,
+    pub text: String,
+    /// 1-based line of the heading
+    pub line: usize,
+}
+
+/// A section of a document that mentions a phase (e.g., a pitfall about Phase 3)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PhaseMention {
+    pub phase: PhaseId,
+    /// Heading of the section containing the mention, if any
+    pub heading: Option<String>,
+    /// 1-based line of the first mention in that section
+    pub line: usize,
+}
+
+/// A supporting markdown document: phase research and verification, or a
+/// project-wide research file
+#[derive(Debug, Clone, Serialize)]
+pub struct Document {
+    pub kind: DocumentKind,
+    /// Path to the markdown file
+    pub path: PathBuf,
+    /// First `#` heading, or the file name when there is none
+    pub title: String,
+    /// Headings in file order
+    pub outline: Vec<OutlineHeading>,
+    /// Phases mentioned, one entry per phase and section
+    pub mentions: Vec<PhaseMention>,
+    /// Raw markdown, rendered in the detail pane
+    #[serde(skip)]
+    pub content: String,
+    /// Where this was parsed from
+    #[serde(skip)]
+    pub span: Option<SourceSpan>,
+}
+
+impl Document {
+    /// Parse a document's title and outline; mentions are linked separately
+    pub fn parse(kind: DocumentKind, path: &Path, content: &str) -> Self {
+        let outline = parse_outline(content);
+        let title = outline
+            .iter()
+            .find(|h| h.level == 1)
+            .map(|h| h.text.clone())
+            .unwrap_or_else(|| {
+                path.file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_default()
+            });
+
+        Self {
+            kind,
+            path: path.to_path_buf(),
+            title,
+            outline,
+            mentions: Vec::new(),
+            content: content.to_string(),
+            span: Some(SourceSpan::file(path, content)),
+        }
+    }
+
+    /// File name of the document (e.g., "PITFALLS.md")
+    pub fn file_name(&self) -> String {
+        self.path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
+
+    /// Heading of the section containing `line`, if any
+    pub fn heading_at(&self, line: usize) -> Option<&OutlineHeading> {
+        self.outline.iter().take_while(|h| h.line <= line).last()
+    }
+
+    /// Mentions of the given phase
+    pub fn mentions_of(&self, phase: PhaseId) -> impl Iterator<Item = &PhaseMention> {
+        self.mentions.iter().filter(move |m| m.phase == phase)
+    }
+}
+
+/// Headings with their levels and line numbers
+fn parse_outline(content: &str) -> Vec<OutlineHeading> {
+    let mut outline = Vec::new();
+    let mut current: Option<OutlineHeading> = None;
+
+    for (event, range) in Parser::new(content).into_offset_iter() {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                current = Some(OutlineHeading {
+                    level: heading_level(level),
+                    text: String::new(),
+                    line: content[..range.start].matches('\n').count() + 1,
+                });
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if let Some(heading) = current.as_mut() {
+                    heading.text.push_str(&text);
+                }
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some(heading) = current.take() {
+                    outline.push(OutlineHeading {
+                        text: heading.text.trim().to_string(),
+                        ..heading
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    outline
+}
+
+fn heading_level(level: HeadingLevel) -> u8 {
+    match level {
+        HeadingLevel::H1 => 1,
+        HeadingLevel::H2 => 2,
+        HeadingLevel::H3 => 3,
+        HeadingLevel::H4 => 4,
+        HeadingLevel::H5 => 5,
+        HeadingLevel::H6 => 6,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_outline_and_title() {
+        let content = "# Pitfalls\n\nIntro.\n\n## Pitfall 1: `notify` floods\n\nText.\n\n### Fix\n";
+        let doc = Document::parse(
+            DocumentKind::Pitfalls,
+            Path::new("research/PITFALLS.md"),
+            content,
+        );
+
+        assert_eq!(doc.title, "Pitfalls");
+        assert_eq!(
+            doc.outline
+                .iter()
+                .map(|h| (h.level, h.text.as_str(), h.line))
+                .collect::<Vec<_>>(),
+            vec![
+                (1, "Pitfalls", 1),
+                (2, "Pitfall 1: notify floods", 5),
+                (3, "Fix", 9)
+            ]
+        );
+        assert_eq!(doc.heading_at(7).map(|h| h.line), Some(5));
+    }
+
+    #[test]
+    fn test_kind_from_file_name() {
+        assert_eq!(
+            DocumentKind::from_phase_file("02.1-VERIFICATION.md"),
+            Some(DocumentKind::Verification)
+        );
+        assert_eq!(DocumentKind::from_phase_file("02-01-PLAN.md"), None);
+        assert_eq!(
+            DocumentKind::from_research_file("STACK.md"),
+            Some(DocumentKind::Stack)
+        );
+        assert_eq!(
+            DocumentKind::from_research_file("competitors.md"),
+            Some(DocumentKind::Notes)
+        );
+        assert_eq!(DocumentKind::from_research_file("diagram.png"), None);
+    }
+}
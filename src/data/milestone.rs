@@ -0,0 +1,187 @@
+use std::path::PathBuf;
+
+use ratatui::style::Color;
+use serde::Serialize;
+
+use super::{Phase, PhaseId, RequirementStatus, SourceSpan};
+
+/// Status of a milestone
+///
+/// Ordered by progress, so `max` picks the most advanced status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MilestoneStatus {
+    #[default]
+    Planned,
+    InProgress,
+    Shipped,
+}
+
+impl MilestoneStatus {
+    /// Parse a status label such as "shipped 2026-01-25" or "In Progress" (case-insensitive)
+    ///
+    /// Returns the status and, for shipped milestones, the date that follows.
+    pub fn from_label(label: &str) -> Option<(Self, Option<String>)> {
+        let lower = label.trim().to_lowercase();
+        if let Some(date) = lower.strip_prefix("shipped") {
+            let date = date.trim_start_matches(':').trim();
+            let date = (!date.is_empty()).then(|| date.to_string());
+            return Some((Self::Shipped, date));
+        }
+        match lower.as_str() {
+            "in progress" | "in-progress" | "active" | "current" => Some((Self::InProgress, None)),
+            "planned" | "pending" | "next" => Some((Self::Planned, None)),
+            _ => None,
+        }
+    }
+
+    /// Human-readable label for this status
+    pub fn label(&self) -> &'static str {
+        match self {
+            MilestoneStatus::Shipped => "Shipped",
+            MilestoneStatus::InProgress => "In Progress",
+            MilestoneStatus::Planned => "Planned",
+        }
+    }
+
+    /// Get the display color for this status
+    pub fn color(&self) -> Color {
+        match self {
+            MilestoneStatus::Shipped => Color::Green,
+            MilestoneStatus::InProgress => Color::Yellow,
+            MilestoneStatus::Planned => Color::DarkGray,
+        }
+    }
+}
+
+/// A release milestone grouping phases (e.g., "v1.0 MVP")
+///
+/// Built from the ROADMAP milestone list and phase groupings, enriched with
+/// MILESTONES.md and the `milestones/vX-ROADMAP.md` archive when present.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Milestone {
+    /// Version tag (e.g., "v1.0")
+    pub version: String,
+    /// Name after the version (e.g., "MVP")
+    pub name: String,
+    /// Milestone status
+    pub status: MilestoneStatus,
+    /// Ship date as written (e.g., "2026-01-25")
+    pub shipped: Option<String>,
+    /// Phase span as written (e.g., "Phases 2-5")
+    pub phases_text: String,
+    /// Phases in this milestone
+    pub phases: Vec<PhaseId>,
+    /// `**Delivered:**` summary from MILESTONES.md
+    pub delivered: String,
+    /// `**Key accomplishments:**` from MILESTONES.md
+    pub accomplishments: Vec<String>,
+    /// `**Stats:**` from MILESTONES.md
+    pub stats: Vec<String>,
+    /// `**Git range:**` from MILESTONES.md (e.g., "ecd9d14 → d656ec2")
+    pub git_range: Option<String>,
+    /// Archive file, relative to the .planning/ directory
+    pub archive: Option<PathBuf>,
+    /// Phases loaded from the archive file (read-only)
+    pub archived_phases: Vec<Phase>,
+    /// Where this was parsed from
+    #[serde(skip)]
+    pub span: Option<SourceSpan>,
+}
+
+impl Milestone {
+    /// Version and name (e.g., "v1.0 MVP")
+    pub fn title(&self) -> String {
+        if self.name.is_empty() {
+            self.version.clone()
+        } else {
+            format!("{} {}", self.version, self.name)
+        }
+    }
+
+    /// Whether the milestone's phases come from an archive file
+    pub fn is_archived(&self) -> bool {
+        !self.archived_phases.is_empty()
+    }
+
+    /// The milestone's phases: archived phases, or the matching roadmap phases
+    pub fn phases_in<'a>(&'a self, roadmap_phases: &'a [Phase]) -> Vec<&'a Phase> {
+        if self.is_archived() {
+            self.archived_phases.iter().collect()
+        } else {
+            roadmap_phases
+                .iter()
+                .filter(|p| self.phases.contains(&p.number))
+                .collect()
+        }
+    }
+
+    /// Completion percentage across the requirements of the milestone's phases
+    ///
+    /// Shipped milestones are complete regardless of what was tracked.
+    pub fn completion_percentage(&self, roadmap_phases: &[Phase]) -> f32 {
+        if self.status == MilestoneStatus::Shipped {
+            return 100.0;
+        }
+        let phases = self.phases_in(roadmap_phases);
+        let total: usize = phases.iter().map(|p| p.requirements.len()).sum();
+        if total == 0 {
+            return 0.0;
+        }
+        let complete = phases
+            .iter()
+            .flat_map(|p| &p.requirements)
+            .filter(|r| r.status == RequirementStatus::Complete)
+            .count();
+        complete as f32 / total as f32 * 100.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::Requirement;
+
+    #[test]
+    fn test_status_from_label() {
+        assert_eq!(
+            MilestoneStatus::from_label("shipped 2026-01-25"),
+            Some((MilestoneStatus::Shipped, Some("2026-01-25".to_string())))
+        );
+        assert_eq!(
+            MilestoneStatus::from_label("In Progress"),
+            Some((MilestoneStatus::InProgress, None))
+        );
+        assert_eq!(MilestoneStatus::from_label("someday"), None);
+    }
+
+    #[test]
+    fn test_completion_uses_roadmap_phases_in_milestone() {
+        let requirement = |status| Requirement {
+            status,
+            ..Default::default()
+        };
+        let phases = vec![
+            Phase {
+                number: PhaseId::new(2),
+                requirements: vec![
+                    requirement(RequirementStatus::Complete),
+                    requirement(RequirementStatus::Pending),
+                ],
+                ..Default::default()
+            },
+            Phase {
+                number: PhaseId::new(3),
+                requirements: vec![requirement(RequirementStatus::Complete)],
+                ..Default::default()
+            },
+        ];
+        let milestone = Milestone {
+            phases: vec![PhaseId::new(2)],
+            status: MilestoneStatus::InProgress,
+            ..Default::default()
+        };
+
+        assert_eq!(milestone.completion_percentage(&phases), 50.0);
+    }
+}
@@ -1,9 +1,35 @@
+pub mod blocks;
+pub mod config;
+pub mod document;
+pub mod milestone;
 pub mod parser;
+pub mod phase_id;
+pub mod plan;
+pub mod plan_body;
 pub mod planning_state;
 pub mod project;
+pub mod requirements;
 pub mod roadmap;
+pub mod span;
+pub mod status;
 
-pub use parser::{load_planning_data, PlanningData};
-pub use planning_state::PlanningState;
+pub use config::{Config, ConfigIssue, KataConfig, Setting, SettingKind, SETTINGS};
+pub use document::{Document, DocumentKind, OutlineHeading, PhaseMention};
+pub use milestone::{Milestone, MilestoneStatus};
+pub use parser::{
+    load_planning_data, parse_requirements, parse_roadmap, parse_state, ParseWarning, PlanningData,
+};
+pub use phase_id::{ParsePhaseIdError, PhaseId};
+pub use plan::{
+    Artifact, KeyLink, MustHaveCheck, MustHaveKind, MustHaves, Plan, PlanFrontmatter, PlanSummary,
+    PlanTask, PlanVerification,
+};
+pub use planning_state::{Decision, PlanningState, StateMilestone};
 pub use project::Project;
-pub use roadmap::{Phase, PhaseStatus, Requirement, RequirementStatus, Roadmap};
+pub use requirements::{ChecklistEntry, RequirementConflict, Requirements, TraceabilityRow};
+pub use roadmap::{
+    Phase, PhaseChecklistItem, PhaseStatus, PlanChecklistItem, Requirement, RequirementStatus,
+    Roadmap,
+};
+pub use span::SourceSpan;
+pub use status::PhaseEvidence;
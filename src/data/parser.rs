@@ -1,35 +1,574 @@
-use std::path::Path;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 use color_eyre::Result;
 use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+use serde::Serialize;
 
-use super::{Phase, PhaseStatus, PlanningState, Project, Requirement, RequirementStatus, Roadmap};
+use super::blocks::{parse_blocks, Block, BlockKind, TableRow, TextLine};
+use super::plan_body::parse_plan_body;
+
+use super::{
+    ChecklistEntry, Config, Decision, Document, DocumentKind, Milestone, MilestoneStatus, Phase,
+    PhaseChecklistItem, PhaseEvidence, PhaseId, PhaseMention, PhaseStatus, Plan, PlanChecklistItem,
+    PlanSummary, PlanTask, PlanningState, Project, Requirement, RequirementStatus, Requirements,
+    Roadmap, SourceSpan, StateMilestone, TraceabilityRow,
+};
 
 /// Combined planning data from all files
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct PlanningData {
     pub project: Project,
     pub roadmap: Roadmap,
     pub state: PlanningState,
+    pub requirements: Requirements,
+    /// Project-wide documents from `research/`
+    pub research: Vec<Document>,
+    /// Kata workflow settings from config.json, if the file exists
+    pub config: Option<Config>,
+    /// Plans in `phases/` directories that match no roadmap or archived phase
+    pub unattached_plans: Vec<Plan>,
+    /// Problems the parsers skipped over, in load order
+    pub warnings: Vec<ParseWarning>,
+}
+
+impl PlanningData {
+    /// Phases total and complete, as STATE.md's metrics count them
+    ///
+    /// Shipped phases may only appear in the ROADMAP checklist or a milestone
+    /// archive, so this counts the union of the checklist, the roadmap's phases and
+    /// archived phases.
+    pub fn phase_totals(&self) -> (usize, usize) {
+        let roadmap = &self.roadmap;
+        let mut phases: HashMap<PhaseId, bool> = roadmap
+            .phase_checklist
+            .iter()
+            .map(|item| (item.number, item.checked))
+            .collect();
+        let archived = roadmap.milestones.iter().flat_map(|m| &m.archived_phases);
+        for phase in roadmap.phases.iter().chain(archived) {
+            let complete = phase.status == PhaseStatus::Complete;
+            *phases.entry(phase.number).or_default() |= complete;
+        }
+        let complete = phases.values().filter(|&&complete| complete).count();
+        (phases.len(), complete)
+    }
+
+    /// Requirements total and complete
+    ///
+    /// Counts REQUIREMENTS.md when it has a checklist, else the roadmap's phases.
+    pub fn requirement_totals(&self) -> (usize, usize) {
+        if self.requirements.checklist.is_empty() {
+            let all: Vec<&Requirement> = self
+                .roadmap
+                .phases
+                .iter()
+                .flat_map(|p| &p.requirements)
+                .collect();
+            let complete = all
+                .iter()
+                .filter(|r| r.status == RequirementStatus::Complete)
+                .count();
+            (all.len(), complete)
+        } else {
+            let statuses = self.requirements.statuses();
+            let complete = self
+                .requirements
+                .checklist
+                .iter()
+                .filter(|e| statuses.get(&e.id) == Some(&RequirementStatus::Complete))
+                .count();
+            (self.requirements.checklist.len(), complete)
+        }
+    }
+}
+
+/// A recoverable problem found while parsing a planning file
+///
+/// Parsers skip what they can't read and carry on; the warning records what was skipped.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ParseWarning {
+    pub file: PathBuf,
+    /// 1-based line number
+    pub line: usize,
+    pub message: String,
+}
+
+impl ParseWarning {
+    /// A file that exists but could not be read or parsed at all
+    fn unreadable(path: &Path, error: impl std::fmt::Display) -> Self {
+        Self {
+            file: path.to_path_buf(),
+            line: 1,
+            message: format!("could not be read: {}", error),
+        }
+    }
 }
 
 /// Load all planning data from a .planning/ directory
 ///
-/// Returns defaults for missing files. Logs warnings for other errors (permissions, corruption).
+/// Returns defaults for missing files. Other errors (permissions, corruption) become
+/// warnings; this runs inside the TUI on reload, so nothing is printed.
 pub fn load_planning_data(planning_dir: &Path) -> Result<PlanningData> {
-    let project = load_file_with_fallback(&planning_dir.join("PROJECT.md"), load_project);
-    let roadmap = load_file_with_fallback(&planning_dir.join("ROADMAP.md"), load_roadmap);
-    let state = load_file_with_fallback(&planning_dir.join("STATE.md"), load_state);
+    let mut warnings = Vec::new();
+    let project = load_file_with_fallback(
+        &planning_dir.join("PROJECT.md"),
+        load_project,
+        &mut warnings,
+    );
+    let (mut roadmap, roadmap_warnings) = load_file_with_fallback(
+        &planning_dir.join("ROADMAP.md"),
+        load_roadmap,
+        &mut warnings,
+    );
+    warnings.extend(roadmap_warnings);
+    let (state, state_warnings) =
+        load_file_with_fallback(&planning_dir.join("STATE.md"), load_state, &mut warnings);
+    warnings.extend(state_warnings);
+    let requirements = load_file_with_fallback(
+        &planning_dir.join("REQUIREMENTS.md"),
+        load_requirements,
+        &mut warnings,
+    );
+
+    let shipped = load_file_with_fallback(
+        &planning_dir.join("MILESTONES.md"),
+        load_milestones,
+        &mut warnings,
+    );
+
+    merge_shipped_milestones(&mut roadmap, shipped);
+    load_milestone_archives(&mut roadmap, planning_dir, &mut warnings);
+    apply_requirement_statuses(&mut roadmap, &requirements);
+    let unattached_plans =
+        attach_phase_plans(&mut roadmap, &planning_dir.join("phases"), &mut warnings);
+    attach_phase_documents(&mut roadmap, &planning_dir.join("phases"), &mut warnings);
+    resolve_phase_statuses(&mut roadmap, &state);
+
+    let mut research = load_research_documents(&planning_dir.join("research"), &mut warnings);
+    link_phase_mentions(&mut roadmap, &mut research);
+
+    let config_path = planning_dir.join("config.json");
+    let config = std::fs::read_to_string(&config_path)
+        .ok()
+        .map(|content| Config::parse(&config_path, &content));
 
     Ok(PlanningData {
         project,
         roadmap,
         state,
+        requirements,
+        research,
+        config,
+        unattached_plans,
+        warnings,
+    })
+}
+
+/// Join requirement statuses from REQUIREMENTS.md onto the roadmap by ID
+///
+/// Requirements not tracked in REQUIREMENTS.md keep their parsed status.
+fn apply_requirement_statuses(roadmap: &mut Roadmap, requirements: &Requirements) {
+    let statuses = requirements.statuses();
+
+    for phase in &mut roadmap.phases {
+        for req in &mut phase.requirements {
+            if let Some(&status) = statuses.get(&req.id) {
+                req.status = status;
+            }
+        }
+    }
+}
+
+/// Attach plans from `phases/NN-slug/` directories to their roadmap and archived phases
+///
+/// Returns the plans whose phase matches neither, sorted by ID.
+fn attach_phase_plans(
+    roadmap: &mut Roadmap,
+    phases_dir: &Path,
+    warnings: &mut Vec<ParseWarning>,
+) -> Vec<Plan> {
+    let mut plans_by_phase = load_phase_plans(phases_dir, warnings);
+
+    let archived = roadmap
+        .milestones
+        .iter_mut()
+        .flat_map(|m| &mut m.archived_phases);
+    for phase in roadmap.phases.iter_mut().chain(archived) {
+        if let Some(plans) = plans_by_phase.remove(&phase.number) {
+            phase.plans = plans;
+        }
+    }
+
+    let mut unattached: Vec<Plan> = plans_by_phase.into_values().flatten().collect();
+    unattached.sort_by(|a, b| a.id.cmp(&b.id));
+    unattached
+}
+
+/// Attach RESEARCH.md and VERIFICATION.md from `phases/NN-slug/` to their phases
+fn attach_phase_documents(
+    roadmap: &mut Roadmap,
+    phases_dir: &Path,
+    warnings: &mut Vec<ParseWarning>,
+) {
+    let mut documents_by_phase = load_phase_documents(phases_dir, warnings);
+
+    let archived = roadmap
+        .milestones
+        .iter_mut()
+        .flat_map(|m| &mut m.archived_phases);
+    for phase in roadmap.phases.iter_mut().chain(archived) {
+        if let Some(documents) = documents_by_phase.remove(&phase.number) {
+            phase.documents = documents;
+        }
+    }
+}
+
+/// Set each phase's status from the ROADMAP checklist, STATE.md and plan summaries
+///
+/// See `PhaseEvidence::resolve` for precedence when sources conflict.
+fn resolve_phase_statuses(roadmap: &mut Roadmap, state: &PlanningState) {
+    for phase in &mut roadmap.phases {
+        let evidence = PhaseEvidence {
+            checked_in_roadmap: roadmap
+                .phase_checklist
+                .iter()
+                .any(|item| item.number == phase.number && item.checked),
+            is_current: state.current_phase == Some(phase.number),
+            plans: phase.plans.len(),
+            summaries: phase.plans.iter().filter(|p| p.is_complete()).count(),
+        };
+        phase.status = evidence.resolve();
+    }
+}
+
+/// Load all plans, grouped by the phase number prefix of their directory
+///
+/// Directories are named `NN-slug` or, for inserted phases, `NN.M-slug`.
+/// Plans are sorted by ID within each phase. Includes plans of phases that have
+/// no ROADMAP heading (e.g. shipped phases archived into a milestone). Plans
+/// that can't be read are skipped with a warning.
+fn load_phase_plans(
+    phases_dir: &Path,
+    warnings: &mut Vec<ParseWarning>,
+) -> HashMap<PhaseId, Vec<Plan>> {
+    let mut plans: HashMap<PhaseId, Vec<Plan>> = HashMap::new();
+
+    for (number, files) in phase_dir_files(phases_dir) {
+        let phase_plans = plans.entry(number).or_default();
+        for path in files {
+            let is_plan = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.ends_with("-PLAN.md"));
+            if !is_plan {
+                continue;
+            }
+            match load_plan(&path) {
+                Ok(plan) => phase_plans.push(plan),
+                Err(e) => warnings.push(ParseWarning::unreadable(&path, e)),
+            }
+        }
+        phase_plans.sort_by(|a, b| a.id.cmp(&b.id));
+    }
+
+    plans
+}
+
+/// Load RESEARCH.md and VERIFICATION.md documents, grouped by phase number
+fn load_phase_documents(
+    phases_dir: &Path,
+    warnings: &mut Vec<ParseWarning>,
+) -> HashMap<PhaseId, Vec<Document>> {
+    let mut documents: HashMap<PhaseId, Vec<Document>> = HashMap::new();
+
+    for (number, files) in phase_dir_files(phases_dir) {
+        let mut phase_documents: Vec<Document> = files
+            .iter()
+            .filter_map(|path| {
+                let name = path.file_name()?.to_str()?;
+                load_document(path, DocumentKind::from_phase_file(name)?, warnings)
+            })
+            .collect();
+        if !phase_documents.is_empty() {
+            phase_documents.sort_by(|a, b| a.kind.cmp(&b.kind).then(a.path.cmp(&b.path)));
+            documents.entry(number).or_default().extend(phase_documents);
+        }
+    }
+
+    documents
+}
+
+/// Files in each `phases/NN-slug/` directory, keyed by the directory's phase number
+///
+/// Directories are named `NN-slug` or, for inserted phases, `NN.M-slug`;
+/// anything else is skipped.
+fn phase_dir_files(phases_dir: &Path) -> Vec<(PhaseId, Vec<PathBuf>)> {
+    let Ok(entries) = std::fs::read_dir(phases_dir) else {
+        return Vec::new(); // No phases/ directory yet
+    };
+
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            let dir = entry.path();
+            let number = dir
+                .file_name()
+                .and_then(|n| n.to_str())
+                .and_then(|n| n.split('-').next())
+                .and_then(|n| n.parse::<PhaseId>().ok())?;
+            let files = std::fs::read_dir(&dir).ok()?;
+            Some((number, files.flatten().map(|f| f.path()).collect()))
+        })
+        .collect()
+}
+
+/// Load the markdown files in `research/`, known kinds first
+fn load_research_documents(research_dir: &Path, warnings: &mut Vec<ParseWarning>) -> Vec<Document> {
+    let Ok(entries) = std::fs::read_dir(research_dir) else {
+        return Vec::new(); // No research yet
+    };
+
+    let mut documents: Vec<Document> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            let kind = DocumentKind::from_research_file(path.file_name()?.to_str()?)?;
+            load_document(&path, kind, warnings)
+        })
+        .collect();
+    documents.sort_by(|a, b| a.kind.cmp(&b.kind).then(a.path.cmp(&b.path)));
+    documents
+}
+
+/// Read and parse a document, warning when it can't be read
+fn load_document(
+    path: &Path,
+    kind: DocumentKind,
+    warnings: &mut Vec<ParseWarning>,
+) -> Option<Document> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => Some(Document::parse(kind, path, &content)),
+        Err(e) => {
+            warnings.push(ParseWarning::unreadable(path, e));
+            None
+        }
+    }
+}
+
+/// Record which sections of each document mention which phases
+///
+/// Only phases the roadmap knows about (including archived ones) are linked,
+/// so stray numbers in prose don't produce dangling references.
+fn link_phase_mentions(roadmap: &mut Roadmap, research: &mut [Document]) {
+    let mut known = known_phases(roadmap);
+    known.extend(
+        roadmap
+            .milestones
+            .iter()
+            .flat_map(|m| &m.archived_phases)
+            .map(|p| p.number),
+    );
+    known.sort();
+    known.dedup();
+
+    let archived = roadmap
+        .milestones
+        .iter_mut()
+        .flat_map(|m| &mut m.archived_phases);
+    let phase_documents = roadmap
+        .phases
+        .iter_mut()
+        .chain(archived)
+        .flat_map(|p| &mut p.documents);
+    for document in research.iter_mut().chain(phase_documents) {
+        document.mentions = find_phase_mentions(document, &known);
+    }
+}
+
+/// Phase mentions in a document, one per phase and section
+fn find_phase_mentions(document: &Document, known: &[PhaseId]) -> Vec<PhaseMention> {
+    let mut mentions: Vec<PhaseMention> = Vec::new();
+
+    for (idx, line) in document.content.lines().enumerate() {
+        let line_no = idx + 1;
+        let heading = document.heading_at(line_no).map(|h| h.text.clone());
+        for phase in parse_phase_refs(line, known) {
+            let seen = mentions
+                .iter()
+                .any(|m| m.phase == phase && m.heading == heading);
+            if known.contains(&phase) && !seen {
+                mentions.push(PhaseMention {
+                    phase,
+                    heading: heading.clone(),
+                    line: line_no,
+                });
+            }
+        }
+    }
+
+    mentions
+}
+
+/// Parse a `NN-MM-PLAN.md` file and its sibling `NN-MM-SUMMARY.md`, if any
+fn load_plan(path: &Path) -> Result<Plan> {
+    let content = std::fs::read_to_string(path)?;
+    let id = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .and_then(|n| n.strip_suffix("-PLAN.md"))
+        .unwrap_or_default()
+        .to_string();
+
+    let (frontmatter, body) = split_frontmatter(&content);
+    let frontmatter = frontmatter.and_then(|yaml| serde_yaml::from_str(yaml).ok());
+
+    let first_line = content[..content.len() - body.len()].matches('\n').count() + 1;
+    let mut body = parse_plan_body(path, body, first_line);
+
+    let summary_path = path.with_file_name(format!("{}-SUMMARY.md", id));
+    let summary = match std::fs::read_to_string(&summary_path) {
+        Ok(content) => Some(parse_plan_summary(summary_path, &content)),
+        Err(_) => None,
+    };
+    mark_completed_tasks(&mut body.tasks, summary.as_ref());
+
+    Ok(Plan {
+        id,
+        path: path.to_path_buf(),
+        frontmatter,
+        objective: body.objective,
+        context: body.context,
+        tasks: body.tasks,
+        verification: body.verification,
+        success_criteria: body.success_criteria,
+        summary,
+        must_have_checks: None,
+        span: Some(SourceSpan::file(path, &content)),
     })
 }
 
-/// Load a file with fallback to default, logging non-NotFound errors
-fn load_file_with_fallback<T, F>(path: &Path, loader: F) -> T
+/// Parse a plan summary's title and status
+fn parse_plan_summary(path: PathBuf, content: &str) -> PlanSummary {
+    let (frontmatter, body) = split_frontmatter(content);
+
+    let mut status = frontmatter.and_then(|yaml| {
+        yaml.lines()
+            .find_map(|line| line.trim().strip_prefix("status:"))
+            .map(|s| s.trim().to_string())
+    });
+
+    let mut title = String::new();
+    let mut task_mentions = Vec::new();
+    for line in body.lines() {
+        let trimmed = line.trim();
+        task_mentions.extend(summary_task_mentions(trimmed));
+        if title.is_empty() && trimmed.starts_with("# ") {
+            let heading = trimmed.trim_start_matches('#').trim();
+            // "Plan 02-01 Summary: Title" -> "Title"
+            title = heading
+                .split_once(':')
+                .map(|(_, t)| t.trim())
+                .unwrap_or(heading)
+                .to_string();
+        } else if status.is_none() && trimmed.starts_with("**Status:**") {
+            let value = trimmed.strip_prefix("**Status:**").unwrap_or("");
+            status = Some(value.trim_start_matches(['✅', ' ']).trim().to_string());
+        }
+    }
+
+    PlanSummary {
+        path,
+        title,
+        status,
+        task_mentions,
+    }
+}
+
+/// Status words that mark a task unfinished when they directly follow its label
+const UNFINISHED_TASK_STATUSES: &[&str] = &[
+    "skipped",
+    "deferred",
+    "blocked",
+    "pending",
+    "incomplete",
+    "todo",
+];
+
+/// "Task N" mentions on a summary line, each with whether the line reads as finished
+///
+/// Only explicit markers count as unfinished: an unticked checkbox, or a status word
+/// right after the label ("Task 3: deferred", "Task 2 (skipped)"). Other wording is
+/// left alone, so "Task 1: added blocking reader" still reads as done.
+fn summary_task_mentions(line: &str) -> Vec<(u32, bool)> {
+    let lower = line.to_lowercase();
+    let unticked = lower.starts_with("- [ ]") || lower.starts_with("* [ ]");
+
+    lower
+        .match_indices("task ")
+        .filter_map(|(pos, matched)| {
+            let rest = &lower[pos + matched.len()..];
+            let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+            let number = digits.parse().ok()?;
+
+            let status =
+                rest[digits.len()..].trim_start_matches([':', '-', '—', '–', '(', '*', ' ']);
+            let word = status.split(|c: char| !c.is_alphabetic()).next();
+            let unfinished = unticked
+                || status.starts_with("not done")
+                || word.is_some_and(|word| UNFINISHED_TASK_STATUSES.contains(&word));
+            Some((number, !unfinished))
+        })
+        .collect()
+}
+
+/// Infer which tasks are finished from the plan's summary
+///
+/// Without a summary nothing is finished. A summary that names tasks ("Task 2: ...")
+/// finishes exactly those it reports as done; one that names none finishes every
+/// task unless its status says the plan is incomplete.
+fn mark_completed_tasks(tasks: &mut [PlanTask], summary: Option<&PlanSummary>) {
+    let Some(summary) = summary else {
+        return;
+    };
+
+    let all_done = summary.status.as_deref().is_none_or(|status| {
+        let status = status.to_lowercase();
+        !["partial", "progress", "block", "fail", "incomplete"]
+            .iter()
+            .any(|word| status.contains(word))
+    });
+
+    for (index, task) in tasks.iter_mut().enumerate() {
+        let number = task.number().unwrap_or(index as u32 + 1);
+        task.completed = if summary.task_mentions.is_empty() {
+            all_done
+        } else {
+            summary
+                .task_mentions
+                .iter()
+                .any(|&(n, done)| n == number && done)
+        };
+    }
+}
+
+/// Split a document into YAML frontmatter (between leading `---` lines) and body
+fn split_frontmatter(content: &str) -> (Option<&str>, &str) {
+    let Some(rest) = content.strip_prefix("---\n") else {
+        return (None, content);
+    };
+
+    match rest.find("\n---") {
+        Some(end) => {
+            let body = &rest[end + 4..];
+            (Some(&rest[..end]), body.strip_prefix('\n').unwrap_or(body))
+        }
+        None => (None, content),
+    }
+}
+
+/// Load a file with fallback to default, recording non-NotFound errors as warnings
+fn load_file_with_fallback<T, F>(path: &Path, loader: F, warnings: &mut Vec<ParseWarning>) -> T
 where
     T: Default,
     F: FnOnce(&Path) -> Result<T>,
@@ -44,7 +583,7 @@ where
                 .is_some_and(|io_err| io_err.kind() == std::io::ErrorKind::NotFound);
 
             if !is_not_found {
-                eprintln!("Warning: Failed to load {}: {}", path.display(), e);
+                warnings.push(ParseWarning::unreadable(path, e));
             }
             T::default()
         }
@@ -52,316 +591,1473 @@ where
 }
 
 /// Parse PROJECT.md
+///
+/// Section bodies are kept as markdown source so the detail pane can render formatting.
 fn load_project(path: &Path) -> Result<Project> {
     let content = std::fs::read_to_string(path)?;
     let mut project = Project::default();
 
-    let parser = Parser::new(&content);
-    let mut current_section = String::new();
-    let mut _in_heading = false;
+    // Heading text and byte offset where the section body starts
+    let mut current_section: Option<(String, usize)> = None;
+    let mut in_heading = false;
     let mut heading_level = 0u8;
-    let mut text_buffer = String::new();
+    let mut heading_text = String::new();
 
-    for event in parser {
+    for (event, range) in Parser::new(&content).into_offset_iter() {
         match event {
             Event::Start(Tag::Heading { level, .. }) => {
                 // Save previous section content
-                if !current_section.is_empty() && !text_buffer.is_empty() {
-                    match current_section.to_lowercase().as_str() {
-                        s if s.contains("core value") => {
-                            project.description = text_buffer.trim().to_string()
-                        }
-                        s if s.contains("problem") => {
-                            project.problem = text_buffer.trim().to_string()
-                        }
-                        s if s.contains("solution") => {
-                            project.solution = text_buffer.trim().to_string()
-                        }
-                        _ => {}
-                    }
+                if let Some((section, start)) = current_section.take() {
+                    assign_project_section(&mut project, &section, &content[start..range.start]);
                 }
-                text_buffer.clear();
-                _in_heading = true;
+                heading_text.clear();
+                in_heading = true;
                 heading_level = level as u8;
             }
             Event::End(TagEnd::Heading(_)) => {
                 if heading_level == 1 && project.name.is_empty() {
-                    project.name = text_buffer.trim().to_string();
+                    project.name = heading_text.trim().to_string();
                 } else {
-                    current_section = text_buffer.trim().to_string();
+                    current_section = Some((heading_text.trim().to_string(), range.end));
                 }
-                text_buffer.clear();
-                _in_heading = false;
+                in_heading = false;
             }
-            Event::Text(text) => {
-                text_buffer.push_str(&text);
-            }
-            Event::SoftBreak | Event::HardBreak => {
-                text_buffer.push('\n');
+            Event::Text(text) | Event::Code(text) if in_heading => {
+                heading_text.push_str(&text);
             }
             _ => {}
         }
     }
 
     // Handle last section
-    if !current_section.is_empty() && !text_buffer.is_empty() {
-        match current_section.to_lowercase().as_str() {
-            s if s.contains("core value") => project.description = text_buffer.trim().to_string(),
-            s if s.contains("problem") => project.problem = text_buffer.trim().to_string(),
-            s if s.contains("solution") => project.solution = text_buffer.trim().to_string(),
-            _ => {}
+    if let Some((section, start)) = current_section {
+        assign_project_section(&mut project, &section, &content[start..]);
+    }
+
+    for block in parse_blocks(&content) {
+        if let BlockKind::Table { header, rows } = block.kind {
+            project
+                .decisions
+                .extend(parse_decision_table(path, &header, &rows).unwrap_or_default());
         }
     }
 
+    project.span = Some(SourceSpan::file(path, &content));
     Ok(project)
 }
 
+/// Rows of a `| Decision | Rationale | Outcome |` table, or None for other tables
+///
+/// Columns are found by header, so they may come in any order or be missing.
+fn parse_decision_table(
+    path: &Path,
+    header: &[String],
+    rows: &[TableRow],
+) -> Option<Vec<Decision>> {
+    let column = |name: &str| header.iter().position(|h| h.eq_ignore_ascii_case(name));
+    let decision = column("decision")?;
+    let (rationale, outcome) = (column("rationale"), column("outcome"));
+
+    let decisions = rows
+        .iter()
+        .map(|row| {
+            let cell = |col: Option<usize>| col.map(|c| row.cell(c)).unwrap_or_default();
+            Decision {
+                decision: row.cell(decision).to_string(),
+                rationale: cell(rationale).to_string(),
+                outcome: cell(outcome).to_string(),
+                span: Some(SourceSpan::line(path, row.line)),
+            }
+        })
+        .collect();
+    Some(decisions)
+}
+
+/// Store a PROJECT.md section body in the matching field, if any
+fn assign_project_section(project: &mut Project, heading: &str, body: &str) {
+    let body = body.trim();
+    if body.is_empty() {
+        return;
+    }
+
+    match heading.to_lowercase().as_str() {
+        s if s.contains("core value") => project.description = body.to_string(),
+        s if s.contains("problem") => project.problem = body.to_string(),
+        s if s.contains("solution") => project.solution = body.to_string(),
+        _ => {}
+    }
+}
+
+/// Labelled block of a phase section that following blocks belong to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PhaseBlock {
+    None,
+    /// `**Goal:**`; an inline goal only takes the lines wrapped onto it
+    Goal {
+        inline: bool,
+    },
+    Requirements,
+    SuccessCriteria,
+    Plans,
+    ResearchNotes,
+}
+
 /// Parse ROADMAP.md
-fn load_roadmap(path: &Path) -> Result<Roadmap> {
+fn load_roadmap(path: &Path) -> Result<(Roadmap, Vec<ParseWarning>)> {
     let content = std::fs::read_to_string(path)?;
-    let mut roadmap = Roadmap::default();
+    Ok(parse_roadmap(path, &content))
+}
+
+/// Parse ROADMAP.md content, with warnings for anything that had to be skipped
+pub fn parse_roadmap(path: &Path, content: &str) -> (Roadmap, Vec<ParseWarning>) {
+    RoadmapParser::new(path, content).parse(content)
+}
 
-    // Simple line-by-line parsing for phase structure
-    let mut current_phase: Option<Phase> = None;
-    let mut in_requirements = false;
-    let mut in_goal = false;
-    let mut goal_buffer = String::new();
+/// Builds a roadmap from the blocks of a ROADMAP.md (or milestone archive)
+///
+/// Phases are `Phase N: Name` headings at any level; a phase runs until the next
+/// heading at its level or above.
+struct RoadmapParser<'a> {
+    path: &'a Path,
+    lines: Vec<&'a str>,
+    roadmap: Roadmap,
+    warnings: Vec<ParseWarning>,
+    /// Phase being read and the level of its heading
+    phase: Option<(Phase, u8)>,
+    block: PhaseBlock,
+    /// `**Research Notes:**` inline value and the line of the label
+    notes: Option<(String, usize)>,
+    /// Inside the `## Milestones` list
+    in_milestone_list: bool,
+    /// Milestone whose heading or `<details>` group the following phases belong to,
+    /// with the level of its heading
+    group: Option<(usize, Option<u8>)>,
+}
 
-    for line in content.lines() {
-        let trimmed = line.trim();
+impl<'a> RoadmapParser<'a> {
+    fn new(path: &'a Path, content: &'a str) -> Self {
+        Self {
+            path,
+            lines: content.lines().collect(),
+            roadmap: Roadmap::default(),
+            warnings: Vec::new(),
+            phase: None,
+            block: PhaseBlock::None,
+            notes: None,
+            in_milestone_list: false,
+            group: None,
+        }
+    }
 
-        // Phase header: ### Phase N: Name
-        if trimmed.starts_with("### Phase") {
-            // Save previous phase
-            if let Some(mut phase) = current_phase.take() {
-                if in_goal && !goal_buffer.is_empty() {
-                    phase.goal = goal_buffer.trim().to_string();
-                }
-                roadmap.phases.push(phase);
+    fn parse(mut self, content: &str) -> (Roadmap, Vec<ParseWarning>) {
+        for block in parse_blocks(content) {
+            self.block(&block);
+        }
+        self.finish_phase(self.lines.len() + 1);
+
+        resolve_phase_dependencies(&mut self.roadmap);
+        let known = known_phases(&self.roadmap);
+        for milestone in &mut self.roadmap.milestones {
+            resolve_milestone_phases(milestone, &known);
+        }
+        (self.roadmap, self.warnings)
+    }
+
+    fn warn(&mut self, line: usize, message: String) {
+        self.warnings.push(ParseWarning {
+            file: self.path.to_path_buf(),
+            line,
+            message,
+        });
+    }
+
+    fn block(&mut self, block: &Block) {
+        match &block.kind {
+            BlockKind::Heading { level, text } => self.heading(*level, text, block.line),
+            BlockKind::Html(html) => self.html(html, block.line),
+            BlockKind::Text(text) => self.text(text, block.line),
+            BlockKind::Item {
+                depth,
+                checked,
+                text,
+            } => self.item(*depth, *checked, text, block.line),
+            BlockKind::Rule => self.end_block(block.line),
+            BlockKind::Table { .. } | BlockKind::Code => {}
+        }
+
+        // The phase's span runs to its last line of content, not the `---` separator
+        if block.kind != BlockKind::Rule {
+            if let Some(span) = self.phase.as_mut().and_then(|(p, _)| p.span.as_mut()) {
+                span.end_line = block.end_line;
             }
+        }
+    }
 
-            // Parse new phase
-            if let Some(rest) = trimmed.strip_prefix("### Phase") {
-                let rest = rest.trim();
-                // Format: "N: Name" or just "N"
-                let parts: Vec<&str> = rest.splitn(2, ':').collect();
-                let number = parts
-                    .first()
-                    .and_then(|s| s.trim().parse().ok())
-                    .unwrap_or(0);
-                let name = parts
-                    .get(1)
-                    .map(|s| s.trim().to_string())
-                    .unwrap_or_default();
+    fn heading(&mut self, level: u8, text: &str, line: usize) {
+        self.end_block(line);
 
-                current_phase = Some(Phase {
+        match parse_phase_heading(text) {
+            Some(Ok((number, name))) => {
+                self.finish_phase(line);
+                let phase = Phase {
                     number,
                     name,
-                    goal: String::new(),
-                    requirements: Vec::new(),
-                    status: PhaseStatus::Pending,
-                    dependencies: Vec::new(),
-                });
-                in_requirements = false;
-                in_goal = false;
-                goal_buffer.clear();
-            }
-        }
-        // Goal line
-        else if trimmed.starts_with("**Goal:**") {
-            if let Some(ref mut phase) = current_phase {
-                let goal_text = trimmed.strip_prefix("**Goal:**").unwrap_or("").trim();
-                if goal_text.is_empty() {
-                    in_goal = true;
-                } else {
-                    phase.goal = goal_text.to_string();
+                    span: Some(SourceSpan::line(self.path, line)),
+                    ..Default::default()
+                };
+                self.phase = Some((phase, level));
+                if let Some((idx, _)) = self.group {
+                    self.roadmap.milestones[idx].phases.push(number);
                 }
+                return;
             }
+            // Headings without a valid number are not phases (and must not collide)
+            Some(Err(message)) => self.warn(line, message),
+            None => {}
         }
-        // Requirements section
-        else if trimmed.starts_with("**Requirements:**") {
-            in_requirements = true;
-            in_goal = false;
-            if !goal_buffer.is_empty() {
-                if let Some(ref mut phase) = current_phase {
-                    phase.goal = goal_buffer.trim().to_string();
-                }
-            }
-            goal_buffer.clear();
-        }
-        // Requirement line: - REQ-ID: Description
-        else if in_requirements && trimmed.starts_with("- ") {
-            if let Some(ref mut phase) = current_phase {
-                let req_text = trimmed.strip_prefix("- ").unwrap_or("");
-                // Format: "REQ-ID: Description"
-                if let Some(colon_pos) = req_text.find(':') {
-                    let id = req_text[..colon_pos].trim().to_string();
-                    let desc = req_text[colon_pos + 1..].trim().to_string();
-                    phase.requirements.push(Requirement {
-                        id,
-                        description: desc,
-                        status: RequirementStatus::Pending,
-                    });
-                }
-            }
+
+        // Milestone group: `### v1.0 MVP (Phases 2-5)`
+        if let Some(milestone) = parse_milestone_title(text) {
+            self.start_group(milestone, line, Some(level));
+            return;
         }
-        // Section divider ends requirements
-        else if trimmed == "---" {
-            in_requirements = false;
-            in_goal = false;
+
+        // Any other heading at the phase's level or above starts a new section
+        if self.phase.as_ref().is_some_and(|(_, l)| level <= *l) {
+            self.finish_phase(line);
         }
-        // Collect goal text
-        else if in_goal && !trimmed.is_empty() {
-            goal_buffer.push_str(trimmed);
-            goal_buffer.push(' ');
+        let group_level = self.group.and_then(|(_, l)| l).unwrap_or(2);
+        if level <= group_level {
+            self.group = None;
         }
+        self.in_milestone_list = text.eq_ignore_ascii_case("milestones");
     }
 
-    // Save last phase
-    if let Some(mut phase) = current_phase.take() {
-        if !goal_buffer.is_empty() {
-            phase.goal = goal_buffer.trim().to_string();
+    fn html(&mut self, html: &str, line: usize) {
+        // Milestone group: `<summary>v0.1 ...</summary>`
+        if let Some(summary) = html.strip_prefix("<summary>") {
+            if let Some(milestone) = parse_milestone_title(&strip_html_tags(summary)) {
+                self.start_group(milestone, line, None);
+            }
+        } else if html.starts_with("</details") {
+            self.group = None;
         }
-        roadmap.phases.push(phase);
     }
 
-    Ok(roadmap)
-}
+    fn start_group(&mut self, milestone: Milestone, line: usize, level: Option<u8>) {
+        self.end_block(line);
+        self.finish_phase(line);
+        let milestone = Milestone {
+            span: Some(SourceSpan::line(self.path, line)),
+            ..milestone
+        };
+        let idx = merge_milestone(&mut self.roadmap.milestones, milestone);
+        self.group = Some((idx, level));
+        self.in_milestone_list = false;
+    }
 
-/// Parse STATE.md
-fn load_state(path: &Path) -> Result<PlanningState> {
-    let content = std::fs::read_to_string(path)?;
-    let mut state = PlanningState::default();
+    fn text(&mut self, text: &TextLine, line: usize) {
+        if self.phase.is_none() {
+            return;
+        }
+        // Labelled block: **Goal:** ..., **Requirements:**, Plans:, ...
+        if let Some(label) = &text.label {
+            if !self.label(label, &text.value, line) {
+                self.end_block(line);
+            }
+            return;
+        }
 
-    for line in content.lines() {
-        let trimmed = line.trim();
+        let Some((phase, _)) = self.phase.as_mut() else {
+            return;
+        };
+        match self.block {
+            // Multi-line goal is joined into one paragraph
+            PhaseBlock::Goal { inline } if !inline || text.continuation => {
+                if !phase.goal.is_empty() {
+                    phase.goal.push(' ');
+                }
+                phase.goal.push_str(&text.value);
+            }
+            PhaseBlock::Goal { .. } => self.block = PhaseBlock::None,
+            _ => {}
+        }
+    }
 
-        // **Phase:** N - Name
-        if trimmed.starts_with("**Phase:**") {
-            let rest = trimmed.strip_prefix("**Phase:**").unwrap_or("").trim();
-            // Format: "N - Name"
-            let parts: Vec<&str> = rest.splitn(2, '-').collect();
-            state.current_phase = parts
-                .first()
-                .and_then(|s| s.trim().parse().ok())
-                .unwrap_or(0);
-            state.current_phase_name = parts
-                .get(1)
-                .map(|s| s.trim().to_string())
-                .unwrap_or_default();
-        }
-        // **Plan:** value
-        else if trimmed.starts_with("**Plan:**") {
-            let value = trimmed.strip_prefix("**Plan:**").unwrap_or("").trim();
-            if !value.is_empty() && value != "Not yet created" {
-                state.current_plan = Some(value.to_string());
-            }
-        }
-        // **Status:** value
-        else if trimmed.starts_with("**Status:**") {
-            state.status = trimmed
-                .strip_prefix("**Status:**")
-                .unwrap_or("")
-                .trim()
-                .to_string();
+    /// Apply a phase label; false if the label isn't one
+    fn label(&mut self, label: &str, value: &str, line: usize) -> bool {
+        let block = match label.to_lowercase().as_str() {
+            "goal" => PhaseBlock::Goal {
+                inline: !value.is_empty(),
+            },
+            "requirements" | "requirements delivered" => PhaseBlock::Requirements,
+            // Dependencies are a single line with no block after them
+            "dependencies" | "depends on" => PhaseBlock::None,
+            "success criteria" | "success criteria met" => PhaseBlock::SuccessCriteria,
+            "plans" => PhaseBlock::Plans,
+            "research notes" => PhaseBlock::ResearchNotes,
+            _ => return false,
+        };
+        self.end_block(line);
+
+        let Some((phase, _)) = self.phase.as_mut() else {
+            return false;
+        };
+        match block {
+            PhaseBlock::Goal { .. } => phase.goal = value.to_string(),
+            PhaseBlock::None => phase.dependencies_text = value.to_string(),
+            PhaseBlock::ResearchNotes => self.notes = Some((value.to_string(), line)),
+            _ => {}
+        }
+        self.block = block;
+        true
+    }
+
+    fn item(&mut self, depth: usize, checked: Option<bool>, text: &TextLine, line: usize) {
+        // Labels may be written as list items: `- **Goal:** ...`
+        if let (Some(label), Some(_)) = (&text.label, &self.phase) {
+            if self.label(label, &text.value, line) {
+                return;
+            }
         }
-        // Table row parsing for metrics
-        else if trimmed.starts_with("| Total Phases |") {
-            if let Some(value) = extract_table_value(trimmed) {
-                state.total_phases = value.parse().unwrap_or(0);
+
+        if self.in_milestone_list {
+            if depth > 0 {
+                return;
             }
-        } else if trimmed.starts_with("| Phases Complete |") {
-            if let Some(value) = extract_table_value(trimmed) {
-                state.phases_complete = value.parse().unwrap_or(0);
+            match parse_milestone_list_item(&text.source) {
+                Some(milestone) => {
+                    let milestone = Milestone {
+                        span: Some(SourceSpan::line(self.path, line)),
+                        ..milestone
+                    };
+                    merge_milestone(&mut self.roadmap.milestones, milestone);
+                }
+                None => self.warn(
+                    line,
+                    format!("milestone list item not understood: {}", text.plain),
+                ),
             }
-        } else if trimmed.starts_with("| v1 Requirements |")
-            || trimmed.starts_with("| Total Requirements |")
+            return;
+        }
+
+        // Phase checklist item: - [x] Phase N: Name
+        if let Some(item) =
+            checked.and_then(|checked| parse_phase_checklist_item(checked, &text.plain))
         {
-            if let Some(value) = extract_table_value(trimmed) {
-                state.total_requirements = value.parse().unwrap_or(0);
+            if let Some((idx, _)) = self.group {
+                self.roadmap.milestones[idx].phases.push(item.number);
             }
-        } else if trimmed.starts_with("| Requirements Complete |") {
-            if let Some(value) = extract_table_value(trimmed) {
-                state.requirements_complete = value.parse().unwrap_or(0);
+            self.roadmap.phase_checklist.push(PhaseChecklistItem {
+                span: Some(SourceSpan::line(self.path, line)),
+                ..item
+            });
+            return;
+        }
+
+        let span = Some(SourceSpan::line(self.path, line));
+        let Some((phase, _)) = self.phase.as_mut() else {
+            return;
+        };
+        match self.block {
+            // Requirement item: - REQ-ID: Description
+            PhaseBlock::Requirements => match split_requirement(text) {
+                Some((id, description)) => phase.requirements.push(Requirement {
+                    id,
+                    description,
+                    status: RequirementStatus::Pending,
+                    span,
+                }),
+                None => self.warn(
+                    line,
+                    format!("requirement has no `ID: description` form: {}", text.plain),
+                ),
+            },
+            PhaseBlock::SuccessCriteria => phase.success_criteria.push(text.value.clone()),
+            PhaseBlock::Plans => {
+                if let Some(item) =
+                    checked.and_then(|checked| parse_plan_checklist_item(checked, &text.source))
+                {
+                    phase
+                        .plan_checklist
+                        .push(PlanChecklistItem { span, ..item });
+                }
             }
+            PhaseBlock::Goal { .. } => self.block = PhaseBlock::None,
+            PhaseBlock::ResearchNotes | PhaseBlock::None => {}
         }
     }
 
-    Ok(state)
-}
+    /// End the current labelled block at the block starting on `line`
+    fn end_block(&mut self, line: usize) {
+        // Research notes keep their markdown, so they are taken from the source lines
+        if let Some((first, label_line)) = self.notes.take() {
+            if let Some((phase, _)) = self.phase.as_mut() {
+                let rest = self.lines[label_line.min(line - 1)..line - 1]
+                    .iter()
+                    .map(|l| l.trim_end());
+                phase.research_notes = std::iter::once(first.as_str())
+                    .chain(rest)
+                    .collect::<Vec<_>>()
+                    .join("\n")
+                    .trim()
+                    .to_string();
+            }
+        }
+        self.block = PhaseBlock::None;
+    }
 
-/// Extract value from markdown table row: | Key | Value |
-fn extract_table_value(line: &str) -> Option<&str> {
-    let parts: Vec<&str> = line.split('|').collect();
-    if parts.len() >= 3 {
-        Some(parts[2].trim())
-    } else {
-        None
+    fn finish_phase(&mut self, line: usize) {
+        self.end_block(line);
+        if let Some((phase, _)) = self.phase.take() {
+            self.roadmap.phases.push(phase);
+        }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use tempfile::tempdir;
+/// Parse a phase heading: `Phase 2: Name`, `Phase 2.1 — Name` or `✅ Phase 3`
+///
+/// Returns None for other headings (including "Phase Details") and an error for
+/// phase headings whose number can't be read.
+fn parse_phase_heading(text: &str) -> Option<Result<(PhaseId, String), String>> {
+    let text = text.trim_start_matches(|c: char| !c.is_ascii_alphanumeric());
+    let rest = text
+        .get(..5)
+        .filter(|prefix| prefix.eq_ignore_ascii_case("phase"))
+        .and_then(|_| text[5..].strip_prefix(char::is_whitespace))?
+        .trim_start();
 
-    #[test]
-    fn test_load_project_basic() {
-        let dir = tempdir().unwrap();
-        let project_path = dir.path().join("PROJECT.md");
-        std::fs::write(
-            &project_path,
-            "# Test Project\n\n## Core Value\n\nA test project.\n",
-        )
-        .unwrap();
+    let end = rest
+        .find(|c: char| c.is_whitespace() || c == ':')
+        .unwrap_or(rest.len());
+    let name = rest[end..]
+        .trim_start()
+        .trim_start_matches([':', '-', '—', '–'])
+        .trim()
+        .to_string();
+    match rest[..end].parse::<PhaseId>() {
+        Ok(number) => Some(Ok((number, name))),
+        Err(_) if rest.contains(':') => {
+            Some(Err(format!("phase heading has no valid number: {}", text)))
+        }
+        Err(_) => None,
+    }
+}
 
-        let project = load_project(&project_path).unwrap();
+/// Requirement ID and description of a `REQ-ID: Description` item
+///
+/// The ID may be bold (`**REQ-ID**: ...`) or separated by a dash instead of a colon.
+fn split_requirement(text: &TextLine) -> Option<(String, String)> {
+    if let Some(label) = &text.label {
+        return Some((label.clone(), text.value.clone()));
+    }
+    let (id, _) = [":", " — ", " – ", " - "]
+        .iter()
+        .find_map(|sep| text.plain.split_once(sep))?;
+    let (_, description) = [":", " — ", " – ", " - "]
+        .iter()
+        .find_map(|sep| text.source.split_once(sep))?;
+    let id = id.trim();
+    if id.is_empty() || id.contains(' ') {
+        return None;
+    }
+    Some((id.to_string(), description.trim().to_string()))
+}
 
-        assert_eq!(project.name, "Test Project");
-        assert_eq!(project.description, "A test project.");
+/// Remove `<tag>`s from a line of HTML
+fn strip_html_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
     }
+    text.trim().to_string()
+}
 
-    #[test]
-    fn test_load_roadmap_phases() {
-        let dir = tempdir().unwrap();
-        let roadmap_path = dir.path().join("ROADMAP.md");
-        std::fs::write(
-            &roadmap_path,
-            r#"# Roadmap
+/// Split a block label line into label and inline value
+///
+/// Accepts `**Label:** value`, `**Label**: value` and the plain `Plans:` form used
+/// above plan checklists.
+fn split_block_label(line: &str) -> Option<(&str, &str)> {
+    if line == "Plans:" {
+        return Some(("Plans", ""));
+    }
+    let rest = line.strip_prefix("**")?;
+    let (label, value) = rest.split_once(":**").or_else(|| rest.split_once("**:"))?;
+    Some((label.trim(), value.trim()))
+}
 
-## Overview
+/// Parse a `Plans:` checklist item's text: `02-01-PLAN.md — Description`
+fn parse_plan_checklist_item(checked: bool, text: &str) -> Option<PlanChecklistItem> {
+    let (file, description) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+    let file = file.trim_matches('`').trim_end_matches(':');
+    let id = file.strip_suffix("-PLAN.md").unwrap_or(file);
+    if id.is_empty() {
+        return None;
+    }
 
-Test roadmap.
+    Some(PlanChecklistItem {
+        id: id.to_string(),
+        description: description
+            .trim_start()
+            .trim_start_matches(['—', '–', '-', ':'])
+            .trim()
+            .to_string(),
+        checked,
+        span: None,
+    })
+}
 
-### Phase 1: Foundation
+/// Resolve each phase's `**Dependencies:**` prose against the phases in the roadmap
+fn resolve_phase_dependencies(roadmap: &mut Roadmap) {
+    let known = known_phases(roadmap);
+    for phase in &mut roadmap.phases {
+        phase.dependencies = parse_dependencies(&phase.dependencies_text, phase.number, &known);
+    }
+}
 
-**Goal:** Build the foundation.
+/// Sorted phase numbers with a heading or checklist line in the roadmap
+fn known_phases(roadmap: &Roadmap) -> Vec<PhaseId> {
+    let mut known: Vec<PhaseId> = roadmap
+        .phases
+        .iter()
+        .map(|p| p.number)
+        .chain(roadmap.phase_checklist.iter().map(|item| item.number))
+        .collect();
+    known.sort();
+    known.dedup();
+    known
+}
 
-**Requirements:**
-- DISP-01: Display stuff
-- NAV-01: Navigate stuff
+/// Phase numbers referenced by dependency prose
+///
+/// Understands everything `parse_phase_refs` does plus "All prior phases",
+/// which expands to the `known` phases numbered below `own`.
+fn parse_dependencies(text: &str, own: PhaseId, known: &[PhaseId]) -> Vec<PhaseId> {
+    let lower = text.to_lowercase();
+    let mut deps = parse_phase_refs(text, known);
 
----
+    if ["all prior", "all previous", "all earlier"]
+        .iter()
+        .any(|phrase| lower.contains(phrase))
+    {
+        deps.extend(known.iter().copied().filter(|&n| n < own));
+    }
 
-### Phase 2: Features
+    deps.retain(|&n| n != own);
+    deps.sort();
+    deps.dedup();
+    deps
+}
 
-**Goal:** Add features.
+/// Phase numbers referenced in prose, in order of appearance
+///
+/// Understands "Phase 1 (foundation ...)", "Phase 1, Phase 3", "Phases 2-4" and
+/// "Phases 1, 2 and 3". Ranges expand to the `known` phases they cover, so
+/// inserted phases such as 2.1 are included.
+fn parse_phase_refs(text: &str, known: &[PhaseId]) -> Vec<PhaseId> {
+    let lower = text.to_lowercase();
+    let mut refs = Vec::new();
+
+    for segment in lower.split("phase").skip(1) {
+        // Numbers follow directly; parenthesized prose ends the reference
+        let segment = segment.strip_prefix('s').unwrap_or(segment);
+        let segment = segment.split(['(', ';']).next().unwrap_or_default();
+        let segment = segment
+            .replace(['–', '—'], "-")
+            .replace(" - ", "-")
+            .replace(" to ", "-");
+
+        for token in segment
+            .split([',', '&', ' '])
+            .filter(|t| !t.is_empty() && *t != "and")
+        {
+            let token = token.trim_end_matches(['.', ')']);
+            let (start, end) = token.split_once('-').unwrap_or((token, ""));
+            let Ok(start) = start.parse::<PhaseId>() else {
+                break;
+            };
+            refs.push(start);
+            match end.parse::<PhaseId>() {
+                Ok(end) => {
+                    refs.extend(known.iter().copied().filter(|&n| n > start && n < end));
+                    refs.push(end);
+                }
+                Err(_) if end.is_empty() => {}
+                Err(_) => break,
+            }
+        }
+    }
+
+    refs
+}
+
+/// Add the phases named by `phases_text` to a milestone's grouped phases
+fn resolve_milestone_phases(milestone: &mut Milestone, known: &[PhaseId]) {
+    let refs = parse_phase_refs(&milestone.phases_text, known);
+    milestone.phases.extend(refs);
+    milestone.phases.sort();
+    milestone.phases.dedup();
+}
+
+/// Parse a milestone heading: `v1.0 MVP (Phases 2-5)`, `v0.1 Foundation Preview
+/// (Phase 1) — SHIPPED 2026-01-25` or `✅ v1.0 MVP (Shipped: 2026-01-25)`
+///
+/// Returns None unless the text starts with a version tag such as "v1.0".
+fn parse_milestone_title(text: &str) -> Option<Milestone> {
+    // Skip status emoji such as ✅ or 🚧
+    let text = text.trim_start_matches(|c: char| !c.is_ascii_alphanumeric());
+    let mut chars = text.chars();
+    if !matches!(chars.next(), Some('v' | 'V')) || !chars.next().is_some_and(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let end = text.find(['(', '—', '–']).unwrap_or(text.len());
+    let title = text[..end].trim();
+    let (version, name) = title.split_once(' ').unwrap_or((title, ""));
+    let mut milestone = Milestone {
+        version: version.to_string(),
+        name: name.trim().to_string(),
+        ..Default::default()
+    };
+    apply_milestone_details(&mut milestone, &text[end..]);
+    Some(milestone)
+}
+
+/// Pick the phase span and status out of text such as "(Phase 1) — SHIPPED 2026-01-25"
+fn apply_milestone_details(milestone: &mut Milestone, text: &str) {
+    for part in text
+        .split(['(', ')', '—', '–', ','])
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+    {
+        if part.to_lowercase().starts_with("phase") {
+            milestone.phases_text = part.to_string();
+        } else if let Some((status, shipped)) = MilestoneStatus::from_label(part) {
+            milestone.status = status;
+            milestone.shipped = shipped;
+        }
+    }
+}
+
+/// Parse a ROADMAP milestone list item's text:
+/// `**v0.1 Foundation Preview** — Phase 1 (shipped 2026-01-25) [archived](milestones/v0.1-ROADMAP.md)`
+fn parse_milestone_list_item(text: &str) -> Option<Milestone> {
+    let strong = if text.contains("**") { "**" } else { "__" };
+    let start = text.find(strong)?;
+    let (title, details) = text[start + 2..].split_once(strong)?;
+    let mut milestone = parse_milestone_title(title)?;
+
+    // Markdown link to the archive file
+    let (details, link) = match details.split_once('[') {
+        Some((before, link)) => (before, Some(link)),
+        None => (details, None),
+    };
+    milestone.archive = link
+        .and_then(|link| link.split_once("](")?.1.split_once(')'))
+        .map(|(path, _)| PathBuf::from(path));
+
+    // "- [ ]"-less lists mark status with an emoji instead
+    let marker = &text[..start];
+    if marker.contains('✅') {
+        milestone.status = MilestoneStatus::Shipped;
+    } else if marker.contains('🚧') {
+        milestone.status = MilestoneStatus::InProgress;
+    }
+    apply_milestone_details(&mut milestone, details.trim_start_matches(['-', ' ']));
+    Some(milestone)
+}
+
+/// Merge a milestone into the list by version, returning its index
+///
+/// Fields already known are kept; the status takes the most advanced of the two.
+fn merge_milestone(milestones: &mut Vec<Milestone>, milestone: Milestone) -> usize {
+    let Some(idx) = milestones
+        .iter()
+        .position(|m| m.version == milestone.version)
+    else {
+        milestones.push(milestone);
+        return milestones.len() - 1;
+    };
+
+    let existing = &mut milestones[idx];
+    if existing.name.is_empty() {
+        existing.name = milestone.name;
+    }
+    existing.status = existing.status.max(milestone.status);
+    existing.shipped = existing.shipped.take().or(milestone.shipped);
+    if existing.phases_text.is_empty() {
+        existing.phases_text = milestone.phases_text;
+    }
+    existing.phases.extend(milestone.phases);
+    if existing.delivered.is_empty() {
+        existing.delivered = milestone.delivered;
+    }
+    if existing.accomplishments.is_empty() {
+        existing.accomplishments = milestone.accomplishments;
+    }
+    if existing.stats.is_empty() {
+        existing.stats = milestone.stats;
+    }
+    existing.git_range = existing.git_range.take().or(milestone.git_range);
+    existing.archive = existing.archive.take().or(milestone.archive);
+    existing.span = existing.span.take().or(milestone.span);
+    idx
+}
+
+/// Parse MILESTONES.md: a `## vX Name (Shipped: DATE)` section per shipped milestone
+fn load_milestones(path: &Path) -> Result<Vec<Milestone>> {
+    let content = std::fs::read_to_string(path)?;
+    let mut milestones: Vec<Milestone> = Vec::new();
+    // Label of the list being collected ("Key accomplishments" or "Stats")
+    let mut list: Option<&str> = None;
+
+    for (idx, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+
+        if let Some(heading) = trimmed.strip_prefix("## ") {
+            list = None;
+            if let Some(milestone) = parse_milestone_title(heading) {
+                milestones.push(Milestone {
+                    span: Some(SourceSpan::line(path, idx + 1)),
+                    ..milestone
+                });
+            }
+            continue;
+        }
+        let Some(milestone) = milestones.last_mut() else {
+            continue;
+        };
+
+        if let Some((label, value)) = split_block_label(trimmed) {
+            list = None;
+            match label {
+                "Delivered" => milestone.delivered = value.to_string(),
+                "Phases completed" if milestone.phases_text.is_empty() => {
+                    milestone.phases_text = format!("Phases {}", value);
+                }
+                "Key accomplishments" | "Stats" => list = Some(label),
+                "Git range" => milestone.git_range = Some(value.replace('`', "")),
+                _ => {}
+            }
+        } else if let Some(item) = trimmed.strip_prefix("- ") {
+            match list {
+                Some("Key accomplishments") => milestone.accomplishments.push(item.to_string()),
+                Some("Stats") => milestone.stats.push(item.to_string()),
+                _ => {}
+            }
+        } else if trimmed == "---" {
+            list = None;
+        }
+    }
+
+    Ok(milestones)
+}
+
+/// Merge MILESTONES.md entries into the roadmap's milestones
+fn merge_shipped_milestones(roadmap: &mut Roadmap, shipped: Vec<Milestone>) {
+    let known = known_phases(roadmap);
+    for milestone in shipped {
+        let idx = merge_milestone(&mut roadmap.milestones, milestone);
+        resolve_milestone_phases(&mut roadmap.milestones[idx], &known);
+    }
+}
+
+/// Load archived phases from each milestone's `milestones/vX-ROADMAP.md`
+///
+/// Uses the archive linked from the ROADMAP milestone list, falling back to the
+/// conventional file name. Phases of shipped milestones are complete.
+fn load_milestone_archives(
+    roadmap: &mut Roadmap,
+    planning_dir: &Path,
+    warnings: &mut Vec<ParseWarning>,
+) {
+    for milestone in &mut roadmap.milestones {
+        let relative = milestone.archive.clone().unwrap_or_else(|| {
+            PathBuf::from(format!("milestones/{}-ROADMAP.md", milestone.version))
+        });
+        let path = planning_dir.join(&relative);
+        if !path.is_file() {
+            continue;
+        }
+
+        let (archive, archive_warnings) = load_file_with_fallback(&path, load_roadmap, warnings);
+        warnings.extend(archive_warnings);
+        milestone.archived_phases = archive.phases;
+        milestone.archive = Some(relative);
+
+        for phase in &mut milestone.archived_phases {
+            milestone.phases.push(phase.number);
+            if milestone.status == MilestoneStatus::Shipped {
+                phase.status = PhaseStatus::Complete;
+                for req in &mut phase.requirements {
+                    req.status = RequirementStatus::Complete;
+                }
+            }
+        }
+        milestone.phases.sort();
+        milestone.phases.dedup();
+    }
+}
+
+/// Parse a phase checklist item's text: `Phase N: Name (5/5 plans) — completed DATE`
+fn parse_phase_checklist_item(checked: bool, text: &str) -> Option<PhaseChecklistItem> {
+    let rest = text.strip_prefix("Phase")?.trim_start();
+
+    let (number, name) = rest.split_once(':')?;
+    let number = number.trim().parse().ok()?;
+    let name = name
+        .split(['(', '—'])
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_string();
+
+    Some(PhaseChecklistItem {
+        number,
+        name,
+        checked,
+        span: None,
+    })
+}
+
+/// Parse STATE.md
+fn load_state(path: &Path) -> Result<(PlanningState, Vec<ParseWarning>)> {
+    let content = std::fs::read_to_string(path)?;
+    Ok(parse_state(path, &content))
+}
+
+/// Parse STATE.md content, with warnings for anything that had to be skipped
+pub fn parse_state(path: &Path, content: &str) -> (PlanningState, Vec<ParseWarning>) {
+    StateParser::new(path, content).parse(content)
+}
+
+/// STATE.md section whose body is read into the model
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StateSection {
+    Other,
+    TechnicalNotes,
+    Blockers,
+    /// `## Session Continuity` itself, for files without the subsections
+    SessionContinuity,
+    LastSession,
+    NextSession,
+}
+
+impl StateSection {
+    fn from_heading(text: &str) -> Self {
+        match text.to_lowercase().as_str() {
+            "technical notes" => Self::TechnicalNotes,
+            t if t.starts_with("blockers") => Self::Blockers,
+            "session continuity" => Self::SessionContinuity,
+            "last session" => Self::LastSession,
+            "context for next session" | "next session" => Self::NextSession,
+            _ => Self::Other,
+        }
+    }
+
+    /// Whether the section's markdown is kept whole rather than read block by block
+    fn is_verbatim(self) -> bool {
+        matches!(
+            self,
+            Self::SessionContinuity | Self::LastSession | Self::NextSession
+        )
+    }
+}
+
+/// Builds the planning state from the blocks of a STATE.md
+struct StateParser<'a> {
+    path: &'a Path,
+    lines: Vec<&'a str>,
+    state: PlanningState,
+    warnings: Vec<ParseWarning>,
+    /// Section being read and the line of its heading
+    section: (StateSection, usize),
+}
+
+impl<'a> StateParser<'a> {
+    fn new(path: &'a Path, content: &'a str) -> Self {
+        Self {
+            path,
+            lines: content.lines().collect(),
+            state: PlanningState::default(),
+            warnings: Vec::new(),
+            section: (StateSection::Other, 0),
+        }
+    }
+
+    fn parse(mut self, content: &str) -> (PlanningState, Vec<ParseWarning>) {
+        for block in parse_blocks(content) {
+            match &block.kind {
+                BlockKind::Heading { text, .. } => {
+                    self.end_section(block.line);
+                    self.section = (StateSection::from_heading(text), block.line);
+                }
+                // The footer after a rule (`*Last updated ...*`) belongs to no section
+                BlockKind::Rule => {
+                    self.end_section(block.line);
+                    self.section = (StateSection::Other, block.line);
+                }
+                BlockKind::Table { header, rows } => self.table(header, rows),
+                BlockKind::Text(text) => self.text(text, block.line, None),
+                BlockKind::Item { depth, text, .. } => self.text(text, block.line, Some(*depth)),
+                BlockKind::Code | BlockKind::Html(_) => {}
+            }
+        }
+        self.end_section(self.lines.len() + 1);
+
+        self.state.span = Some(SourceSpan::file(self.path, content));
+        (self.state, self.warnings)
+    }
+
+    fn warn(&mut self, line: usize, message: String) {
+        self.warnings.push(ParseWarning {
+            file: self.path.to_path_buf(),
+            line,
+            message,
+        });
+    }
+
+    /// Keep the markdown of a verbatim section that ends before `line`
+    fn end_section(&mut self, line: usize) {
+        let (section, heading) = self.section;
+        if !section.is_verbatim() {
+            return;
+        }
+        let body = self.lines[heading.min(line - 1)..line - 1]
+            .join("\n")
+            .trim()
+            .to_string();
+        match section {
+            StateSection::LastSession => self.state.last_session = body,
+            StateSection::NextSession => self.state.next_session = body,
+            // Without subsections, the whole section is what the next session resumes from
+            StateSection::SessionContinuity if self.state.next_session.is_empty() => {
+                self.state.next_session = body
+            }
+            _ => {}
+        }
+    }
+
+    /// A paragraph line or, with its `depth`, a list item
+    fn text(&mut self, text: &TextLine, line: usize, depth: Option<usize>) {
+        match self.section.0 {
+            StateSection::TechnicalNotes if depth == Some(0) => {
+                self.state.technical_notes.push(text.value.clone());
+                return;
+            }
+            // "None", "None currently." and the like mean no blockers
+            StateSection::Blockers if depth.unwrap_or(0) == 0 => {
+                if !text.plain.to_lowercase().starts_with("none") {
+                    self.state.blockers.push(text.value.clone());
+                }
+                return;
+            }
+            section if section.is_verbatim() => return,
+            _ => {}
+        }
+
+        let Some(label) = &text.label else {
+            return;
+        };
+        let value = text.value.as_str();
+        match label.to_lowercase().as_str() {
+            "milestone" | "current milestone" => self.state.milestone = value.to_string(),
+            // **Phase:** N - Name
+            "phase" | "current phase" => match parse_state_phase(value) {
+                Some((number, name)) => {
+                    self.state.current_phase = Some(number);
+                    self.state.current_phase_name = name;
+                }
+                None => self.warn(line, format!("phase has no number: {:?}", value)),
+            },
+            "plan" | "current plan" => {
+                let unset = ["", "-", "none", "n/a", "not started", "not yet created"];
+                if !unset.contains(&value.to_lowercase().as_str()) {
+                    self.state.current_plan = Some(value.to_string());
+                }
+            }
+            "status" => self.state.status = value.to_string(),
+            // **Progress:** [████------] 40% (2 of 5 phases complete)
+            "progress" => match parse_percentage(value) {
+                Some(progress) => self.state.progress = progress,
+                None => self.warn(line, format!("progress has no percentage: {:?}", value)),
+            },
+            "last activity" => self.state.last_activity = value.to_string(),
+            _ => {}
+        }
+    }
+
+    /// Decisions, milestones or metrics, told apart by their header
+    fn table(&mut self, header: &[String], rows: &[TableRow]) {
+        let column = |name: &str| header.iter().position(|h| h.eq_ignore_ascii_case(name));
+
+        if let Some(decisions) = parse_decision_table(self.path, header, rows) {
+            self.state.decisions.extend(decisions);
+        } else if let Some(name) = column("milestone") {
+            let (phases, status, shipped) = (column("phases"), column("status"), column("shipped"));
+            for row in rows {
+                let cell = |col: Option<usize>| col.map(|c| row.cell(c)).unwrap_or_default();
+                let shipped = cell(shipped);
+                self.state.milestones.push(StateMilestone {
+                    name: row.cell(name).to_string(),
+                    phases: cell(phases).to_string(),
+                    status: cell(status).to_string(),
+                    shipped: (!matches!(shipped, "" | "-" | "—")).then(|| shipped.to_string()),
+                });
+            }
+        } else {
+            // Metric and value columns may come in either order
+            let metric = column("metric").unwrap_or(0);
+            let value = column("value").unwrap_or(1);
+            for row in rows {
+                self.metric(row.cell(metric), row.cell(value), row.line);
+            }
+        }
+    }
+
+    fn metric(&mut self, label: &str, raw: &str, line: usize) {
+        let state = &mut self.state;
+        let parsed = match label.to_lowercase().as_str() {
+            "total phases" => raw.parse().map(|n| state.total_phases = n),
+            "phases complete" => raw.parse().map(|n| state.phases_complete = n),
+            "requirements complete" => raw.parse().map(|n| state.requirements_complete = n),
+            _ if is_requirements_total_label(label) => {
+                raw.parse().map(|n| state.total_requirements = n)
+            }
+            _ => return,
+        };
+        if parsed.is_err() {
+            self.warn(line, format!("{} is not a number: {:?}", label, raw));
+        }
+    }
+}
+
+/// Parse a `**Phase:**` value: "3 - Name", "3: Name", "Phase 3 — Name" or "3 of 5 (Name)"
+fn parse_state_phase(value: &str) -> Option<(PhaseId, String)> {
+    let value = value.trim();
+    let value = match value.get(..5) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("phase") => value[5..].trim_start(),
+        _ => value,
+    };
+
+    let end = value
+        .find(|c: char| c.is_whitespace() || c == ':' || c == '(')
+        .unwrap_or(value.len());
+    let number = value[..end].parse().ok()?;
+    let rest = value[end..].trim_start();
+
+    // "of 5 (Name)" names the phase in parentheses
+    let rest = match rest.strip_prefix("of ") {
+        Some(total) => total
+            .split_once('(')
+            .map(|(_, name)| name)
+            .unwrap_or_default(),
+        None => rest,
+    };
+    let name = rest
+        .trim_start_matches([':', '-', '—', '–', '('])
+        .trim()
+        .trim_end_matches(')')
+        .trim();
+    Some((number, name.to_string()))
+}
+
+/// First `N%` in text such as "[████------] 40% (2 of 5 phases complete)"
+fn parse_percentage(text: &str) -> Option<u8> {
+    text.split_whitespace()
+        .find_map(|word| word.strip_suffix('%')?.parse::<f32>().ok())
+        .map(|percent| percent.clamp(0.0, 100.0).round() as u8)
+}
+
+/// `Total Requirements` or a milestone-scoped `v1.0 Requirements` metric label
+pub(crate) fn is_requirements_total_label(label: &str) -> bool {
+    label
+        .strip_suffix("Requirements")
+        .map(str::trim)
+        .is_some_and(|scope| scope == "Total" || (scope.starts_with('v') && scope.len() > 1))
+}
+
+/// A metrics table row whose label is `is_requirements_total_label`, in any column
+pub(crate) fn is_requirements_total_row(line: &str) -> bool {
+    line.starts_with('|')
+        && split_table_row(line)
+            .into_iter()
+            .any(is_requirements_total_label)
+}
+
+/// Parse REQUIREMENTS.md
+///
+/// Reads `- [x] **ID**: text` checklist lines and the `| REQ-ID | Phase | Status |`
+/// traceability table. Checklist lines without a bold ID (e.g. deferred ideas) are skipped.
+fn load_requirements(path: &Path) -> Result<Requirements> {
+    let content = std::fs::read_to_string(path)?;
+    Ok(parse_requirements(path, &content))
+}
+
+/// Parse REQUIREMENTS.md content: the checklist and the traceability table
+pub fn parse_requirements(path: &Path, content: &str) -> Requirements {
+    let mut requirements = Requirements::default();
+
+    // Column indices (phase, status) of the traceability table currently being read
+    let mut table_columns: Option<(Option<usize>, usize)> = None;
+
+    for (idx, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        let span = Some(SourceSpan::line(path, idx + 1));
+
+        if !trimmed.starts_with('|') {
+            table_columns = None;
+            if let Some(entry) = parse_checklist_entry(trimmed) {
+                requirements
+                    .checklist
+                    .push(ChecklistEntry { span, ..entry });
+            }
+            continue;
+        }
+
+        let cells = split_table_row(trimmed);
+
+        match table_columns {
+            None => {
+                // Header row: | REQ-ID | Phase | Status |
+                let headers: Vec<String> = cells.iter().map(|c| c.to_lowercase()).collect();
+                let is_traceability = headers
+                    .first()
+                    .is_some_and(|h| h == "req-id" || h == "requirement" || h == "id");
+                if let (true, Some(status)) =
+                    (is_traceability, headers.iter().position(|h| h == "status"))
+                {
+                    let phase = headers.iter().position(|h| h == "phase");
+                    table_columns = Some((phase, status));
+                }
+            }
+            Some((phase_col, status_col)) => {
+                let id = cells.first().copied().unwrap_or_default();
+                if id.is_empty() || id.chars().all(|c| c == '-' || c == ':') {
+                    continue; // Separator row
+                }
+                let status = cells
+                    .get(status_col)
+                    .and_then(|s| RequirementStatus::from_label(s))
+                    .unwrap_or_default();
+                let phase = phase_col
+                    .and_then(|col| cells.get(col))
+                    .map(|s| s.to_string())
+                    .unwrap_or_default();
+                requirements.traceability.push(TraceabilityRow {
+                    id: id.to_string(),
+                    phase,
+                    status,
+                    span,
+                });
+            }
+        }
+    }
+
+    requirements
+}
+
+/// Parse a checklist line: `- [x] **ID**: Description`
+fn parse_checklist_entry(line: &str) -> Option<ChecklistEntry> {
+    let rest = line.strip_prefix("- [")?;
+    let mut chars = rest.chars();
+    let mark = chars.next()?;
+    let rest = chars.as_str().strip_prefix(']')?.trim_start();
+
+    let rest = rest.strip_prefix("**")?;
+    let end = rest.find("**")?;
+    let id = rest[..end].trim().trim_end_matches(':');
+    let description = rest[end + 2..].trim_start_matches(':').trim();
+
+    if id.is_empty() || id.contains(' ') {
+        return None;
+    }
+
+    Some(ChecklistEntry {
+        id: id.to_string(),
+        description: description.to_string(),
+        checked: matches!(mark, 'x' | 'X'),
+        span: None,
+    })
+}
+
+/// Split a markdown table row into trimmed cells
+fn split_table_row(line: &str) -> Vec<&str> {
+    line.trim()
+        .trim_start_matches('|')
+        .trim_end_matches('|')
+        .split('|')
+        .map(str::trim)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_load_project_basic() {
+        let dir = tempdir().unwrap();
+        let project_path = dir.path().join("PROJECT.md");
+        std::fs::write(
+            &project_path,
+            "# Test Project\n\n## Core Value\n\nA test project.\n",
+        )
+        .unwrap();
+
+        let project = load_project(&project_path).unwrap();
+
+        assert_eq!(project.name, "Test Project");
+        assert_eq!(project.description, "A test project.");
+    }
+
+    #[test]
+    fn test_load_project_preserves_markdown() {
+        let dir = tempdir().unwrap();
+        let project_path = dir.path().join("PROJECT.md");
+        std::fs::write(
+            &project_path,
+            "# Test Project\n\n## Core Value\n\n**Visibility.** See `everything`.\n\n## The Solution\n\nA dashboard that:\n- Shows phases\n- Updates live\n",
+        )
+        .unwrap();
+
+        let project = load_project(&project_path).unwrap();
+
+        assert_eq!(project.description, "**Visibility.** See `everything`.");
+        assert_eq!(
+            project.solution,
+            "A dashboard that:\n- Shows phases\n- Updates live"
+        );
+    }
+
+    #[test]
+    fn test_load_project_key_decisions() {
+        let dir = tempdir().unwrap();
+        let project_path = dir.path().join("PROJECT.md");
+        std::fs::write(
+            &project_path,
+            "# Test Project\n\n## Key Decisions\n\n| Decision | Outcome | Rationale |\n|---|---|---|\n| Rust | Implemented | Single binary |\n| No Windows | — Standing | Scope |\n",
+        )
+        .unwrap();
+
+        let project = load_project(&project_path).unwrap();
+
+        let rows: Vec<_> = project
+            .decisions
+            .iter()
+            .map(|d| {
+                (
+                    d.decision.as_str(),
+                    d.rationale.as_str(),
+                    d.outcome.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("Rust", "Single binary", "Implemented"),
+                ("No Windows", "Scope", "— Standing")
+            ]
+        );
+        assert_eq!(
+            project.decisions[1].span,
+            Some(SourceSpan::line(&project_path, 8))
+        );
+    }
+
+    #[test]
+    fn test_load_roadmap_phases() {
+        let dir = tempdir().unwrap();
+        let roadmap_path = dir.path().join("ROADMAP.md");
+        std::fs::write(
+            &roadmap_path,
+            r#"# Roadmap
+
+## Overview
+
+Test roadmap.
+
+### Phase 1: Foundation
+
+**Goal:** Build the foundation.
+
+**Requirements:**
+- DISP-01: Display stuff
+- NAV-01: Navigate stuff
+
+---
+
+### Phase 2: Features
+
+**Goal:** Add features.
+
+**Requirements:**
+- FEAT-01: Add feature
+
+---
+"#,
+        )
+        .unwrap();
+
+        let (roadmap, warnings) = load_roadmap(&roadmap_path).unwrap();
+        assert_eq!(warnings, vec![]);
+
+        assert_eq!(roadmap.phases.len(), 2);
+        assert_eq!(roadmap.phases[0].number, PhaseId::new(1));
+        assert_eq!(roadmap.phases[0].name, "Foundation");
+        assert_eq!(roadmap.phases[0].requirements.len(), 2);
+        assert_eq!(roadmap.phases[0].requirements[0].id, "DISP-01");
+    }
+
+    #[test]
+    fn test_source_spans_point_at_lines() {
+        let dir = tempdir().unwrap();
+        let roadmap_path = dir.path().join("ROADMAP.md");
+        std::fs::write(
+            &roadmap_path,
+            "# Roadmap\n\n- [ ] Phase 1: Foundation\n\n### Phase 1: Foundation\n\n\
+             **Requirements:**\n- DISP-01: Display stuff\n\n---\n\n## Progress\n\ntext\n",
+        )
+        .unwrap();
+        let requirements_path = dir.path().join("REQUIREMENTS.md");
+        std::fs::write(&requirements_path, REQUIREMENTS).unwrap();
+
+        let (roadmap, warnings) = load_roadmap(&roadmap_path).unwrap();
+        assert_eq!(warnings, vec![]);
+        let phase = &roadmap.phases[0];
+        assert_eq!(
+            phase.span,
+            Some(SourceSpan::new(&roadmap_path, 5, 8)),
+            "phase span stops before the separator and next section"
+        );
+        assert_eq!(
+            phase.requirements[0].span,
+            Some(SourceSpan::line(&roadmap_path, 8))
+        );
+        assert_eq!(
+            roadmap.phase_checklist[0].span,
+            Some(SourceSpan::line(&roadmap_path, 3))
+        );
+
+        let reqs = load_requirements(&requirements_path).unwrap();
+        let line_of = |needle: &str| REQUIREMENTS.lines().position(|l| l.contains(needle));
+        assert_eq!(
+            reqs.checklist[0].span.as_ref().map(|s| s.start_line - 1),
+            line_of("- [x] **DISP-04")
+        );
+        assert_eq!(
+            reqs.traceability[0].span.as_ref().map(|s| s.start_line - 1),
+            line_of("| DISP-04 |")
+        );
+    }
+
+    #[test]
+    fn test_load_roadmap_phase_sections() {
+        let dir = tempdir().unwrap();
+        let roadmap_path = dir.path().join("ROADMAP.md");
+        std::fs::write(
+            &roadmap_path,
+            r#"# Roadmap
+
+- [x] Phase 1: Foundation (5/5 plans) — completed 2026-01-25
+
+### Phase 2: Navigation
+
+**Goal:** Navigate.
+
+**Dependencies:** Phase 1 (foundation and basic navigation)
+
+**Plans:** 2 plans
+
+Plans:
+- [x] 02-01-PLAN.md — InputMode state (DISP-04)
+- [ ] 02-02-PLAN.md — Expand/collapse tree nodes
 
 **Requirements:**
-- FEAT-01: Add feature
+- NAV-03: Expand nodes
+
+**Success Criteria:**
+1. User can expand a phase
+2. User sees **progress** bars
+
+---
+
+### Phase 2.1: Hotfix
+
+**Dependencies:** Phases 1-2
 
 ---
+
+### Phase 3: Live
+
+**Dependencies:** Phase 1 (parsing), Phase 2.1 (hotfix)
+
+**Research Notes:** Debounce the watcher.
+See PITFALLS.md.
+
+---
+
+### Phase 4: Ship
+
+**Dependencies:** All prior phases (complete, tested application)
 "#,
         )
         .unwrap();
 
-        let roadmap = load_roadmap(&roadmap_path).unwrap();
+        let (roadmap, warnings) = load_roadmap(&roadmap_path).unwrap();
+        assert_eq!(warnings, vec![]);
+        let phases = &roadmap.phases;
 
-        assert_eq!(roadmap.phases.len(), 2);
-        assert_eq!(roadmap.phases[0].number, 1);
-        assert_eq!(roadmap.phases[0].name, "Foundation");
-        assert_eq!(roadmap.phases[0].requirements.len(), 2);
-        assert_eq!(roadmap.phases[0].requirements[0].id, "DISP-01");
+        assert_eq!(phases[0].dependencies, vec![PhaseId::new(1)]);
+        assert_eq!(
+            phases[0].dependencies_text,
+            "Phase 1 (foundation and basic navigation)"
+        );
+        assert_eq!(phases[0].plan_checklist.len(), 2);
+        assert_eq!(phases[0].plan_checklist[0].id, "02-01");
+        assert_eq!(
+            phases[0].plan_checklist[0].description,
+            "InputMode state (DISP-04)"
+        );
+        assert!(phases[0].plan_checklist[0].checked);
+        assert!(!phases[0].plan_checklist[1].checked);
+        assert_eq!(phases[0].requirements.len(), 1);
+        assert_eq!(
+            phases[0].success_criteria,
+            vec!["User can expand a phase", "User sees **progress** bars"]
+        );
+
+        // Ranges take in inserted phases; prose in parentheses is ignored
+        assert_eq!(
+            phases[1].dependencies,
+            vec![PhaseId::new(1), PhaseId::new(2)]
+        );
+        assert_eq!(
+            phases[2].dependencies,
+            vec![PhaseId::new(1), PhaseId::inserted(2, 1)]
+        );
+        assert_eq!(
+            phases[2].research_notes,
+            "Debounce the watcher.\nSee PITFALLS.md."
+        );
+        assert_eq!(
+            phases[3].dependencies,
+            vec![
+                PhaseId::new(1),
+                PhaseId::new(2),
+                PhaseId::inserted(2, 1),
+                PhaseId::new(3)
+            ]
+        );
     }
 
     #[test]
@@ -379,16 +2075,517 @@ Test roadmap.
 |--------|-------|
 | Total Phases | 5 |
 | Phases Complete | 1 |
+| v1.0 Requirements | 12 |
+| Requirements Complete | 5 |
 "#,
         )
         .unwrap();
 
-        let state = load_state(&state_path).unwrap();
+        let (state, warnings) = load_state(&state_path).unwrap();
+        assert_eq!(warnings, vec![]);
 
-        assert_eq!(state.current_phase, 1);
+        assert_eq!(state.current_phase, Some(PhaseId::new(1)));
         assert_eq!(state.current_phase_name, "Foundation");
         assert_eq!(state.total_phases, 5);
         assert_eq!(state.phases_complete, 1);
+        assert_eq!(state.total_requirements, 12);
+        assert_eq!(state.requirements_complete, 5);
+    }
+
+    const REQUIREMENTS: &str = r#"# Requirements
+
+### Enhanced Display (Phase 2)
+
+- [x] **DISP-04**: User can see color-coded status indicators
+- [ ] **NAV-03**: User can expand and collapse tree nodes
+
+## v2 Requirements (Deferred)
+
+- [ ] Copy commands to clipboard
+
+## Traceability
+
+| REQ-ID | Phase | Status |
+|--------|-------|--------|
+| DISP-04 | Phase 2 | Complete |
+| NAV-03 | Phase 2 | In Progress |
+"#;
+
+    #[test]
+    fn test_load_requirements_checklist_and_table() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("REQUIREMENTS.md");
+        std::fs::write(&path, REQUIREMENTS).unwrap();
+
+        let reqs = load_requirements(&path).unwrap();
+
+        assert_eq!(reqs.checklist.len(), 2);
+        assert_eq!(reqs.checklist[0].id, "DISP-04");
+        assert!(reqs.checklist[0].checked);
+        assert_eq!(
+            reqs.checklist[1].description,
+            "User can expand and collapse tree nodes"
+        );
+        assert_eq!(reqs.traceability.len(), 2);
+        assert_eq!(reqs.traceability[1].phase, "Phase 2");
+        assert_eq!(reqs.traceability[1].status, RequirementStatus::InProgress);
+    }
+
+    #[test]
+    fn test_requirement_statuses_joined_onto_roadmap() {
+        let dir = tempdir().unwrap();
+        std::fs::write(dir.path().join("REQUIREMENTS.md"), REQUIREMENTS).unwrap();
+        std::fs::write(
+            dir.path().join("ROADMAP.md"),
+            "### Phase 2: Display\n\n**Requirements:**\n- DISP-04: Colors\n- NAV-03: Expand\n- NAV-09: Untracked\n",
+        )
+        .unwrap();
+
+        let data = load_planning_data(dir.path()).unwrap();
+        let reqs = &data.roadmap.phases[0].requirements;
+
+        assert_eq!(reqs[0].status, RequirementStatus::Complete);
+        assert_eq!(reqs[1].status, RequirementStatus::InProgress);
+        assert_eq!(reqs[2].status, RequirementStatus::Pending);
+        assert!((data.roadmap.phases[0].completion_percentage() - 33.33).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_load_roadmap_phase_checklist() {
+        let dir = tempdir().unwrap();
+        let roadmap_path = dir.path().join("ROADMAP.md");
+        std::fs::write(
+            &roadmap_path,
+            "<details>\n\n- [x] Phase 1: Foundation & Core Display (5/5 plans) — completed 2026-01-25\n- [ ] Phase 2: Next\n\n</details>\n",
+        )
+        .unwrap();
+
+        let (roadmap, warnings) = load_roadmap(&roadmap_path).unwrap();
+        assert_eq!(warnings, vec![]);
+
+        assert_eq!(roadmap.phase_checklist.len(), 2);
+        assert_eq!(roadmap.phase_checklist[0].number, PhaseId::new(1));
+        assert_eq!(roadmap.phase_checklist[0].name, "Foundation & Core Display");
+        assert!(roadmap.phase_checklist[0].checked);
+        assert!(!roadmap.phase_checklist[1].checked);
+    }
+
+    #[test]
+    fn test_phase_status_resolved_from_all_sources() {
+        let dir = tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("ROADMAP.md"),
+            "- [x] Phase 1: Done\n\n### Phase 1: Done\n\n### Phase 2: Executed\n\n### Phase 3: Current\n\n### Phase 4: Started\n\n### Phase 5: Later\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("STATE.md"), "**Phase:** 3 - Current\n").unwrap();
+
+        let phase2 = dir.path().join("phases/02-executed");
+        std::fs::create_dir_all(&phase2).unwrap();
+        for file in ["02-01-PLAN.md", "02-01-SUMMARY.md", "02-RESEARCH.md"] {
+            std::fs::write(phase2.join(file), "").unwrap();
+        }
+        let phase4 = dir.path().join("phases/04-started");
+        std::fs::create_dir_all(&phase4).unwrap();
+        for file in ["04-01-PLAN.md", "04-01-SUMMARY.md", "04-02-PLAN.md"] {
+            std::fs::write(phase4.join(file), "").unwrap();
+        }
+
+        let data = load_planning_data(dir.path()).unwrap();
+        let statuses: Vec<PhaseStatus> = data.roadmap.phases.iter().map(|p| p.status).collect();
+
+        assert_eq!(
+            statuses,
+            vec![
+                PhaseStatus::Complete,
+                PhaseStatus::Complete,
+                PhaseStatus::InProgress,
+                PhaseStatus::InProgress,
+                PhaseStatus::Pending,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_load_plan_with_summary() {
+        let dir = tempdir().unwrap();
+        let phase_dir = dir.path().join("02-display");
+        std::fs::create_dir_all(&phase_dir).unwrap();
+        std::fs::write(
+            phase_dir.join("02-01-PLAN.md"),
+            r#"---
+phase: 02-display
+plan: 01
+type: execute
+wave: 1
+depends_on: []
+autonomous: true
+---
+
+<objective>
+Add color-coded status indicators.
+
+Purpose: Visibility.
+</objective>
+
+<tasks>
+
+<task type="auto">
+  <name>Task 1: Add InputMode</name>
+</task>
+
+<task type="checkpoint:human-verify">
+  <name>Task 2: Check colors</name>
+</task>
+
+</tasks>
+"#,
+        )
+        .unwrap();
+        std::fs::write(
+            phase_dir.join("02-01-SUMMARY.md"),
+            "# Plan 02-01 Summary: Status Colors\n\n**Status:** ✅ Complete\n",
+        )
+        .unwrap();
+
+        let plan = load_plan(&phase_dir.join("02-01-PLAN.md")).unwrap();
+
+        assert_eq!(plan.id, "02-01");
+        assert_eq!(plan.frontmatter.as_ref().unwrap().wave, 1);
+        assert!(plan.objective.starts_with("Add color-coded"));
+        assert_eq!(plan.tasks.len(), 2);
+        assert_eq!(plan.tasks[0].name, "Task 1: Add InputMode");
+        assert_eq!(plan.tasks[1].task_type, "checkpoint:human-verify");
+        let summary = plan.summary.unwrap();
+        assert_eq!(summary.title, "Status Colors");
+        assert_eq!(summary.status.as_deref(), Some("Complete"));
+    }
+
+    #[test]
+    fn test_task_completion_from_summary() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("03-02-PLAN.md");
+        std::fs::write(
+            &path,
+            "<tasks>\n<task type=\"auto\"><name>Task 1: Watch</name></task>\n<task type=\"auto\"><name>Task 2: Debounce</name></task>\n<task type=\"checkpoint:human-verify\"><name>Task 3: Try it</name></task>\n</tasks>\n",
+        )
+        .unwrap();
+
+        let plan = load_plan(&path).unwrap();
+        assert!(plan.tasks.iter().all(|task| !task.completed));
+        assert_eq!(plan.tasks[2].span, Some(SourceSpan::line(&path, 4)));
+
+        std::fs::write(
+            dir.path().join("03-02-SUMMARY.md"),
+            "---\nstatus: partial\n---\n# Plan 03-02 Summary: Watcher\n\n- [x] Task 1: Watch\n- Task 2: Debounce\n- [ ] Task 3: deferred to next session\n",
+        )
+        .unwrap();
+        let plan = load_plan(&path).unwrap();
+        let completed: Vec<bool> = plan.tasks.iter().map(|task| task.completed).collect();
+        assert_eq!(completed, vec![true, true, false]);
+
+        // A complete summary that names no tasks finishes them all
+        std::fs::write(
+            dir.path().join("03-02-SUMMARY.md"),
+            "# Plan 03-02 Summary: Watcher\n\n**Status:** Complete\n",
+        )
+        .unwrap();
+        let plan = load_plan(&path).unwrap();
+        assert!(plan.tasks.iter().all(|task| task.completed));
+    }
+
+    #[test]
+    fn test_summary_task_mentions_need_explicit_markers() {
+        assert_eq!(
+            summary_task_mentions("Task 1: added blocking reader"),
+            vec![(1, true)]
+        );
+        assert_eq!(
+            summary_task_mentions("- Task 2: blockers banner"),
+            vec![(2, true)]
+        );
+        assert_eq!(
+            summary_task_mentions("Task 3: Skipped, the API was pending review"),
+            vec![(3, false)]
+        );
+        assert_eq!(
+            summary_task_mentions("Task 4 (deferred) and Task 5 — done"),
+            vec![(4, false), (5, true)]
+        );
+        assert_eq!(summary_task_mentions("Task 6 - not done"), vec![(6, false)]);
+        assert_eq!(
+            summary_task_mentions("- [ ] Task 7: Wire it"),
+            vec![(7, false)]
+        );
+    }
+
+    #[test]
+    fn test_load_plan_without_frontmatter() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("03-01-PLAN.md");
+        std::fs::write(&path, "<objective>\nDo it.\n</objective>\n").unwrap();
+
+        let plan = load_plan(&path).unwrap();
+
+        assert!(plan.frontmatter.is_none());
+        assert_eq!(plan.objective, "Do it.");
+        assert!(!plan.is_complete());
+    }
+
+    #[test]
+    fn test_plans_attached_to_phases() {
+        let dir = tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("ROADMAP.md"),
+            "### Phase 1: One\n\n### Phase 2: Two\n",
+        )
+        .unwrap();
+        let phase_dir = dir.path().join("phases/02-two");
+        std::fs::create_dir_all(&phase_dir).unwrap();
+        for file in ["02-02-PLAN.md", "02-01-PLAN.md", "02-RESEARCH.md"] {
+            std::fs::write(phase_dir.join(file), "").unwrap();
+        }
+
+        let data = load_planning_data(dir.path()).unwrap();
+
+        assert!(data.roadmap.phases[0].plans.is_empty());
+        let ids: Vec<&str> = data.roadmap.phases[1]
+            .plans
+            .iter()
+            .map(|p| p.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["02-01", "02-02"]);
+    }
+
+    #[test]
+    fn test_unreadable_plan_becomes_warning() {
+        let dir = tempdir().unwrap();
+        std::fs::write(dir.path().join("ROADMAP.md"), "### Phase 2: Two\n").unwrap();
+        let phase_dir = dir.path().join("phases/02-two");
+        std::fs::create_dir_all(&phase_dir).unwrap();
+        std::fs::write(phase_dir.join("02-01-PLAN.md"), [0xff, 0xfe]).unwrap();
+        std::fs::write(phase_dir.join("02-02-PLAN.md"), "").unwrap();
+
+        let data = load_planning_data(dir.path()).unwrap();
+
+        assert_eq!(data.roadmap.phases[0].plans.len(), 1);
+        assert_eq!(data.warnings.len(), 1);
+        assert_eq!(data.warnings[0].file, phase_dir.join("02-01-PLAN.md"));
+        assert!(data.warnings[0].message.starts_with("could not be read"));
+    }
+
+    #[test]
+    fn test_unreadable_documents_and_files_become_warnings() {
+        let dir = tempdir().unwrap();
+        std::fs::write(dir.path().join("STATE.md"), [0xff, 0xfe]).unwrap();
+        let research_dir = dir.path().join("research");
+        std::fs::create_dir_all(&research_dir).unwrap();
+        std::fs::write(research_dir.join("STACK.md"), [0xff, 0xfe]).unwrap();
+
+        let data = load_planning_data(dir.path()).unwrap();
+
+        assert!(data.research.is_empty());
+        let files: Vec<PathBuf> = data.warnings.iter().map(|w| w.file.clone()).collect();
+        assert_eq!(
+            files,
+            vec![dir.path().join("STATE.md"), research_dir.join("STACK.md")]
+        );
+    }
+
+    #[test]
+    fn test_documents_attached_and_phase_mentions_linked() {
+        let dir = tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("ROADMAP.md"),
+            "### Phase 1: One\n\n### Phase 2: Two\n",
+        )
+        .unwrap();
+        let phase_dir = dir.path().join("phases/02-two");
+        std::fs::create_dir_all(&phase_dir).unwrap();
+        std::fs::write(phase_dir.join("02-VERIFICATION.md"), "# Verified\n").unwrap();
+        std::fs::write(phase_dir.join("02-RESEARCH.md"), "# Phase 2 Research\n").unwrap();
+        let research_dir = dir.path().join("research");
+        std::fs::create_dir_all(&research_dir).unwrap();
+        std::fs::write(
+            research_dir.join("PITFALLS.md"),
+            "# Pitfalls\n\n## Watcher floods\n\nHits Phase 2 and phase 2 again.\n\n\
+             ## Unicode\n\nSee Phases 1-2. Phase 7 is not planned.\n",
+        )
+        .unwrap();
+        std::fs::write(research_dir.join("STACK.md"), "# Stack\n").unwrap();
+
+        let data = load_planning_data(dir.path()).unwrap();
+
+        let kinds: Vec<DocumentKind> = data.roadmap.phases[1]
+            .documents
+            .iter()
+            .map(|d| d.kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![DocumentKind::Research, DocumentKind::Verification]
+        );
+        assert_eq!(
+            data.roadmap.phases[1].documents[0].title,
+            "Phase 2 Research"
+        );
+
+        let titles: Vec<&str> = data.research.iter().map(|d| d.title.as_str()).collect();
+        assert_eq!(titles, vec!["Stack", "Pitfalls"]);
+
+        // One mention per phase and section; unknown phases are dropped
+        let mentions: Vec<(PhaseId, Option<&str>, usize)> = data.research[1]
+            .mentions
+            .iter()
+            .map(|m| (m.phase, m.heading.as_deref(), m.line))
+            .collect();
+        assert_eq!(
+            mentions,
+            vec![
+                (PhaseId::new(2), Some("Watcher floods"), 5),
+                (PhaseId::new(1), Some("Unicode"), 9),
+                (PhaseId::new(2), Some("Unicode"), 9),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_milestones_from_roadmap_milestones_file_and_archive() {
+        let dir = tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("ROADMAP.md"),
+            r#"# Roadmap
+
+## Milestones
+
+- **v0.1 Foundation** — Phase 1 (shipped 2026-01-25) [archived](milestones/v0.1-ROADMAP.md)
+- **v1.0 MVP** — Phases 2-3 (in progress)
+
+## Phases
+
+<details>
+<summary>v0.1 Foundation (Phase 1) — SHIPPED 2026-01-25</summary>
+
+- [x] Phase 1: Foundation (2/2 plans) — completed 2026-01-25
+
+</details>
+
+### v1.0 MVP (Phases 2-3)
+
+### Phase 2: Navigation
+
+### Phase 2.1: Hotfix
+
+### Phase 3: Live
+
+## Progress
+"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("MILESTONES.md"),
+            r#"# Project Milestones
+
+## v0.1 Foundation (Shipped: 2026-01-25)
+
+**Delivered:** The basics.
+
+**Key accomplishments:**
+
+- Two-pane layout
+
+**Stats:**
+
+- 1 phase, 2 plans
+
+**Git range:** `ecd9d14` → `d656ec2`
+
+---
+"#,
+        )
+        .unwrap();
+        std::fs::create_dir_all(dir.path().join("milestones")).unwrap();
+        std::fs::write(
+            dir.path().join("milestones/v0.1-ROADMAP.md"),
+            r#"# Milestone v0.1: Foundation
+
+### Phase 1: Foundation
+
+**Goal**: Launch the TUI.
+**Depends on**: None (first phase)
+
+**Requirements Delivered:**
+- DISP-01: Display stuff
+"#,
+        )
+        .unwrap();
+
+        let data = load_planning_data(dir.path()).unwrap();
+        let milestones = &data.roadmap.milestones;
+
+        assert_eq!(milestones.len(), 2);
+        let shipped = &milestones[0];
+        assert_eq!(shipped.title(), "v0.1 Foundation");
+        assert_eq!(shipped.status, MilestoneStatus::Shipped);
+        assert_eq!(shipped.shipped.as_deref(), Some("2026-01-25"));
+        assert_eq!(shipped.delivered, "The basics.");
+        assert_eq!(shipped.accomplishments, vec!["Two-pane layout"]);
+        assert_eq!(shipped.stats, vec!["1 phase, 2 plans"]);
+        assert_eq!(shipped.git_range.as_deref(), Some("ecd9d14 → d656ec2"));
+        assert_eq!(shipped.phases, vec![PhaseId::new(1)]);
+
+        // Archived phases are read from the archive and complete once shipped
+        let archived = &shipped.archived_phases[0];
+        assert_eq!(archived.goal, "Launch the TUI.");
+        assert_eq!(archived.status, PhaseStatus::Complete);
+        assert_eq!(archived.requirements[0].status, RequirementStatus::Complete);
+
+        // The range takes in the inserted phase grouped under the heading
+        let mvp = &milestones[1];
+        assert_eq!(mvp.status, MilestoneStatus::InProgress);
+        assert_eq!(
+            mvp.phases,
+            vec![PhaseId::new(2), PhaseId::inserted(2, 1), PhaseId::new(3)]
+        );
+        assert!(!mvp.is_archived());
+    }
+
+    #[test]
+    fn test_load_roadmap_mixed_phase_numbering() {
+        let dir = tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("ROADMAP.md"),
+            r#"### Phase 1: Foundation
+
+### Phase 2: Display
+
+### Phase 2.1: Urgent Fix (INSERTED)
+
+### Phase 2.2: Another Fix (INSERTED)
+
+### Phase X: Not a phase
+
+### Phase 3: Real-time
+"#,
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("STATE.md"), "**Phase:** 2.1 - Urgent Fix\n").unwrap();
+        let phase_dir = dir.path().join("phases/02.1-urgent-fix");
+        std::fs::create_dir_all(&phase_dir).unwrap();
+        std::fs::write(phase_dir.join("02.1-01-PLAN.md"), "").unwrap();
+        std::fs::create_dir_all(dir.path().join("phases/02-display")).unwrap();
+
+        let data = load_planning_data(dir.path()).unwrap();
+        let phases = &data.roadmap.phases;
+
+        let numbers: Vec<String> = phases.iter().map(|p| p.number.to_string()).collect();
+        assert_eq!(numbers, vec!["1", "2", "2.1", "2.2", "3"]);
+        assert!(phases[2].number.is_inserted());
+        assert_eq!(data.state.current_phase, Some(PhaseId::inserted(2, 1)));
+        assert_eq!(phases[2].status, PhaseStatus::InProgress);
+        assert_eq!(phases[1].status, PhaseStatus::Pending);
+        assert!(phases[1].plans.is_empty());
+        assert_eq!(phases[2].plans[0].id, "02.1-01");
     }
 
     #[test]
@@ -400,4 +2597,221 @@ Test roadmap.
         assert!(data.project.name.is_empty());
         assert!(data.roadmap.phases.is_empty());
     }
+
+    fn fixture(path: &str) -> (PathBuf, String) {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures")
+            .join(path);
+        let content = std::fs::read_to_string(&path).unwrap();
+        (path, content)
+    }
+
+    /// Everything the roadmap parser reads, one fact per line, so layouts can be compared
+    fn roadmap_summary(roadmap: &Roadmap) -> String {
+        let ids = |phases: &[PhaseId]| {
+            phases
+                .iter()
+                .map(PhaseId::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let mut out = Vec::new();
+        for m in &roadmap.milestones {
+            out.push(format!(
+                "milestone {} {:?} {:?} {:?} [{}]",
+                m.version,
+                m.name,
+                m.status,
+                m.shipped,
+                ids(&m.phases)
+            ));
+        }
+        for item in &roadmap.phase_checklist {
+            out.push(format!(
+                "checklist {} {:?} {}",
+                item.number, item.name, item.checked
+            ));
+        }
+        for phase in &roadmap.phases {
+            out.push(format!("phase {} {:?}", phase.number, phase.name));
+            out.push(format!("  goal {:?}", phase.goal));
+            out.push(format!(
+                "  depends {:?} [{}]",
+                phase.dependencies_text,
+                ids(&phase.dependencies)
+            ));
+            for req in &phase.requirements {
+                out.push(format!("  requirement {} {:?}", req.id, req.description));
+            }
+            for criterion in &phase.success_criteria {
+                out.push(format!("  criterion {:?}", criterion));
+            }
+            for plan in &phase.plan_checklist {
+                out.push(format!(
+                    "  plan {} {:?} {}",
+                    plan.id, plan.description, plan.checked
+                ));
+            }
+            out.push(format!("  notes {:?}", phase.research_notes));
+        }
+        out.join("\n")
+    }
+
+    #[test]
+    fn test_roadmap_fixtures_parse_alike() {
+        let (path, content) = fixture("roadmap/canonical.md");
+        let (canonical, warnings) = parse_roadmap(&path, &content);
+        assert_eq!(warnings, vec![]);
+        let expected = roadmap_summary(&canonical);
+        insta::assert_snapshot!(expected);
+
+        let crlf = content.replace('\n', "\r\n");
+        let (roadmap, warnings) = parse_roadmap(&path, &crlf);
+        assert_eq!(
+            (roadmap_summary(&roadmap), warnings),
+            (expected.clone(), vec![])
+        );
+
+        for name in [
+            "h2-phases-star-bullets.md",
+            "compact.md",
+            "bulleted-labels.md",
+        ] {
+            let (path, content) = fixture(&format!("roadmap/{}", name));
+            let (roadmap, warnings) = parse_roadmap(&path, &content);
+            assert_eq!(warnings, vec![], "{}", name);
+            assert_eq!(roadmap_summary(&roadmap), expected, "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_roadmap_warnings_for_unreadable_lines() {
+        let (path, content) = fixture("roadmap/malformed.md");
+        let (roadmap, warnings) = parse_roadmap(&path, &content);
+
+        let lines: Vec<usize> = warnings.iter().map(|w| w.line).collect();
+        assert_eq!(lines, vec![5, 9, 14]);
+        assert!(warnings[1].message.contains("Phase X"));
+
+        // Parsing carries on past each problem
+        assert_eq!(roadmap.phases.len(), 1);
+        assert_eq!(roadmap.phases[0].requirements[0].id, "NAV-01");
+    }
+
+    #[test]
+    fn test_state_fixtures_parse_alike() {
+        for name in ["canonical.md", "reordered.md", "phase-prefixed.md"] {
+            let (path, content) = fixture(&format!("state/{}", name));
+            let (state, warnings) = parse_state(&path, &content);
+
+            assert_eq!(warnings, vec![], "{}", name);
+            assert_eq!(
+                (
+                    state.current_phase,
+                    state.current_phase_name.as_str(),
+                    state.current_plan.as_deref(),
+                    state.status.as_str(),
+                    state.progress,
+                ),
+                (
+                    Some(PhaseId::new(3)),
+                    "Live Updates",
+                    Some("03-02"),
+                    "Executing",
+                    40
+                ),
+                "{}",
+                name
+            );
+            assert_eq!(
+                (
+                    state.total_phases,
+                    state.phases_complete,
+                    state.total_requirements,
+                    state.requirements_complete
+                ),
+                (5, 2, 12, 5),
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_state_context_sections() {
+        let (path, content) = fixture("state/full.md");
+        let (state, warnings) = parse_state(&path, &content);
+
+        assert_eq!(warnings, vec![]);
+        assert_eq!(state.milestone, "v1.0 MVP (Phases 2-3)");
+        assert_eq!(state.last_activity, "2026-01-25 — Plan 03-01 complete");
+        assert_eq!(
+            state.milestones[1],
+            StateMilestone {
+                name: "v1.0 MVP".into(),
+                phases: "2-3".into(),
+                status: "In Progress".into(),
+                shipped: None,
+            }
+        );
+        assert_eq!(state.milestones[0].shipped.as_deref(), Some("2026-01-25"));
+
+        // Columns are found by header, in any order
+        let decision = &state.decisions[1];
+        assert_eq!(
+            (
+                decision.decision.as_str(),
+                decision.rationale.as_str(),
+                decision.outcome.as_str()
+            ),
+            (
+                "Debounce at 200ms",
+                "Editors write files in bursts",
+                "Pending"
+            )
+        );
+        assert_eq!(decision.span, Some(SourceSpan::line(&path, 27)));
+
+        assert_eq!(state.technical_notes.len(), 2);
+        assert_eq!(
+            state.blockers,
+            vec![
+                "Waiting on `notify` 7.0 for FSEvents fixes",
+                "CI runner out of disk"
+            ]
+        );
+        assert_eq!(
+            state.last_session,
+            "Plan 03-01 executed:\n- Watcher wired into the event loop"
+        );
+        // The footer after the rule is not part of the section
+        assert_eq!(state.next_session, "Run `/kata:execute-plan 03-02` next.");
+    }
+
+    #[test]
+    fn test_state_blockers_none_and_bare_session_continuity() {
+        let (state, _) = parse_state(
+            Path::new("STATE.md"),
+            "### Blockers\n\nNone currently.\n\n## Session Continuity\n\nLast session: 2026-01-25\nStopped at: Plan 03-01\n",
+        );
+
+        assert!(state.blockers.is_empty());
+        assert_eq!(
+            state.next_session,
+            "Last session: 2026-01-25\nStopped at: Plan 03-01"
+        );
+    }
+
+    #[test]
+    fn test_state_warnings_for_unreadable_values() {
+        let (path, content) = fixture("state/malformed.md");
+        let (state, warnings) = parse_state(&path, &content);
+
+        let lines: Vec<usize> = warnings.iter().map(|w| w.line).collect();
+        assert_eq!(lines, vec![3, 5, 9]);
+        assert_eq!(state.current_phase, None);
+        assert_eq!(state.current_plan, None);
+        assert_eq!(state.total_phases, 0);
+        assert_eq!(state.phases_complete, 2);
+    }
 }
@@ -0,0 +1,147 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Serialize, Serializer};
+
+/// Phase identifier supporting integer ("2") and inserted decimal ("2.1") forms
+///
+/// Kata inserts urgent work between planned phases as decimal phases, so
+/// "Phase 2.1" sorts after "Phase 2" and before "Phase 3". The part after the
+/// dot is an insertion sequence number, not a fraction: 2.10 follows 2.9.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct PhaseId {
+    major: u16,
+    minor: Option<u16>,
+}
+
+/// Error returned when a phase number cannot be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePhaseIdError(String);
+
+impl fmt::Display for ParsePhaseIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid phase number: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParsePhaseIdError {}
+
+impl PhaseId {
+    /// An integer phase (e.g., Phase 2)
+    pub const fn new(major: u16) -> Self {
+        Self { major, minor: None }
+    }
+
+    /// An inserted decimal phase (e.g., Phase 2.1)
+    pub const fn inserted(major: u16, minor: u16) -> Self {
+        Self {
+            major,
+            minor: Some(minor),
+        }
+    }
+
+    /// The integer part
+    pub fn major(&self) -> u16 {
+        self.major
+    }
+
+    /// The insertion sequence, if this is a decimal phase
+    pub fn minor(&self) -> Option<u16> {
+        self.minor
+    }
+
+    /// Whether this phase was inserted between planned phases
+    pub fn is_inserted(&self) -> bool {
+        self.minor.is_some()
+    }
+
+    /// Zero-padded form used in directory and file names (e.g., "02", "02.1")
+    pub fn padded(&self) -> String {
+        match self.minor {
+            Some(minor) => format!("{:02}.{}", self.major, minor),
+            None => format!("{:02}", self.major),
+        }
+    }
+}
+
+impl From<u16> for PhaseId {
+    fn from(major: u16) -> Self {
+        Self::new(major)
+    }
+}
+
+impl fmt::Display for PhaseId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.minor {
+            Some(minor) => write!(f, "{}.{}", self.major, minor),
+            None => write!(f, "{}", self.major),
+        }
+    }
+}
+
+/// Serialized as its display form ("2", "2.1") so decimal phases stay unambiguous
+impl Serialize for PhaseId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl FromStr for PhaseId {
+    type Err = ParsePhaseIdError;
+
+    /// Parse "2", "02", "2.1" or "02.1"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParsePhaseIdError(s.to_string());
+        let trimmed = s.trim();
+
+        let (major, minor) = match trimmed.split_once('.') {
+            Some((major, minor)) => (major, Some(minor)),
+            None => (trimmed, None),
+        };
+
+        let major = major.parse().map_err(|_| err())?;
+        let minor = minor.map(|m| m.parse().map_err(|_| err())).transpose()?;
+
+        Ok(Self { major, minor })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_integer_and_decimal() {
+        assert_eq!("2".parse::<PhaseId>(), Ok(PhaseId::new(2)));
+        assert_eq!("02".parse::<PhaseId>(), Ok(PhaseId::new(2)));
+        assert_eq!("2.1".parse::<PhaseId>(), Ok(PhaseId::inserted(2, 1)));
+        assert_eq!(" 02.1 ".parse::<PhaseId>(), Ok(PhaseId::inserted(2, 1)));
+    }
+
+    #[test]
+    fn test_parse_rejects_garbage() {
+        assert!("".parse::<PhaseId>().is_err());
+        assert!("X".parse::<PhaseId>().is_err());
+        assert!("2.".parse::<PhaseId>().is_err());
+        assert!("2.1.3".parse::<PhaseId>().is_err());
+    }
+
+    #[test]
+    fn test_ordering_places_inserted_between_integers() {
+        let mut ids: Vec<PhaseId> = ["3", "2.10", "2", "2.2", "1", "2.9"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        ids.sort();
+
+        let rendered: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+        assert_eq!(rendered, vec!["1", "2", "2.2", "2.9", "2.10", "3"]);
+    }
+
+    #[test]
+    fn test_padded() {
+        assert_eq!(PhaseId::new(2).padded(), "02");
+        assert_eq!(PhaseId::inserted(2, 1).padded(), "02.1");
+        assert_eq!(PhaseId::new(12).padded(), "12");
+    }
+}
@@ -0,0 +1,309 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use super::SourceSpan;
+
+/// A file that must exist after a plan executes (from `must_haves.artifacts`)
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Artifact {
+    /// Path relative to the repository root
+    pub path: String,
+    /// What the file provides
+    pub provides: String,
+    /// String the file must contain
+    pub contains: Option<String>,
+    /// Symbols the file must export
+    pub exports: Vec<String>,
+}
+
+/// A required connection between two pieces of code (from `must_haves.key_links`)
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct KeyLink {
+    /// Source file
+    pub from: String,
+    /// Target symbol or file
+    pub to: String,
+    /// How the link is made
+    pub via: String,
+    /// Regex expected to match in `from`
+    pub pattern: Option<String>,
+}
+
+/// Goal-backward verification criteria for a plan
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct MustHaves {
+    /// Observable behaviours that must be true
+    pub truths: Vec<String>,
+    /// Files that must exist
+    pub artifacts: Vec<Artifact>,
+    /// Connections that must be wired up
+    pub key_links: Vec<KeyLink>,
+}
+
+/// YAML frontmatter of a `NN-MM-PLAN.md` file
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct PlanFrontmatter {
+    /// Phase slug (e.g., "02-enhanced-display-navigation")
+    #[serde(deserialize_with = "string_or_number")]
+    pub phase: String,
+    /// Plan number within the phase (e.g., "01")
+    #[serde(deserialize_with = "string_or_number")]
+    pub plan: String,
+    /// Plan type (e.g., "execute")
+    #[serde(rename = "type")]
+    pub plan_type: String,
+    /// Execution wave; plans in the same wave can run in parallel
+    pub wave: u32,
+    /// Plan IDs that must complete first (e.g., "02-01")
+    pub depends_on: Vec<String>,
+    /// Files the plan expects to touch
+    pub files_modified: Vec<String>,
+    /// Whether the plan runs without human checkpoints
+    pub autonomous: bool,
+    /// Verification criteria
+    pub must_haves: MustHaves,
+}
+
+/// A `<task>` block from a plan body
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PlanTask {
+    /// Task name (e.g., "Task 1: Add InputMode enum")
+    pub name: String,
+    /// Task type attribute (e.g., "auto", "checkpoint:human-verify")
+    pub task_type: String,
+    /// Paths from `<files>`, relative to the repository root
+    pub files: Vec<String>,
+    /// Text of the `<action>` block
+    pub action: String,
+    /// Text of the `<verify>` block
+    pub verify: String,
+    /// Text of the `<done>` block: what must be true once the task is finished
+    pub done: String,
+    /// Whether the plan's SUMMARY.md shows the task as finished
+    pub completed: bool,
+    /// Where this was parsed from
+    #[serde(skip)]
+    pub span: Option<SourceSpan>,
+}
+
+impl PlanTask {
+    /// Number from a "Task N: ..." name
+    pub fn number(&self) -> Option<u32> {
+        let rest = self.name.trim().strip_prefix("Task ")?;
+        let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+        digits.parse().ok()
+    }
+
+    /// Whether the task stops for a human (`checkpoint:*` types)
+    pub fn is_checkpoint(&self) -> bool {
+        self.task_type.starts_with("checkpoint")
+    }
+}
+
+/// Which kind of `must_haves` entry a check came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MustHaveKind {
+    Artifact,
+    KeyLink,
+}
+
+/// Result of checking one `must_haves` artifact or key link against the working tree
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MustHaveCheck {
+    pub kind: MustHaveKind,
+    /// File the check reads, relative to the repository root
+    pub path: String,
+    /// What was expected (e.g., `contains "enum InputMode"`)
+    pub expectation: String,
+    /// Why the check failed, or None if it passed
+    pub failure: Option<String>,
+}
+
+impl MustHaveCheck {
+    pub fn passed(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+/// Results of checking a plan's `must_haves` against the working tree
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct PlanVerification {
+    pub checks: Vec<MustHaveCheck>,
+}
+
+impl PlanVerification {
+    /// Whether every check passed
+    pub fn passed(&self) -> bool {
+        self.checks.iter().all(MustHaveCheck::passed)
+    }
+
+    /// Number of checks that passed
+    pub fn passed_count(&self) -> usize {
+        self.checks.iter().filter(|check| check.passed()).count()
+    }
+}
+
+/// Execution summary from a matching `NN-MM-SUMMARY.md`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PlanSummary {
+    /// Path to the summary file
+    pub path: PathBuf,
+    /// Title from the first heading, without the "Plan NN-MM Summary:" prefix
+    pub title: String,
+    /// Status from frontmatter or a `**Status:**` line
+    pub status: Option<String>,
+    /// Task numbers the summary mentions, with whether each reads as finished
+    #[serde(skip)]
+    pub task_mentions: Vec<(u32, bool)>,
+}
+
+/// An executable plan within a phase
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Plan {
+    /// Plan ID from the file name (e.g., "02-01")
+    pub id: String,
+    /// Path to the PLAN.md file
+    pub path: PathBuf,
+    /// Parsed frontmatter, if present and valid
+    pub frontmatter: Option<PlanFrontmatter>,
+    /// Text of the `<objective>` block
+    pub objective: String,
+    /// `@path` references from the `<context>` block
+    pub context: Vec<String>,
+    /// Tasks in file order
+    pub tasks: Vec<PlanTask>,
+    /// Text of the `<verification>` block
+    pub verification: String,
+    /// Text of the `<success_criteria>` block
+    pub success_criteria: String,
+    /// Summary, if the plan has been executed
+    pub summary: Option<PlanSummary>,
+    /// `must_haves` checked against the working tree, once verified; None when the
+    /// plan declares no artifacts or key links
+    #[serde(skip)]
+    pub must_have_checks: Option<PlanVerification>,
+    /// Where this was parsed from
+    #[serde(skip)]
+    pub span: Option<SourceSpan>,
+}
+
+impl Plan {
+    /// Whether the plan has been executed (a SUMMARY.md exists)
+    pub fn is_complete(&self) -> bool {
+        self.summary.is_some()
+    }
+
+    /// Short title: the summary title once executed, else the objective's first line
+    pub fn title(&self) -> &str {
+        match &self.summary {
+            Some(summary) if !summary.title.is_empty() => &summary.title,
+            _ => self.objective.lines().next().unwrap_or_default(),
+        }
+    }
+}
+
+/// Accept both `plan: 01` and `plan: "01"` in frontmatter
+fn string_or_number<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrNumber {
+        String(String),
+        Number(u64),
+    }
+
+    Ok(match StringOrNumber::deserialize(deserializer)? {
+        StringOrNumber::String(s) => s,
+        StringOrNumber::Number(n) => format!("{:02}", n),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frontmatter_deserializes() {
+        let yaml = r#"
+phase: 02-enhanced-display-navigation
+plan: 01
+type: execute
+wave: 2
+depends_on: ["02-01"]
+files_modified:
+  - src/state.rs
+autonomous: true
+must_haves:
+  truths:
+    - "User sees colors"
+  artifacts:
+    - path: "src/state.rs"
+      provides: "InputMode"
+      contains: "enum InputMode"
+  key_links:
+    - from: "src/view.rs"
+      to: "InputMode::Search"
+      via: "render"
+      pattern: "state\\.input_mode"
+"#;
+
+        let fm: PlanFrontmatter = serde_yaml::from_str(yaml).unwrap();
+
+        assert_eq!(fm.plan, "01");
+        assert_eq!(fm.plan_type, "execute");
+        assert_eq!(fm.wave, 2);
+        assert_eq!(fm.depends_on, vec!["02-01"]);
+        assert!(fm.autonomous);
+        assert_eq!(
+            fm.must_haves.artifacts[0].contains.as_deref(),
+            Some("enum InputMode")
+        );
+        assert_eq!(
+            fm.must_haves.key_links[0].pattern.as_deref(),
+            Some("state\\.input_mode")
+        );
+    }
+
+    #[test]
+    fn test_title_prefers_summary() {
+        let mut plan = Plan {
+            objective: "Add things.\nMore detail.".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(plan.title(), "Add things.");
+
+        plan.summary = Some(PlanSummary {
+            title: "Things Added".to_string(),
+            ..Default::default()
+        });
+        assert_eq!(plan.title(), "Things Added");
+        assert!(plan.is_complete());
+    }
+
+    #[test]
+    fn test_task_number_and_checkpoint() {
+        let task = PlanTask {
+            name: "Task 12: Check colors".to_string(),
+            task_type: "checkpoint:human-verify".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(task.number(), Some(12));
+        assert!(task.is_checkpoint());
+
+        let unnumbered = PlanTask {
+            name: "Wire it up".to_string(),
+            task_type: "auto".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(unnumbered.number(), None);
+        assert!(!unnumbered.is_checkpoint());
+    }
+}
@@ -0,0 +1,217 @@
+use std::path::Path;
+
+use super::{PlanTask, SourceSpan};
+
+/// Sections of a PLAN.md body: the XML-style blocks that follow the frontmatter
+#[derive(Debug, Clone, Default)]
+pub struct PlanBody {
+    /// Text of `<objective>`
+    pub objective: String,
+    /// `@path` references from `<context>`, without the `@`
+    pub context: Vec<String>,
+    /// `<task>` blocks in file order
+    pub tasks: Vec<PlanTask>,
+    /// Text of `<verification>`
+    pub verification: String,
+    /// Text of `<success_criteria>`
+    pub success_criteria: String,
+}
+
+/// An `<tag attrs>inner</tag>` element
+struct TagBlock<'a> {
+    attrs: &'a str,
+    inner: &'a str,
+    /// Byte offset of the opening `<`
+    start: usize,
+    /// Byte offset just past the closing `>`
+    end: usize,
+}
+
+/// Parse the body of `path`, whose first line is line `first_line` of the file
+///
+/// Tasks get spans covering `<task>` to `</task>`; completion is left to the caller,
+/// which knows about the plan's SUMMARY.md.
+pub fn parse_plan_body(path: &Path, body: &str, first_line: usize) -> PlanBody {
+    let line_at = |offset: usize| first_line + body[..offset].matches('\n').count();
+    let text = |tag: &str| {
+        tag_blocks(body, tag)
+            .first()
+            .map(|block| block.inner.trim().to_string())
+            .unwrap_or_default()
+    };
+
+    let tasks = tag_blocks(body, "task")
+        .into_iter()
+        .map(|block| {
+            let inner_text = |tag: &str| {
+                tag_blocks(block.inner, tag)
+                    .first()
+                    .map(|child| child.inner.trim().to_string())
+                    .unwrap_or_default()
+            };
+            PlanTask {
+                name: inner_text("name"),
+                task_type: attribute(block.attrs, "type").unwrap_or_default(),
+                files: split_files(&inner_text("files")),
+                action: inner_text("action"),
+                verify: inner_text("verify"),
+                done: inner_text("done"),
+                completed: false,
+                span: Some(SourceSpan::new(
+                    path,
+                    line_at(block.start),
+                    line_at(block.end.saturating_sub(1)),
+                )),
+            }
+        })
+        .collect();
+
+    let context = text("context")
+        .split_whitespace()
+        .filter_map(|word| word.strip_prefix('@'))
+        .map(|reference| reference.trim_end_matches([',', '.', ';']).to_string())
+        .filter(|reference| !reference.is_empty())
+        .collect();
+
+    PlanBody {
+        objective: text("objective"),
+        context,
+        tasks,
+        verification: text("verification"),
+        success_criteria: text("success_criteria"),
+    }
+}
+
+/// Paths from a `<files>` block: one per line or comma-separated, bullets and backticks allowed
+fn split_files(files: &str) -> Vec<String> {
+    files
+        .split(['\n', ','])
+        .map(|file| {
+            file.trim()
+                .trim_start_matches(['-', '*'])
+                .trim()
+                .trim_matches('`')
+                .to_string()
+        })
+        .filter(|file| !file.is_empty())
+        .collect()
+}
+
+/// All `<tag attrs>inner</tag>` blocks in `content`
+///
+/// Only matches the exact tag name, so `task` does not match `<tasks>`.
+fn tag_blocks<'a>(content: &'a str, tag: &str) -> Vec<TagBlock<'a>> {
+    let open = format!("<{}", tag);
+    let close = format!("</{}>", tag);
+    let mut blocks = Vec::new();
+    let mut pos = 0;
+
+    while let Some(found) = content[pos..].find(&open) {
+        let start = pos + found;
+        let after_name = start + open.len();
+        if !content[after_name..].starts_with([' ', '>']) {
+            pos = after_name;
+            continue;
+        }
+        let Some(attrs_len) = content[after_name..].find('>') else {
+            break;
+        };
+        let inner_start = after_name + attrs_len + 1;
+        let Some(inner_len) = content[inner_start..].find(&close) else {
+            break;
+        };
+        let inner_end = inner_start + inner_len;
+        blocks.push(TagBlock {
+            attrs: content[after_name..after_name + attrs_len].trim(),
+            inner: &content[inner_start..inner_end],
+            start,
+            end: inner_end + close.len(),
+        });
+        pos = inner_end + close.len();
+    }
+
+    blocks
+}
+
+/// Get an attribute value from a tag's attribute string: `type="auto"`
+fn attribute(attrs: &str, name: &str) -> Option<String> {
+    let pattern = format!("{}=\"", name);
+    let start = attrs.find(&pattern)? + pattern.len();
+    let end = attrs[start..].find('"')?;
+    Some(attrs[start..start + end].to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BODY: &str = r#"<objective>
+Add search.
+</objective>
+
+<context>
+@.planning/PROJECT.md
+See also @src/state.rs.
+</context>
+
+<tasks>
+
+<task type="auto">
+  <name>Task 1: Add matcher</name>
+  <files>
+    - `src/search.rs`
+    src/lib.rs
+  </files>
+  <action>Wrap nucleo.</action>
+  <verify>cargo test search</verify>
+  <done>Matcher scores queries</done>
+</task>
+
+<task type="checkpoint:human-verify">
+  <name>Task 2: Try it</name>
+  <files>src/app.rs, src/view.rs</files>
+</task>
+
+</tasks>
+
+<verification>
+cargo test
+</verification>
+
+<success_criteria>
+Search works.
+</success_criteria>
+"#;
+
+    #[test]
+    fn test_parse_plan_body_sections() {
+        let body = parse_plan_body(Path::new("02-05-PLAN.md"), BODY, 10);
+
+        assert_eq!(body.objective, "Add search.");
+        assert_eq!(body.context, vec![".planning/PROJECT.md", "src/state.rs"]);
+        assert_eq!(body.verification, "cargo test");
+        assert_eq!(body.success_criteria, "Search works.");
+    }
+
+    #[test]
+    fn test_parse_plan_body_tasks() {
+        let body = parse_plan_body(Path::new("02-05-PLAN.md"), BODY, 10);
+
+        let [first, second] = body.tasks.as_slice() else {
+            panic!("expected two tasks, got {:?}", body.tasks);
+        };
+        assert_eq!(first.name, "Task 1: Add matcher");
+        assert_eq!(first.files, vec!["src/search.rs", "src/lib.rs"]);
+        assert_eq!(first.action, "Wrap nucleo.");
+        assert_eq!(first.verify, "cargo test search");
+        assert_eq!(first.done, "Matcher scores queries");
+        assert_eq!(
+            first.span,
+            Some(SourceSpan::new(Path::new("02-05-PLAN.md"), 21, 30))
+        );
+
+        assert_eq!(second.task_type, "checkpoint:human-verify");
+        assert_eq!(second.files, vec!["src/app.rs", "src/view.rs"]);
+        assert!(second.action.is_empty());
+    }
+}
@@ -1,8 +1,14 @@
+use serde::Serialize;
+
+use super::{PhaseId, SourceSpan};
+
 /// Current project state from STATE.md
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct PlanningState {
-    /// Current phase number
-    pub current_phase: u8,
+    /// `**Milestone:**` as written (e.g., "v1.0 MVP (Phases 2-5)")
+    pub milestone: String,
+    /// Current phase number, if STATE.md names one
+    pub current_phase: Option<PhaseId>,
     /// Current phase name
     pub current_phase_name: String,
     /// Current plan within phase
@@ -11,6 +17,8 @@ pub struct PlanningState {
     pub status: String,
     /// Progress percentage
     pub progress: u8,
+    /// `**Last activity:**` as written (e.g., "2026-01-25 — Phase 2 complete")
+    pub last_activity: String,
     /// Total phases count
     pub total_phases: u8,
     /// Phases complete count
@@ -19,4 +27,41 @@ pub struct PlanningState {
     pub total_requirements: u16,
     /// Requirements complete count
     pub requirements_complete: u16,
+    /// Rows of the `## Milestones` table
+    pub milestones: Vec<StateMilestone>,
+    /// Rows of the `### Key Decisions` table
+    pub decisions: Vec<Decision>,
+    /// `### Technical Notes` list items
+    pub technical_notes: Vec<String>,
+    /// `### Blockers` items; empty when the section says "None"
+    pub blockers: Vec<String>,
+    /// `### Last Session` markdown
+    pub last_session: String,
+    /// `### Context for Next Session` markdown
+    pub next_session: String,
+    /// Where this was parsed from
+    #[serde(skip)]
+    pub span: Option<SourceSpan>,
+}
+
+/// A row of the STATE.md `| Milestone | Phases | Status | Shipped |` table
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct StateMilestone {
+    pub name: String,
+    /// Phases as written (e.g., "2-5")
+    pub phases: String,
+    pub status: String,
+    /// Ship date, if shipped
+    pub shipped: Option<String>,
+}
+
+/// A row of a `| Decision | Rationale | Outcome |` table
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Decision {
+    pub decision: String,
+    pub rationale: String,
+    pub outcome: String,
+    /// Where this was parsed from
+    #[serde(skip)]
+    pub span: Option<SourceSpan>,
 }
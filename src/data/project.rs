@@ -1,12 +1,21 @@
+use serde::Serialize;
+
+use super::{Decision, SourceSpan};
+
 /// Project metadata from PROJECT.md
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct Project {
     /// Project name (from first H1)
     pub name: String,
-    /// Core value/description
+    /// Core value/description (markdown)
     pub description: String,
-    /// The problem being solved
+    /// The problem being solved (markdown)
     pub problem: String,
-    /// The solution approach
+    /// The solution approach (markdown)
     pub solution: String,
+    /// Rows of the `## Key Decisions` table
+    pub decisions: Vec<Decision>,
+    /// Where this was parsed from
+    #[serde(skip)]
+    pub span: Option<SourceSpan>,
 }
@@ -0,0 +1,236 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::Serialize;
+
+use super::{RequirementStatus, SourceSpan};
+
+/// A checklist line from REQUIREMENTS.md: `- [x] **ID**: text`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ChecklistEntry {
+    /// Requirement ID (e.g., "DISP-04")
+    pub id: String,
+    /// Description text after the ID
+    pub description: String,
+    /// Whether the checkbox is ticked
+    pub checked: bool,
+    /// Where this was parsed from
+    #[serde(skip)]
+    pub span: Option<SourceSpan>,
+}
+
+/// A row of the traceability table: `| REQ-ID | Phase | Status |`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TraceabilityRow {
+    /// Requirement ID
+    pub id: String,
+    /// Phase reference as written (e.g., "Phase 2")
+    pub phase: String,
+    /// Status column
+    pub status: RequirementStatus,
+    /// Where this was parsed from
+    #[serde(skip)]
+    pub span: Option<SourceSpan>,
+}
+
+/// A disagreement between the checklist and the traceability table
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RequirementConflict {
+    /// Both sources list the ID but with different statuses
+    StatusMismatch {
+        id: String,
+        checklist: RequirementStatus,
+        traceability: RequirementStatus,
+    },
+    /// The ID is ticked off in the checklist but absent from the table
+    MissingFromTraceability { id: String },
+    /// The ID is in the table but has no checklist line
+    MissingFromChecklist { id: String },
+}
+
+impl RequirementConflict {
+    /// The requirement ID this conflict concerns
+    pub fn id(&self) -> &str {
+        match self {
+            RequirementConflict::StatusMismatch { id, .. }
+            | RequirementConflict::MissingFromTraceability { id }
+            | RequirementConflict::MissingFromChecklist { id } => id,
+        }
+    }
+}
+
+impl fmt::Display for RequirementConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RequirementConflict::StatusMismatch {
+                id,
+                checklist,
+                traceability,
+            } => write!(
+                f,
+                "{}: checklist says {}, traceability says {}",
+                id,
+                checklist.label(),
+                traceability.label()
+            ),
+            RequirementConflict::MissingFromTraceability { id } => {
+                write!(f, "{}: missing from traceability table", id)
+            }
+            RequirementConflict::MissingFromChecklist { id } => {
+                write!(f, "{}: missing from requirements checklist", id)
+            }
+        }
+    }
+}
+
+/// Requirement tracking data from REQUIREMENTS.md
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Requirements {
+    /// Checklist entries in file order
+    pub checklist: Vec<ChecklistEntry>,
+    /// Traceability table rows in file order
+    pub traceability: Vec<TraceabilityRow>,
+}
+
+impl Requirements {
+    /// Resolved status for each requirement ID
+    ///
+    /// When the checklist and the traceability table disagree, the more advanced
+    /// status wins: a requirement ticked in either place counts as complete.
+    pub fn statuses(&self) -> HashMap<String, RequirementStatus> {
+        let mut statuses: HashMap<String, RequirementStatus> = HashMap::new();
+
+        let checklist = self
+            .checklist
+            .iter()
+            .map(|e| (e.id.as_str(), RequirementStatus::from_checkbox(e.checked)));
+        let traceability = self.traceability.iter().map(|r| (r.id.as_str(), r.status));
+
+        for (id, status) in checklist.chain(traceability) {
+            let entry = statuses.entry(id.to_string()).or_default();
+            *entry = (*entry).max(status);
+        }
+
+        statuses
+    }
+
+    /// Find disagreements between the checklist and the traceability table
+    ///
+    /// Returns nothing when the file has no traceability table at all.
+    pub fn conflicts(&self) -> Vec<RequirementConflict> {
+        if self.traceability.is_empty() {
+            return Vec::new();
+        }
+
+        let mut conflicts = Vec::new();
+
+        for entry in &self.checklist {
+            let checklist = RequirementStatus::from_checkbox(entry.checked);
+            match self.traceability.iter().find(|r| r.id == entry.id) {
+                // A checkbox cannot express "in progress", so only complete vs not counts
+                Some(row)
+                    if (row.status == RequirementStatus::Complete)
+                        != (checklist == RequirementStatus::Complete) =>
+                {
+                    conflicts.push(RequirementConflict::StatusMismatch {
+                        id: entry.id.clone(),
+                        checklist,
+                        traceability: row.status,
+                    });
+                }
+                Some(_) => {}
+                None => conflicts.push(RequirementConflict::MissingFromTraceability {
+                    id: entry.id.clone(),
+                }),
+            }
+        }
+
+        for row in &self.traceability {
+            if !self.checklist.iter().any(|e| e.id == row.id) {
+                conflicts.push(RequirementConflict::MissingFromChecklist { id: row.id.clone() });
+            }
+        }
+
+        conflicts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: &str, checked: bool) -> ChecklistEntry {
+        ChecklistEntry {
+            id: id.to_string(),
+            description: String::new(),
+            checked,
+            span: None,
+        }
+    }
+
+    fn row(id: &str, status: RequirementStatus) -> TraceabilityRow {
+        TraceabilityRow {
+            id: id.to_string(),
+            phase: "Phase 1".to_string(),
+            status,
+            span: None,
+        }
+    }
+
+    #[test]
+    fn test_statuses_take_most_advanced() {
+        let reqs = Requirements {
+            checklist: vec![entry("A-01", true), entry("A-02", false)],
+            traceability: vec![
+                row("A-01", RequirementStatus::Pending),
+                row("A-02", RequirementStatus::InProgress),
+            ],
+        };
+
+        let statuses = reqs.statuses();
+
+        assert_eq!(statuses["A-01"], RequirementStatus::Complete);
+        assert_eq!(statuses["A-02"], RequirementStatus::InProgress);
+    }
+
+    #[test]
+    fn test_conflicts_reports_mismatch_and_missing() {
+        let reqs = Requirements {
+            checklist: vec![entry("A-01", true), entry("A-02", false)],
+            traceability: vec![
+                row("A-01", RequirementStatus::Pending),
+                row("A-03", RequirementStatus::Pending),
+            ],
+        };
+
+        let conflicts = reqs.conflicts();
+
+        assert_eq!(
+            conflicts,
+            vec![
+                RequirementConflict::StatusMismatch {
+                    id: "A-01".to_string(),
+                    checklist: RequirementStatus::Complete,
+                    traceability: RequirementStatus::Pending,
+                },
+                RequirementConflict::MissingFromTraceability {
+                    id: "A-02".to_string()
+                },
+                RequirementConflict::MissingFromChecklist {
+                    id: "A-03".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_in_progress_unchecked_is_not_a_conflict() {
+        let reqs = Requirements {
+            checklist: vec![entry("A-01", false)],
+            traceability: vec![row("A-01", RequirementStatus::InProgress)],
+        };
+
+        assert!(reqs.conflicts().is_empty());
+    }
+}
@@ -1,7 +1,13 @@
 use ratatui::style::Color;
+use serde::Serialize;
+
+use super::{Document, Milestone, PhaseId, Plan, SourceSpan};
 
 /// Status of a requirement
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+///
+/// Ordered by progress, so `max` picks the most advanced status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum RequirementStatus {
     #[default]
     Pending,
@@ -18,6 +24,25 @@ impl RequirementStatus {
         }
     }
 
+    /// Parse a status label such as "Complete" or "In Progress" (case-insensitive)
+    pub fn from_label(label: &str) -> Option<Self> {
+        match label.trim().to_lowercase().as_str() {
+            "complete" | "completed" | "done" => Some(Self::Complete),
+            "in progress" | "in-progress" | "active" => Some(Self::InProgress),
+            "pending" | "not started" | "planned" => Some(Self::Pending),
+            _ => None,
+        }
+    }
+
+    /// Human-readable label for this status
+    pub fn label(&self) -> &'static str {
+        match self {
+            RequirementStatus::Complete => "Complete",
+            RequirementStatus::InProgress => "In Progress",
+            RequirementStatus::Pending => "Pending",
+        }
+    }
+
     /// Get the display color for this status
     pub fn color(&self) -> Color {
         match self {
@@ -29,7 +54,7 @@ impl RequirementStatus {
 }
 
 /// A single requirement (e.g., DISP-01)
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct Requirement {
     /// Requirement ID (e.g., "DISP-01")
     pub id: String,
@@ -37,10 +62,14 @@ pub struct Requirement {
     pub description: String,
     /// Current status
     pub status: RequirementStatus,
+    /// Where this was parsed from
+    #[serde(skip)]
+    pub span: Option<SourceSpan>,
 }
 
 /// Status of a phase
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum PhaseStatus {
     #[default]
     Pending,
@@ -60,10 +89,10 @@ impl PhaseStatus {
 }
 
 /// A project phase
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct Phase {
-    /// Phase number (1, 2, 3...)
-    pub number: u8,
+    /// Phase number (1, 2, 2.1, 3...)
+    pub number: PhaseId,
     /// Phase name
     pub name: String,
     /// Phase goal
@@ -72,8 +101,23 @@ pub struct Phase {
     pub requirements: Vec<Requirement>,
     /// Phase status
     pub status: PhaseStatus,
-    /// Dependency phase numbers
-    pub dependencies: Vec<u8>,
+    /// Dependency phase numbers, resolved from `dependencies_text`
+    pub dependencies: Vec<PhaseId>,
+    /// `**Dependencies:**` as written (e.g., "Phase 1 (foundation and basic navigation)")
+    pub dependencies_text: String,
+    /// `**Success Criteria:**` numbered list items
+    pub success_criteria: Vec<String>,
+    /// `Plans:` checklist from the ROADMAP
+    pub plan_checklist: Vec<PlanChecklistItem>,
+    /// `**Research Notes:**` markdown
+    pub research_notes: String,
+    /// Plans from the phase directory, sorted by ID
+    pub plans: Vec<Plan>,
+    /// RESEARCH.md and VERIFICATION.md from the phase directory
+    pub documents: Vec<Document>,
+    /// Where this was parsed from
+    #[serde(skip)]
+    pub span: Option<SourceSpan>,
 }
 
 impl Phase {
@@ -89,15 +133,56 @@ impl Phase {
             .count();
         (complete as f32 / self.requirements.len() as f32) * 100.0
     }
+
+    /// Whether every verified plan passed its must_haves checks; None if no plan was verified
+    pub fn must_haves_passed(&self) -> Option<bool> {
+        self.plans
+            .iter()
+            .filter_map(|plan| plan.must_have_checks.as_ref())
+            .map(|verification| verification.passed())
+            .reduce(|a, b| a && b)
+    }
+}
+
+/// A `- [x] Phase N: Name` line from the ROADMAP milestone checklist
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PhaseChecklistItem {
+    /// Phase number
+    pub number: PhaseId,
+    /// Phase name as written in the checklist
+    pub name: String,
+    /// Whether the checkbox is ticked
+    pub checked: bool,
+    /// Where this was parsed from
+    #[serde(skip)]
+    pub span: Option<SourceSpan>,
+}
+
+/// A `- [x] 02-01-PLAN.md — Description` line from a phase's `Plans:` list
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PlanChecklistItem {
+    /// Plan ID taken from the file name (e.g., "02-01")
+    pub id: String,
+    /// Description after the file name
+    pub description: String,
+    /// Whether the checkbox is ticked
+    pub checked: bool,
+    /// Where this was parsed from
+    #[serde(skip)]
+    pub span: Option<SourceSpan>,
 }
 
 /// Project roadmap containing all phases
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct Roadmap {
     /// Overview text
     pub overview: String,
     /// All phases
     pub phases: Vec<Phase>,
+    /// Phase checklist lines (e.g. shipped phases collapsed into a `<details>` block)
+    pub phase_checklist: Vec<PhaseChecklistItem>,
+    /// Milestones in roadmap order
+    pub milestones: Vec<Milestone>,
 }
 
 #[cfg(test)]
@@ -170,6 +255,23 @@ mod tests {
         assert!((percentage - 33.33).abs() < 0.01);
     }
 
+    #[test]
+    fn test_requirement_status_from_label() {
+        assert_eq!(
+            RequirementStatus::from_label("Complete"),
+            Some(RequirementStatus::Complete)
+        );
+        assert_eq!(
+            RequirementStatus::from_label(" in progress "),
+            Some(RequirementStatus::InProgress)
+        );
+        assert_eq!(
+            RequirementStatus::from_label("Pending"),
+            Some(RequirementStatus::Pending)
+        );
+        assert_eq!(RequirementStatus::from_label("Blocked"), None);
+    }
+
     #[test]
     fn test_status_colors() {
         assert_eq!(PhaseStatus::Complete.color(), Color::Green);
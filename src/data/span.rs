@@ -0,0 +1,48 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Where a parsed element came from: a file and a 1-based, inclusive line range
+///
+/// Spans are left out of exports since the paths are machine-specific.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SourceSpan {
+    pub path: PathBuf,
+    pub start_line: usize,
+    pub end_line: usize,
+}
+
+impl SourceSpan {
+    pub fn new(path: &Path, start_line: usize, end_line: usize) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            start_line,
+            end_line: end_line.max(start_line),
+        }
+    }
+
+    /// Span of a single line
+    pub fn line(path: &Path, line: usize) -> Self {
+        Self::new(path, line, line)
+    }
+
+    /// Span of a whole file with the given content
+    pub fn file(path: &Path, content: &str) -> Self {
+        Self::new(path, 1, content.lines().count())
+    }
+}
+
+impl fmt::Display for SourceSpan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.start_line == self.end_line {
+            write!(f, "{}:{}", self.path.display(), self.start_line)
+        } else {
+            write!(
+                f,
+                "{}:{}-{}",
+                self.path.display(),
+                self.start_line,
+                self.end_line
+            )
+        }
+    }
+}
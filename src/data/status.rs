@@ -0,0 +1,92 @@
+use super::PhaseStatus;
+
+/// Evidence about a phase's progress gathered from the planning files
+///
+/// Each source on its own is incomplete: the ROADMAP checklist is only ticked when a
+/// milestone ships, STATE.md only names the phase being worked on, and plan
+/// summaries only exist once plans have been executed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PhaseEvidence {
+    /// `- [x] Phase N:` is ticked in the ROADMAP.md checklist
+    pub checked_in_roadmap: bool,
+    /// STATE.md names this phase as the current phase
+    pub is_current: bool,
+    /// Number of `NN-MM-PLAN.md` files in the phase directory
+    pub plans: usize,
+    /// Number of those plans with a matching `NN-MM-SUMMARY.md`
+    pub summaries: usize,
+}
+
+impl PhaseEvidence {
+    /// Resolve the phase status from all available evidence
+    ///
+    /// Precedence, highest first:
+    /// 1. Ticked in the ROADMAP checklist → Complete (an explicit human sign-off)
+    /// 2. Every plan has a summary → Complete (all planned work executed)
+    /// 3. Current phase in STATE.md → InProgress
+    /// 4. Some plans have summaries → InProgress (execution has started)
+    /// 5. Otherwise → Pending
+    ///
+    /// Completion evidence outranks STATE.md because STATE.md is updated by hand
+    /// and often still names a phase that has just finished.
+    pub fn resolve(&self) -> PhaseStatus {
+        if self.checked_in_roadmap || (self.plans > 0 && self.summaries >= self.plans) {
+            PhaseStatus::Complete
+        } else if self.is_current || self.summaries > 0 {
+            PhaseStatus::InProgress
+        } else {
+            PhaseStatus::Pending
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_evidence_is_pending() {
+        assert_eq!(PhaseEvidence::default().resolve(), PhaseStatus::Pending);
+    }
+
+    #[test]
+    fn test_roadmap_checklist_wins() {
+        let evidence = PhaseEvidence {
+            checked_in_roadmap: true,
+            is_current: true,
+            plans: 3,
+            summaries: 1,
+        };
+        assert_eq!(evidence.resolve(), PhaseStatus::Complete);
+    }
+
+    #[test]
+    fn test_all_plans_summarized_beats_current_phase() {
+        let evidence = PhaseEvidence {
+            is_current: true,
+            plans: 2,
+            summaries: 2,
+            ..Default::default()
+        };
+        assert_eq!(evidence.resolve(), PhaseStatus::Complete);
+    }
+
+    #[test]
+    fn test_current_phase_is_in_progress() {
+        let evidence = PhaseEvidence {
+            is_current: true,
+            ..Default::default()
+        };
+        assert_eq!(evidence.resolve(), PhaseStatus::InProgress);
+    }
+
+    #[test]
+    fn test_partial_summaries_are_in_progress() {
+        let evidence = PhaseEvidence {
+            plans: 3,
+            summaries: 1,
+            ..Default::default()
+        };
+        assert_eq!(evidence.resolve(), PhaseStatus::InProgress);
+    }
+}
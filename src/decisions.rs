@@ -0,0 +1,198 @@
+use std::cmp::Ordering;
+
+use crate::data::{Decision, PlanningData};
+use crate::search::FuzzyMatcher;
+
+/// Column the decisions table is sorted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DecisionColumn {
+    #[default]
+    Source,
+    Decision,
+    Outcome,
+    Rationale,
+}
+
+impl DecisionColumn {
+    /// Columns in display order
+    pub const ALL: [DecisionColumn; 4] = [
+        DecisionColumn::Decision,
+        DecisionColumn::Outcome,
+        DecisionColumn::Rationale,
+        DecisionColumn::Source,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            DecisionColumn::Decision => "Decision",
+            DecisionColumn::Outcome => "Outcome",
+            DecisionColumn::Rationale => "Rationale",
+            DecisionColumn::Source => "Source",
+        }
+    }
+
+    /// Next column to sort by, wrapping around
+    pub fn next(self) -> Self {
+        let pos = Self::ALL.iter().position(|&c| c == self).unwrap_or(0);
+        Self::ALL[(pos + 1) % Self::ALL.len()]
+    }
+}
+
+/// Key decisions from PROJECT.md followed by those from STATE.md
+pub fn all_decisions(data: &PlanningData) -> Vec<&Decision> {
+    data.project
+        .decisions
+        .iter()
+        .chain(&data.state.decisions)
+        .collect()
+}
+
+/// File and line a decision was read from (e.g., "PROJECT.md:119")
+pub fn decision_source(decision: &Decision) -> String {
+    let Some(span) = &decision.span else {
+        return String::new();
+    };
+    let file = span
+        .path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    format!("{}:{}", file, span.start_line)
+}
+
+/// Text of a decision's cell in `column`
+pub fn decision_cell(decision: &Decision, column: DecisionColumn) -> String {
+    match column {
+        DecisionColumn::Decision => decision.decision.clone(),
+        DecisionColumn::Outcome => decision.outcome.clone(),
+        DecisionColumn::Rationale => decision.rationale.clone(),
+        DecisionColumn::Source => decision_source(decision),
+    }
+}
+
+/// Indices into `decisions` matching the query, sorted by `column`
+///
+/// The sort is stable, so rows that tie keep file order. Source sorts by file and
+/// then line number rather than as plain text.
+pub fn visible_decisions(
+    matcher: &mut FuzzyMatcher,
+    query: &str,
+    decisions: &[&Decision],
+    column: DecisionColumn,
+    descending: bool,
+) -> Vec<usize> {
+    let mut rows: Vec<usize> = decisions
+        .iter()
+        .enumerate()
+        .filter(|(_, decision)| {
+            let haystack = format!(
+                "{} {} {}",
+                decision.decision, decision.rationale, decision.outcome
+            );
+            matcher.matches(query, &haystack)
+        })
+        .map(|(idx, _)| idx)
+        .collect();
+
+    let compare = |a: &usize, b: &usize| -> Ordering {
+        let (a, b) = (decisions[*a], decisions[*b]);
+        match column {
+            DecisionColumn::Source => {
+                let key = |d: &Decision| d.span.as_ref().map(|s| (s.path.clone(), s.start_line));
+                key(a).cmp(&key(b))
+            }
+            _ => decision_cell(a, column)
+                .to_lowercase()
+                .cmp(&decision_cell(b, column).to_lowercase()),
+        }
+    };
+    rows.sort_by(|a, b| {
+        let order = compare(a, b);
+        if descending {
+            order.reverse()
+        } else {
+            order
+        }
+    });
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::SourceSpan;
+    use std::path::Path;
+
+    fn decision(file: &str, line: usize, text: &str, outcome: &str) -> Decision {
+        Decision {
+            decision: text.to_string(),
+            rationale: format!("Because {}", text.to_lowercase()),
+            outcome: outcome.to_string(),
+            span: Some(SourceSpan::line(Path::new(file), line)),
+        }
+    }
+
+    fn fixture() -> Vec<Decision> {
+        vec![
+            decision("PROJECT.md", 10, "Tokio runtime", "Implemented"),
+            decision("PROJECT.md", 11, "File watching", "Pending"),
+            decision("STATE.md", 4, "Debounce at 200ms", "Implemented"),
+        ]
+    }
+
+    #[test]
+    fn test_source_order_by_default() {
+        let decisions = fixture();
+        let refs: Vec<&Decision> = decisions.iter().collect();
+        let mut matcher = FuzzyMatcher::new();
+
+        let rows = visible_decisions(&mut matcher, "", &refs, DecisionColumn::default(), false);
+
+        assert_eq!(rows, vec![0, 1, 2]);
+        assert_eq!(decision_source(refs[2]), "STATE.md:4");
+    }
+
+    #[test]
+    fn test_sort_by_column_is_stable() {
+        let decisions = fixture();
+        let refs: Vec<&Decision> = decisions.iter().collect();
+        let mut matcher = FuzzyMatcher::new();
+
+        let rows = visible_decisions(&mut matcher, "", &refs, DecisionColumn::Outcome, false);
+        assert_eq!(rows, vec![0, 2, 1]);
+
+        let rows = visible_decisions(&mut matcher, "", &refs, DecisionColumn::Decision, true);
+        assert_eq!(rows, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_filter_matches_any_column() {
+        let decisions = fixture();
+        let refs: Vec<&Decision> = decisions.iter().collect();
+        let mut matcher = FuzzyMatcher::new();
+
+        let rows = visible_decisions(
+            &mut matcher,
+            "pending",
+            &refs,
+            DecisionColumn::Source,
+            false,
+        );
+        assert_eq!(rows, vec![1]);
+
+        let rows = visible_decisions(
+            &mut matcher,
+            "because deb",
+            &refs,
+            DecisionColumn::Source,
+            false,
+        );
+        assert_eq!(rows, vec![2]);
+    }
+
+    #[test]
+    fn test_next_column_wraps() {
+        assert_eq!(DecisionColumn::Decision.next(), DecisionColumn::Outcome);
+        assert_eq!(DecisionColumn::Source.next(), DecisionColumn::Decision);
+    }
+}
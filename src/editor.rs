@@ -0,0 +1,57 @@
+use crate::data::SourceSpan;
+
+/// Editor used when neither `$EDITOR` nor `$VISUAL` is set
+pub const DEFAULT_EDITOR: &str = "vi";
+
+/// The user's editor command, split into program and leading arguments
+///
+/// Reads `$EDITOR`, then `$VISUAL`, so values like `code --wait` work.
+pub fn editor_command() -> Vec<String> {
+    let value = std::env::var("EDITOR")
+        .ok()
+        .filter(|v| !v.trim().is_empty())
+        .or_else(|| std::env::var("VISUAL").ok())
+        .unwrap_or_default();
+    split_command(&value)
+}
+
+/// Split an editor command on whitespace, falling back to `DEFAULT_EDITOR`
+fn split_command(value: &str) -> Vec<String> {
+    let parts: Vec<String> = value.split_whitespace().map(String::from).collect();
+    if parts.is_empty() {
+        vec![DEFAULT_EDITOR.to_string()]
+    } else {
+        parts
+    }
+}
+
+/// Arguments opening `span` at its first line (`+line path`)
+///
+/// The `+line` form is understood by vi, vim, nvim, emacs and nano.
+pub fn editor_args(span: &SourceSpan) -> Vec<String> {
+    vec![
+        format!("+{}", span.start_line.max(1)),
+        span.path.to_string_lossy().into_owned(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn test_editor_args_jump_to_start_line() {
+        let span = SourceSpan::new(Path::new("/p/.planning/ROADMAP.md"), 12, 30);
+        assert_eq!(
+            editor_args(&span),
+            vec!["+12".to_string(), "/p/.planning/ROADMAP.md".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_split_command_keeps_flags_and_falls_back() {
+        assert_eq!(split_command("code --wait"), vec!["code", "--wait"]);
+        assert_eq!(split_command("  "), vec![DEFAULT_EDITOR]);
+    }
+}
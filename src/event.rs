@@ -1,9 +1,14 @@
+use std::path::PathBuf;
 use std::time::Duration;
 
 use crossterm::event::{Event as CrosstermEvent, EventStream, KeyEvent, KeyEventKind};
 use futures::{FutureExt, StreamExt};
 use tokio::sync::mpsc;
 
+use crate::activity::ActivityFeed;
+use crate::burnup::Burnup;
+use crate::runner::CommandOutput;
+
 /// Application events
 #[derive(Debug, Clone)]
 pub enum Event {
@@ -13,6 +18,16 @@ pub enum Event {
     Resize(u16, u16),
     /// Periodic tick (for future animations/updates)
     Tick,
+    /// Planning files changed on disk (debounced, from the file watcher)
+    DataChanged(Vec<PathBuf>),
+    /// Files checked by plan must_haves changed on disk (from the source watcher)
+    SourcesChanged(Vec<PathBuf>),
+    /// Output or completion of a running Kata command (from the command runner)
+    CommandOutput(CommandOutput),
+    /// Progress history read in the background, for the given planning directory
+    BurnupLoaded(PathBuf, Burnup),
+    /// Recent commits read in the background, for the given planning directory
+    ActivityLoaded(PathBuf, ActivityFeed),
     /// Terminal event error (allows main loop to handle gracefully)
     Error(String),
 }
@@ -22,72 +37,113 @@ pub enum Event {
 /// Spawns a background task that reads terminal events and sends them
 /// through a channel. This keeps the main loop non-blocking.
 pub struct EventHandler {
+    tx: mpsc::UnboundedSender<Event>,
     rx: mpsc::UnboundedReceiver<Event>,
-    // Keep handle to abort on drop
-    _task: tokio::task::JoinHandle<()>,
+    tick_rate: Duration,
+    // Keep handle to abort on drop and while paused
+    task: Option<tokio::task::JoinHandle<()>>,
 }
 
 impl EventHandler {
     /// Create a new event handler with the given tick rate
     pub fn new(tick_rate: Duration) -> Self {
         let (tx, rx) = mpsc::unbounded_channel();
+        let task = spawn_reader(tx.clone(), tick_rate);
 
-        let task = tokio::spawn(async move {
-            let mut reader = EventStream::new();
-            let mut tick_interval = tokio::time::interval(tick_rate);
+        Self {
+            tx,
+            rx,
+            tick_rate,
+            task: Some(task),
+        }
+    }
 
-            loop {
-                let tick_delay = tick_interval.tick();
-                let crossterm_event = reader.next().fuse();
+    /// Stop reading terminal events, e.g. while an external editor owns the terminal
+    ///
+    /// Events from other subsystems still queue up on the channel.
+    pub fn pause(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+    }
 
-                tokio::select! {
-                    _ = tick_delay => {
-                        if tx.send(Event::Tick).is_err() {
-                            break;
-                        }
+    /// Resume reading terminal events after `pause`
+    pub fn resume(&mut self) {
+        if self.task.is_none() {
+            self.task = Some(spawn_reader(self.tx.clone(), self.tick_rate));
+        }
+    }
+
+    /// Get a sender for injecting events from other subsystems (e.g. the file watcher, command runner)
+    pub fn sender(&self) -> mpsc::UnboundedSender<Event> {
+        self.tx.clone()
+    }
+
+    /// Get the next event, waiting asynchronously
+    pub async fn next(&mut self) -> Option<Event> {
+        self.rx.recv().await
+    }
+}
+
+impl Drop for EventHandler {
+    fn drop(&mut self) {
+        self.pause();
+    }
+}
+
+/// Spawn the task forwarding terminal events and ticks to the channel
+fn spawn_reader(
+    tx: mpsc::UnboundedSender<Event>,
+    tick_rate: Duration,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut reader = EventStream::new();
+        let mut tick_interval = tokio::time::interval(tick_rate);
+
+        loop {
+            let tick_delay = tick_interval.tick();
+            let crossterm_event = reader.next().fuse();
+
+            tokio::select! {
+                _ = tick_delay => {
+                    if tx.send(Event::Tick).is_err() {
+                        break;
                     }
-                    maybe_event = crossterm_event => {
-                        match maybe_event {
-                            Some(Ok(event)) => {
-                                match event {
-                                    CrosstermEvent::Key(key) => {
-                                        // CRITICAL: Only handle Press events for cross-platform compatibility
-                                        // Windows sends Press+Release, macOS/Linux may vary
-                                        if key.kind == KeyEventKind::Press
-                                            && tx.send(Event::Key(key)).is_err()
-                                        {
-                                            break;
-                                        }
+                }
+                maybe_event = crossterm_event => {
+                    match maybe_event {
+                        Some(Ok(event)) => {
+                            match event {
+                                CrosstermEvent::Key(key) => {
+                                    // CRITICAL: Only handle Press events for cross-platform compatibility
+                                    // Windows sends Press+Release, macOS/Linux may vary
+                                    if key.kind == KeyEventKind::Press
+                                        && tx.send(Event::Key(key)).is_err()
+                                    {
+                                        break;
                                     }
-                                    CrosstermEvent::Resize(w, h) => {
-                                        if tx.send(Event::Resize(w, h)).is_err() {
-                                            break;
-                                        }
+                                }
+                                CrosstermEvent::Resize(w, h) => {
+                                    if tx.send(Event::Resize(w, h)).is_err() {
+                                        break;
                                     }
-                                    // Mouse, Paste, Focus events intentionally ignored
-                                    CrosstermEvent::Mouse(_)
-                                    | CrosstermEvent::Paste(_)
-                                    | CrosstermEvent::FocusGained
-                                    | CrosstermEvent::FocusLost => {}
                                 }
+                                // Mouse, Paste, Focus events intentionally ignored
+                                CrosstermEvent::Mouse(_)
+                                | CrosstermEvent::Paste(_)
+                                | CrosstermEvent::FocusGained
+                                | CrosstermEvent::FocusLost => {}
                             }
-                            Some(Err(e)) => {
-                                // Send error to main loop for graceful handling
-                                let _ = tx.send(Event::Error(e.to_string()));
-                                break;
-                            }
-                            None => break,
                         }
+                        Some(Err(e)) => {
+                            // Send error to main loop for graceful handling
+                            let _ = tx.send(Event::Error(e.to_string()));
+                            break;
+                        }
+                        None => break,
                     }
                 }
             }
-        });
-
-        Self { rx, _task: task }
-    }
-
-    /// Get the next event, waiting asynchronously
-    pub async fn next(&mut self) -> Option<Event> {
-        self.rx.recv().await
-    }
+        }
+    })
 }
@@ -0,0 +1,896 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use clap::ValueEnum;
+use color_eyre::Result;
+use serde::Serialize;
+
+use crate::data::{
+    Artifact, ChecklistEntry, Decision, Document, DocumentKind, KeyLink, Milestone,
+    MilestoneStatus, MustHaves, OutlineHeading, Phase, PhaseChecklistItem, PhaseId, PhaseMention,
+    PhaseStatus, Plan, PlanChecklistItem, PlanFrontmatter, PlanSummary, PlanTask, PlanningData,
+    PlanningState, Project, Requirement, RequirementConflict, RequirementStatus, Requirements,
+    StateMilestone, TraceabilityRow,
+};
+
+/// Version of the export schema
+///
+/// Bump when a field is renamed, removed or changes meaning. Adding fields is
+/// backwards compatible and does not require a bump.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Output format for `kata-tui export`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum ExportFormat {
+    #[default]
+    Json,
+    Yaml,
+    Markdown,
+}
+
+/// Versioned snapshot of the parsed planning data, with computed fields
+///
+/// The snapshot types below define the export schema. They copy what they need from
+/// the data model rather than serializing it, so the model can change without
+/// changing the schema.
+#[derive(Debug, Serialize)]
+pub struct Snapshot<'a> {
+    pub schema_version: u32,
+    /// Tool and version that produced the snapshot (e.g., "kata-tui 0.1.0")
+    pub generator: String,
+    pub project: ProjectSnapshot<'a>,
+    pub state: StateSnapshot<'a>,
+    pub summary: Summary,
+    pub phases: Vec<PhaseSnapshot<'a>>,
+    pub phase_checklist: Vec<PhaseChecklistSnapshot<'a>>,
+    pub milestones: Vec<MilestoneSnapshot<'a>>,
+    pub research: Vec<DocumentSnapshot<'a>>,
+    pub requirements: RequirementsSnapshot<'a>,
+    pub requirement_conflicts: Vec<ConflictSnapshot>,
+}
+
+/// Totals computed across all phases, counted as `kata-tui lint` counts them
+#[derive(Debug, Serialize)]
+pub struct Summary {
+    pub phases_total: usize,
+    pub phases_complete: usize,
+    pub requirements_total: usize,
+    pub requirements_complete: usize,
+    /// Share of requirements complete, 0-100
+    pub completion_percentage: f32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProjectSnapshot<'a> {
+    pub name: &'a str,
+    pub description: &'a str,
+    pub problem: &'a str,
+    pub solution: &'a str,
+    pub decisions: Vec<DecisionSnapshot<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DecisionSnapshot<'a> {
+    pub decision: &'a str,
+    pub rationale: &'a str,
+    pub outcome: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StateSnapshot<'a> {
+    pub milestone: &'a str,
+    pub current_phase: Option<PhaseId>,
+    pub current_phase_name: &'a str,
+    pub current_plan: Option<&'a str>,
+    pub status: &'a str,
+    pub progress: u8,
+    pub last_activity: &'a str,
+    pub total_phases: u8,
+    pub phases_complete: u8,
+    pub total_requirements: u16,
+    pub requirements_complete: u16,
+    pub milestones: Vec<StateMilestoneSnapshot<'a>>,
+    pub decisions: Vec<DecisionSnapshot<'a>>,
+    pub technical_notes: &'a [String],
+    pub blockers: &'a [String],
+    pub last_session: &'a str,
+    pub next_session: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StateMilestoneSnapshot<'a> {
+    pub name: &'a str,
+    pub phases: &'a str,
+    pub status: &'a str,
+    pub shipped: Option<&'a str>,
+}
+
+/// A phase with its computed progress fields
+#[derive(Debug, Serialize)]
+pub struct PhaseSnapshot<'a> {
+    pub number: PhaseId,
+    pub name: &'a str,
+    pub goal: &'a str,
+    pub requirements: Vec<RequirementSnapshot<'a>>,
+    pub status: PhaseStatus,
+    pub dependencies: &'a [PhaseId],
+    pub dependencies_text: &'a str,
+    pub success_criteria: &'a [String],
+    pub plan_checklist: Vec<PlanChecklistSnapshot<'a>>,
+    pub research_notes: &'a str,
+    pub plans: Vec<PlanSnapshot<'a>>,
+    pub documents: Vec<DocumentSnapshot<'a>>,
+    /// Share of the phase's requirements complete, 0-100
+    pub completion_percentage: f32,
+    pub requirements_complete: usize,
+    pub plans_complete: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RequirementSnapshot<'a> {
+    pub id: &'a str,
+    pub description: &'a str,
+    pub status: RequirementStatus,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PlanChecklistSnapshot<'a> {
+    pub id: &'a str,
+    pub description: &'a str,
+    pub checked: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PlanSnapshot<'a> {
+    pub id: &'a str,
+    pub path: &'a Path,
+    pub frontmatter: Option<FrontmatterSnapshot<'a>>,
+    pub objective: &'a str,
+    pub context: &'a [String],
+    pub tasks: Vec<TaskSnapshot<'a>>,
+    pub verification: &'a str,
+    pub success_criteria: &'a str,
+    pub summary: Option<PlanSummarySnapshot<'a>>,
+    /// Summary title once executed, else the objective's first line
+    pub title: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FrontmatterSnapshot<'a> {
+    pub phase: &'a str,
+    pub plan: &'a str,
+    #[serde(rename = "type")]
+    pub plan_type: &'a str,
+    pub wave: u32,
+    pub depends_on: &'a [String],
+    pub files_modified: &'a [String],
+    pub autonomous: bool,
+    pub must_haves: MustHavesSnapshot<'a>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MustHavesSnapshot<'a> {
+    pub truths: &'a [String],
+    pub artifacts: Vec<ArtifactSnapshot<'a>>,
+    pub key_links: Vec<KeyLinkSnapshot<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ArtifactSnapshot<'a> {
+    pub path: &'a str,
+    pub provides: &'a str,
+    pub contains: Option<&'a str>,
+    pub exports: &'a [String],
+}
+
+#[derive(Debug, Serialize)]
+pub struct KeyLinkSnapshot<'a> {
+    pub from: &'a str,
+    pub to: &'a str,
+    pub via: &'a str,
+    pub pattern: Option<&'a str>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TaskSnapshot<'a> {
+    pub name: &'a str,
+    pub task_type: &'a str,
+    pub files: &'a [String],
+    pub action: &'a str,
+    pub verify: &'a str,
+    pub done: &'a str,
+    pub completed: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PlanSummarySnapshot<'a> {
+    pub path: &'a Path,
+    pub title: &'a str,
+    pub status: Option<&'a str>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DocumentSnapshot<'a> {
+    pub kind: DocumentKind,
+    pub path: &'a Path,
+    pub title: &'a str,
+    pub outline: Vec<OutlineSnapshot<'a>>,
+    pub mentions: Vec<MentionSnapshot<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct OutlineSnapshot<'a> {
+    pub level: u8,
+    pub text: &'a str,
+    pub line: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MentionSnapshot<'a> {
+    pub phase: PhaseId,
+    pub heading: Option<&'a str>,
+    pub line: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PhaseChecklistSnapshot<'a> {
+    pub number: PhaseId,
+    pub name: &'a str,
+    pub checked: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MilestoneSnapshot<'a> {
+    pub version: &'a str,
+    pub name: &'a str,
+    pub status: MilestoneStatus,
+    pub shipped: Option<&'a str>,
+    pub phases_text: &'a str,
+    pub phases: &'a [PhaseId],
+    pub delivered: &'a str,
+    pub accomplishments: &'a [String],
+    pub stats: &'a [String],
+    pub git_range: Option<&'a str>,
+    pub archive: Option<&'a Path>,
+    pub archived_phases: Vec<PhaseSnapshot<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RequirementsSnapshot<'a> {
+    pub checklist: Vec<ChecklistEntrySnapshot<'a>>,
+    pub traceability: Vec<TraceabilitySnapshot<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChecklistEntrySnapshot<'a> {
+    pub id: &'a str,
+    pub description: &'a str,
+    pub checked: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TraceabilitySnapshot<'a> {
+    pub id: &'a str,
+    pub phase: &'a str,
+    pub status: RequirementStatus,
+}
+
+/// A disagreement between the requirements checklist and traceability table
+#[derive(Debug, Serialize)]
+pub struct ConflictSnapshot {
+    /// "status_mismatch", "missing_from_traceability" or "missing_from_checklist"
+    pub kind: &'static str,
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checklist: Option<RequirementStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub traceability: Option<RequirementStatus>,
+    /// The conflict as `kata-tui lint` words it
+    pub message: String,
+}
+
+impl<'a> Snapshot<'a> {
+    pub fn new(data: &'a PlanningData) -> Self {
+        let (phases_total, phases_complete) = data.phase_totals();
+        let (requirements_total, requirements_complete) = data.requirement_totals();
+        let completion_percentage = if requirements_total == 0 {
+            0.0
+        } else {
+            requirements_complete as f32 / requirements_total as f32 * 100.0
+        };
+
+        let summary = Summary {
+            phases_total,
+            phases_complete,
+            requirements_total,
+            requirements_complete,
+            completion_percentage,
+        };
+
+        Self {
+            schema_version: SCHEMA_VERSION,
+            generator: format!("{} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION")),
+            project: (&data.project).into(),
+            state: (&data.state).into(),
+            summary,
+            phases: data.roadmap.phases.iter().map(Into::into).collect(),
+            phase_checklist: data
+                .roadmap
+                .phase_checklist
+                .iter()
+                .map(Into::into)
+                .collect(),
+            milestones: data.roadmap.milestones.iter().map(Into::into).collect(),
+            research: data.research.iter().map(Into::into).collect(),
+            requirements: (&data.requirements).into(),
+            requirement_conflicts: data
+                .requirements
+                .conflicts()
+                .iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+impl<'a> From<&'a Project> for ProjectSnapshot<'a> {
+    fn from(project: &'a Project) -> Self {
+        Self {
+            name: &project.name,
+            description: &project.description,
+            problem: &project.problem,
+            solution: &project.solution,
+            decisions: project.decisions.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<'a> From<&'a Decision> for DecisionSnapshot<'a> {
+    fn from(decision: &'a Decision) -> Self {
+        Self {
+            decision: &decision.decision,
+            rationale: &decision.rationale,
+            outcome: &decision.outcome,
+        }
+    }
+}
+
+impl<'a> From<&'a PlanningState> for StateSnapshot<'a> {
+    fn from(state: &'a PlanningState) -> Self {
+        Self {
+            milestone: &state.milestone,
+            current_phase: state.current_phase,
+            current_phase_name: &state.current_phase_name,
+            current_plan: state.current_plan.as_deref(),
+            status: &state.status,
+            progress: state.progress,
+            last_activity: &state.last_activity,
+            total_phases: state.total_phases,
+            phases_complete: state.phases_complete,
+            total_requirements: state.total_requirements,
+            requirements_complete: state.requirements_complete,
+            milestones: state.milestones.iter().map(Into::into).collect(),
+            decisions: state.decisions.iter().map(Into::into).collect(),
+            technical_notes: &state.technical_notes,
+            blockers: &state.blockers,
+            last_session: &state.last_session,
+            next_session: &state.next_session,
+        }
+    }
+}
+
+impl<'a> From<&'a StateMilestone> for StateMilestoneSnapshot<'a> {
+    fn from(milestone: &'a StateMilestone) -> Self {
+        Self {
+            name: &milestone.name,
+            phases: &milestone.phases,
+            status: &milestone.status,
+            shipped: milestone.shipped.as_deref(),
+        }
+    }
+}
+
+impl<'a> From<&'a Phase> for PhaseSnapshot<'a> {
+    fn from(phase: &'a Phase) -> Self {
+        Self {
+            number: phase.number,
+            name: &phase.name,
+            goal: &phase.goal,
+            requirements: phase.requirements.iter().map(Into::into).collect(),
+            status: phase.status,
+            dependencies: &phase.dependencies,
+            dependencies_text: &phase.dependencies_text,
+            success_criteria: &phase.success_criteria,
+            plan_checklist: phase.plan_checklist.iter().map(Into::into).collect(),
+            research_notes: &phase.research_notes,
+            plans: phase.plans.iter().map(Into::into).collect(),
+            documents: phase.documents.iter().map(Into::into).collect(),
+            completion_percentage: phase.completion_percentage(),
+            requirements_complete: phase
+                .requirements
+                .iter()
+                .filter(|r| r.status == RequirementStatus::Complete)
+                .count(),
+            plans_complete: phase.plans.iter().filter(|p| p.is_complete()).count(),
+        }
+    }
+}
+
+impl<'a> From<&'a Requirement> for RequirementSnapshot<'a> {
+    fn from(req: &'a Requirement) -> Self {
+        Self {
+            id: &req.id,
+            description: &req.description,
+            status: req.status,
+        }
+    }
+}
+
+impl<'a> From<&'a PlanChecklistItem> for PlanChecklistSnapshot<'a> {
+    fn from(item: &'a PlanChecklistItem) -> Self {
+        Self {
+            id: &item.id,
+            description: &item.description,
+            checked: item.checked,
+        }
+    }
+}
+
+impl<'a> From<&'a Plan> for PlanSnapshot<'a> {
+    fn from(plan: &'a Plan) -> Self {
+        Self {
+            id: &plan.id,
+            path: &plan.path,
+            frontmatter: plan.frontmatter.as_ref().map(Into::into),
+            objective: &plan.objective,
+            context: &plan.context,
+            tasks: plan.tasks.iter().map(Into::into).collect(),
+            verification: &plan.verification,
+            success_criteria: &plan.success_criteria,
+            summary: plan.summary.as_ref().map(Into::into),
+            title: plan.title(),
+        }
+    }
+}
+
+impl<'a> From<&'a PlanFrontmatter> for FrontmatterSnapshot<'a> {
+    fn from(fm: &'a PlanFrontmatter) -> Self {
+        Self {
+            phase: &fm.phase,
+            plan: &fm.plan,
+            plan_type: &fm.plan_type,
+            wave: fm.wave,
+            depends_on: &fm.depends_on,
+            files_modified: &fm.files_modified,
+            autonomous: fm.autonomous,
+            must_haves: (&fm.must_haves).into(),
+        }
+    }
+}
+
+impl<'a> From<&'a MustHaves> for MustHavesSnapshot<'a> {
+    fn from(must_haves: &'a MustHaves) -> Self {
+        Self {
+            truths: &must_haves.truths,
+            artifacts: must_haves.artifacts.iter().map(Into::into).collect(),
+            key_links: must_haves.key_links.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<'a> From<&'a Artifact> for ArtifactSnapshot<'a> {
+    fn from(artifact: &'a Artifact) -> Self {
+        Self {
+            path: &artifact.path,
+            provides: &artifact.provides,
+            contains: artifact.contains.as_deref(),
+            exports: &artifact.exports,
+        }
+    }
+}
+
+impl<'a> From<&'a KeyLink> for KeyLinkSnapshot<'a> {
+    fn from(link: &'a KeyLink) -> Self {
+        Self {
+            from: &link.from,
+            to: &link.to,
+            via: &link.via,
+            pattern: link.pattern.as_deref(),
+        }
+    }
+}
+
+impl<'a> From<&'a PlanTask> for TaskSnapshot<'a> {
+    fn from(task: &'a PlanTask) -> Self {
+        Self {
+            name: &task.name,
+            task_type: &task.task_type,
+            files: &task.files,
+            action: &task.action,
+            verify: &task.verify,
+            done: &task.done,
+            completed: task.completed,
+        }
+    }
+}
+
+impl<'a> From<&'a PlanSummary> for PlanSummarySnapshot<'a> {
+    fn from(summary: &'a PlanSummary) -> Self {
+        Self {
+            path: &summary.path,
+            title: &summary.title,
+            status: summary.status.as_deref(),
+        }
+    }
+}
+
+impl<'a> From<&'a Document> for DocumentSnapshot<'a> {
+    fn from(document: &'a Document) -> Self {
+        Self {
+            kind: document.kind,
+            path: &document.path,
+            title: &document.title,
+            outline: document.outline.iter().map(Into::into).collect(),
+            mentions: document.mentions.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<'a> From<&'a OutlineHeading> for OutlineSnapshot<'a> {
+    fn from(heading: &'a OutlineHeading) -> Self {
+        Self {
+            level: heading.level,
+            text: &heading.text,
+            line: heading.line,
+        }
+    }
+}
+
+impl<'a> From<&'a PhaseMention> for MentionSnapshot<'a> {
+    fn from(mention: &'a PhaseMention) -> Self {
+        Self {
+            phase: mention.phase,
+            heading: mention.heading.as_deref(),
+            line: mention.line,
+        }
+    }
+}
+
+impl<'a> From<&'a PhaseChecklistItem> for PhaseChecklistSnapshot<'a> {
+    fn from(item: &'a PhaseChecklistItem) -> Self {
+        Self {
+            number: item.number,
+            name: &item.name,
+            checked: item.checked,
+        }
+    }
+}
+
+impl<'a> From<&'a Milestone> for MilestoneSnapshot<'a> {
+    fn from(milestone: &'a Milestone) -> Self {
+        Self {
+            version: &milestone.version,
+            name: &milestone.name,
+            status: milestone.status,
+            shipped: milestone.shipped.as_deref(),
+            phases_text: &milestone.phases_text,
+            phases: &milestone.phases,
+            delivered: &milestone.delivered,
+            accomplishments: &milestone.accomplishments,
+            stats: &milestone.stats,
+            git_range: milestone.git_range.as_deref(),
+            archive: milestone.archive.as_deref(),
+            archived_phases: milestone.archived_phases.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<'a> From<&'a Requirements> for RequirementsSnapshot<'a> {
+    fn from(requirements: &'a Requirements) -> Self {
+        Self {
+            checklist: requirements.checklist.iter().map(Into::into).collect(),
+            traceability: requirements.traceability.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<'a> From<&'a ChecklistEntry> for ChecklistEntrySnapshot<'a> {
+    fn from(entry: &'a ChecklistEntry) -> Self {
+        Self {
+            id: &entry.id,
+            description: &entry.description,
+            checked: entry.checked,
+        }
+    }
+}
+
+impl<'a> From<&'a TraceabilityRow> for TraceabilitySnapshot<'a> {
+    fn from(row: &'a TraceabilityRow) -> Self {
+        Self {
+            id: &row.id,
+            phase: &row.phase,
+            status: row.status,
+        }
+    }
+}
+
+impl From<&RequirementConflict> for ConflictSnapshot {
+    fn from(conflict: &RequirementConflict) -> Self {
+        let (kind, checklist, traceability) = match conflict {
+            RequirementConflict::StatusMismatch {
+                checklist,
+                traceability,
+                ..
+            } => ("status_mismatch", Some(*checklist), Some(*traceability)),
+            RequirementConflict::MissingFromTraceability { .. } => {
+                ("missing_from_traceability", None, None)
+            }
+            RequirementConflict::MissingFromChecklist { .. } => {
+                ("missing_from_checklist", None, None)
+            }
+        };
+        Self {
+            kind,
+            id: conflict.id().to_string(),
+            checklist,
+            traceability,
+            message: conflict.to_string(),
+        }
+    }
+}
+
+/// Render planning data in the requested format
+pub fn export(data: &PlanningData, format: ExportFormat) -> Result<String> {
+    let snapshot = Snapshot::new(data);
+    Ok(match format {
+        ExportFormat::Json => serde_json::to_string_pretty(&snapshot)? + "\n",
+        ExportFormat::Yaml => serde_yaml::to_string(&snapshot)?,
+        ExportFormat::Markdown => to_markdown(&snapshot),
+    })
+}
+
+fn checkbox(done: bool) -> &'static str {
+    if done {
+        "[x]"
+    } else {
+        "[ ]"
+    }
+}
+
+fn status_label(status: PhaseStatus) -> &'static str {
+    match status {
+        PhaseStatus::Complete => "Complete",
+        PhaseStatus::InProgress => "In Progress",
+        PhaseStatus::Pending => "Pending",
+    }
+}
+
+/// Human-readable report of the snapshot
+fn to_markdown(snapshot: &Snapshot) -> String {
+    let mut out = String::new();
+    let summary = &snapshot.summary;
+
+    // Writing to a String cannot fail
+    let _ = writeln!(out, "# {}\n", snapshot.project.name);
+    let _ = writeln!(
+        out,
+        "_Exported by {} (schema v{})_\n",
+        snapshot.generator, snapshot.schema_version
+    );
+
+    if let Some(phase) = snapshot.state.current_phase {
+        let _ = writeln!(
+            out,
+            "**Current:** Phase {}: {} ({})  ",
+            phase, snapshot.state.current_phase_name, snapshot.state.status
+        );
+    }
+    let _ = writeln!(
+        out,
+        "**Progress:** {}/{} phases, {}/{} requirements ({:.0}%)\n",
+        summary.phases_complete,
+        summary.phases_total,
+        summary.requirements_complete,
+        summary.requirements_total,
+        summary.completion_percentage
+    );
+
+    if !snapshot.state.blockers.is_empty() {
+        let _ = writeln!(out, "**Blockers:**\n");
+        for blocker in snapshot.state.blockers {
+            let _ = writeln!(out, "- {}", blocker);
+        }
+        out.push('\n');
+    }
+
+    for phase in &snapshot.phases {
+        let _ = writeln!(
+            out,
+            "## Phase {}: {} — {} ({:.0}%)\n",
+            phase.number,
+            phase.name,
+            status_label(phase.status),
+            phase.completion_percentage
+        );
+
+        if !phase.goal.is_empty() {
+            let _ = writeln!(out, "{}\n", phase.goal);
+        }
+
+        if !phase.dependencies.is_empty() {
+            let deps: Vec<String> = phase
+                .dependencies
+                .iter()
+                .map(|d| format!("Phase {}", d))
+                .collect();
+            let _ = writeln!(out, "**Depends on:** {}\n", deps.join(", "));
+        }
+
+        if !phase.requirements.is_empty() {
+            for req in &phase.requirements {
+                let done = req.status == RequirementStatus::Complete;
+                let _ = writeln!(out, "- {} {}: {}", checkbox(done), req.id, req.description);
+            }
+            out.push('\n');
+        }
+
+        if !phase.plans.is_empty() {
+            let _ = writeln!(
+                out,
+                "**Plans:** {}/{} executed\n",
+                phase.plans_complete,
+                phase.plans.len()
+            );
+            for plan in &phase.plans {
+                let _ = writeln!(
+                    out,
+                    "- {} {}: {}",
+                    checkbox(plan.summary.is_some()),
+                    plan.id,
+                    plan.title
+                );
+            }
+            out.push('\n');
+        }
+    }
+
+    if !snapshot.requirement_conflicts.is_empty() {
+        let _ = writeln!(out, "## Requirement Conflicts\n");
+        for conflict in &snapshot.requirement_conflicts {
+            let _ = writeln!(out, "- {}", conflict.message);
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{PhaseId, Plan, PlanSummary, Requirement};
+
+    fn sample_data() -> PlanningData {
+        let mut data = PlanningData::default();
+        data.project.name = "Kata TUI".to_string();
+        data.state.current_phase = Some(PhaseId::new(2));
+        data.state.current_phase_name = "Navigation".to_string();
+        data.state.status = "Executing".to_string();
+        data.roadmap.phases = vec![
+            Phase {
+                number: PhaseId::new(1),
+                name: "Foundation".to_string(),
+                goal: "Build the foundation.".to_string(),
+                requirements: vec![Requirement {
+                    id: "DISP-01".to_string(),
+                    description: "Display stuff".to_string(),
+                    status: RequirementStatus::Complete,
+                    span: None,
+                }],
+                status: PhaseStatus::Complete,
+                ..Default::default()
+            },
+            Phase {
+                number: PhaseId::inserted(1, 1),
+                name: "Navigation".to_string(),
+                requirements: vec![
+                    Requirement {
+                        id: "NAV-01".to_string(),
+                        description: "Move around".to_string(),
+                        status: RequirementStatus::Complete,
+                        span: None,
+                    },
+                    Requirement {
+                        id: "NAV-02".to_string(),
+                        description: "Focus".to_string(),
+                        status: RequirementStatus::Pending,
+                        span: None,
+                    },
+                ],
+                status: PhaseStatus::InProgress,
+                dependencies: vec![PhaseId::new(1)],
+                plans: vec![Plan {
+                    id: "01.1-01".to_string(),
+                    path: "phases/01.1-navigation/01.1-01-PLAN.md".into(),
+                    objective: "Add navigation.".to_string(),
+                    summary: Some(PlanSummary {
+                        path: "phases/01.1-navigation/01.1-01-SUMMARY.md".into(),
+                        title: "Navigation Added".to_string(),
+                        status: Some("Complete".to_string()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+        ];
+        data
+    }
+
+    #[test]
+    fn test_json_schema_is_stable() {
+        let json = export(&sample_data(), ExportFormat::Json).unwrap();
+        let json = json.replace(env!("CARGO_PKG_VERSION"), "<version>");
+
+        insta::assert_snapshot!(json);
+    }
+
+    #[test]
+    fn test_yaml_round_trips_computed_fields() {
+        let yaml = export(&sample_data(), ExportFormat::Yaml).unwrap();
+        let value: serde_yaml::Value = serde_yaml::from_str(&yaml).unwrap();
+
+        assert_eq!(
+            value["schema_version"].as_u64(),
+            Some(SCHEMA_VERSION as u64)
+        );
+        assert_eq!(value["phases"][1]["number"].as_str(), Some("1.1"));
+        assert_eq!(value["phases"][1]["status"].as_str(), Some("in_progress"));
+        assert_eq!(
+            value["phases"][1]["completion_percentage"].as_f64(),
+            Some(50.0)
+        );
+        assert_eq!(value["summary"]["requirements_complete"].as_u64(), Some(2));
+    }
+
+    #[test]
+    fn test_markdown_report() {
+        let markdown = export(&sample_data(), ExportFormat::Markdown).unwrap();
+
+        assert!(markdown.starts_with("# Kata TUI\n"));
+        assert!(markdown.contains("**Progress:** 1/2 phases, 2/3 requirements (67%)"));
+        assert!(markdown.contains("## Phase 1.1: Navigation — In Progress (50%)"));
+        assert!(markdown.contains("**Depends on:** Phase 1"));
+        assert!(markdown.contains("- [ ] NAV-02: Focus"));
+        assert!(markdown.contains("- [x] 01.1-01: Navigation Added"));
+    }
+
+    #[test]
+    fn test_summary_counts_archived_phases_and_requirements_file() {
+        let mut data = sample_data();
+        data.roadmap.milestones.push(Milestone {
+            archived_phases: vec![Phase {
+                number: PhaseId::new(0),
+                status: PhaseStatus::Complete,
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        data.requirements.checklist = ["DISP-01", "NAV-01", "NAV-02", "NAV-03"]
+            .iter()
+            .map(|id| ChecklistEntry {
+                id: id.to_string(),
+                checked: *id == "DISP-01",
+                ..Default::default()
+            })
+            .collect();
+
+        let summary = Snapshot::new(&data).summary;
+
+        assert_eq!((summary.phases_total, summary.phases_complete), (3, 2));
+        assert_eq!(
+            (summary.requirements_total, summary.requirements_complete),
+            (4, 1)
+        );
+    }
+}
@@ -0,0 +1,512 @@
+use std::collections::HashMap;
+
+use crate::data::{Phase, PhaseId, PhaseStatus, PlanningData};
+
+/// Which dependency graph the graph view shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GraphKind {
+    /// Phases linked by ROADMAP.md `**Depends on:**`
+    #[default]
+    Phases,
+    /// Plans linked by PLAN.md `depends_on`
+    Plans,
+}
+
+/// A phase or plan in a dependency graph
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphNode {
+    /// Name dependencies use for this node ("2.1" for phases, "02-01" for plans)
+    pub key: String,
+    /// First line of the node's box (e.g., "Phase 2", "02-01")
+    pub title: String,
+    /// Second line: phase name or plan objective
+    pub subtitle: String,
+    pub status: PhaseStatus,
+    pub phase: PhaseId,
+    /// Plan ID for plan nodes
+    pub plan: Option<String>,
+}
+
+/// A dependency on something that is not in the graph
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DanglingRef {
+    /// Index of the node declaring the dependency
+    pub node: usize,
+    /// The dependency as written
+    pub missing: String,
+}
+
+/// One cell of a layer in the layered layout
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Slot {
+    /// A node's box (index into `nodes`)
+    Node(usize),
+    /// An edge passing through on its way to a later layer
+    Through,
+}
+
+/// A connector between slots of adjacent layers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Segment {
+    /// Layer of the `from` slot; `to` is in the next layer
+    pub layer: usize,
+    /// Position of the starting slot within its layer
+    pub from: usize,
+    /// Position of the ending slot within the next layer
+    pub to: usize,
+    /// Whether `to` is a node, so the connector ends in an arrowhead
+    pub arrow: bool,
+}
+
+/// Direction to move the selection in the graph view
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Up,
+    Down,
+    /// Towards dependencies
+    Left,
+    /// Towards dependents
+    Right,
+}
+
+/// Dependencies between phases or plans, analysed and laid out in layers
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DependencyGraph {
+    pub nodes: Vec<GraphNode>,
+    /// `(dependency, dependent)` node index pairs
+    pub edges: Vec<(usize, usize)>,
+    pub dangling: Vec<DanglingRef>,
+    /// Groups of nodes that depend on each other, in dependency order
+    pub cycles: Vec<Vec<usize>>,
+    /// Every node, dependencies first; members of a cycle are adjacent
+    pub order: Vec<usize>,
+    /// Layer 0 holds the nodes without dependencies; edges point to later layers
+    pub layers: Vec<Vec<Slot>>,
+    pub segments: Vec<Segment>,
+}
+
+impl DependencyGraph {
+    /// Graph of the roadmap's phases
+    ///
+    /// Dependencies on phases of archived milestones are already met and left out.
+    pub fn phases(data: &PlanningData) -> Self {
+        let archived: Vec<String> = archived_phases(data)
+            .map(|phase| phase.number.to_string())
+            .collect();
+        let (nodes, depends_on) = data
+            .roadmap
+            .phases
+            .iter()
+            .map(|phase| {
+                let node = GraphNode {
+                    key: phase.number.to_string(),
+                    title: format!("Phase {}", phase.number),
+                    subtitle: phase.name.clone(),
+                    status: phase.status,
+                    phase: phase.number,
+                    plan: None,
+                };
+                let deps = phase
+                    .dependencies
+                    .iter()
+                    .map(PhaseId::to_string)
+                    .filter(|dep| !archived.contains(dep))
+                    .collect();
+                (node, deps)
+            })
+            .unzip();
+        Self::build(nodes, depends_on)
+    }
+
+    /// Graph of the plans of the roadmap's phases
+    ///
+    /// As for phases, plans of archived milestones are treated as done.
+    pub fn plans(data: &PlanningData) -> Self {
+        let archived: Vec<&str> = archived_phases(data)
+            .flat_map(|phase| &phase.plans)
+            .map(|plan| plan.id.as_str())
+            .collect();
+        let current_plan = data.state.current_plan.as_deref();
+        let (nodes, depends_on) = data
+            .roadmap
+            .phases
+            .iter()
+            .flat_map(|phase| phase.plans.iter().map(move |plan| (phase, plan)))
+            .map(|(phase, plan)| {
+                let status = if plan.is_complete() {
+                    PhaseStatus::Complete
+                } else if current_plan == Some(plan.id.as_str()) {
+                    PhaseStatus::InProgress
+                } else {
+                    PhaseStatus::Pending
+                };
+                let node = GraphNode {
+                    key: plan.id.clone(),
+                    title: plan.id.clone(),
+                    subtitle: plan
+                        .objective
+                        .lines()
+                        .next()
+                        .unwrap_or_default()
+                        .to_string(),
+                    status,
+                    phase: phase.number,
+                    plan: Some(plan.id.clone()),
+                };
+                let deps = plan
+                    .frontmatter
+                    .as_ref()
+                    .map(|fm| fm.depends_on.clone())
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|dep| !archived.contains(&dep.as_str()))
+                    .collect();
+                (node, deps)
+            })
+            .unzip();
+        Self::build(nodes, depends_on)
+    }
+
+    /// Build a graph where `depends_on[i]` names the keys node `i` depends on
+    pub fn build(nodes: Vec<GraphNode>, depends_on: Vec<Vec<String>>) -> Self {
+        let index: HashMap<&str, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (node.key.as_str(), i))
+            .collect();
+
+        let mut edges = Vec::new();
+        let mut dangling = Vec::new();
+        let mut deps: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+        for (node, keys) in depends_on.iter().enumerate() {
+            for key in keys {
+                match index.get(key.as_str()) {
+                    Some(&dep) if !deps[node].contains(&dep) => {
+                        deps[node].push(dep);
+                        edges.push((dep, node));
+                    }
+                    Some(_) => {}
+                    None => dangling.push(DanglingRef {
+                        node,
+                        missing: key.clone(),
+                    }),
+                }
+            }
+        }
+
+        let components = strongly_connected(&deps);
+        let cycles = components
+            .iter()
+            .filter(|c| c.len() > 1 || deps[c[0]].contains(&c[0]))
+            .cloned()
+            .collect();
+        let order = components.iter().flatten().copied().collect();
+        let (layers, segments) = layout(&deps, &components);
+
+        Self {
+            nodes,
+            edges,
+            dangling,
+            cycles,
+            order,
+            layers,
+            segments,
+        }
+    }
+
+    /// Whether node `index` is on a cycle
+    pub fn in_cycle(&self, index: usize) -> bool {
+        self.cycles.iter().any(|cycle| cycle.contains(&index))
+    }
+
+    /// Layer and position within the layer of node `index`
+    pub fn position(&self, index: usize) -> Option<(usize, usize)> {
+        self.layers.iter().enumerate().find_map(|(layer, slots)| {
+            slots
+                .iter()
+                .position(|&slot| slot == Slot::Node(index))
+                .map(|pos| (layer, pos))
+        })
+    }
+
+    /// The node reached by moving one step from node `index`
+    ///
+    /// Up and down stay in the layer; left and right pick the nearest node in the
+    /// neighbouring layer.
+    pub fn step(&self, index: usize, step: Step) -> Option<usize> {
+        let (layer, pos) = self.position(index)?;
+        let node_at = |layer: usize, pos: usize| match self.layers[layer][pos] {
+            Slot::Node(node) => Some(node),
+            Slot::Through => None,
+        };
+
+        match step {
+            Step::Up => (0..pos).rev().find_map(|p| node_at(layer, p)),
+            Step::Down => (pos + 1..self.layers[layer].len()).find_map(|p| node_at(layer, p)),
+            Step::Left | Step::Right => {
+                let target = if step == Step::Left {
+                    layer.checked_sub(1)?
+                } else {
+                    layer + 1
+                };
+                let slots = self.layers.get(target)?;
+                (0..slots.len())
+                    .filter_map(|p| node_at(target, p).map(|node| (p.abs_diff(pos), node)))
+                    .min()
+                    .map(|(_, node)| node)
+            }
+        }
+    }
+}
+
+/// Phases of shipped milestones whose details were moved out of ROADMAP.md
+fn archived_phases(data: &PlanningData) -> impl Iterator<Item = &Phase> {
+    data.roadmap
+        .milestones
+        .iter()
+        .flat_map(|milestone| &milestone.archived_phases)
+}
+
+/// Strongly connected components of the graph with `deps` as edges (Tarjan)
+///
+/// Components come out dependencies first, each listing its nodes in index order.
+fn strongly_connected(deps: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    struct Tarjan<'a> {
+        deps: &'a [Vec<usize>],
+        index: Vec<Option<usize>>,
+        low: Vec<usize>,
+        stack: Vec<usize>,
+        on_stack: Vec<bool>,
+        next: usize,
+        components: Vec<Vec<usize>>,
+    }
+
+    impl Tarjan<'_> {
+        fn visit(&mut self, node: usize) {
+            self.index[node] = Some(self.next);
+            self.low[node] = self.next;
+            self.next += 1;
+            self.stack.push(node);
+            self.on_stack[node] = true;
+
+            for &dep in &self.deps[node] {
+                match self.index[dep] {
+                    None => {
+                        self.visit(dep);
+                        self.low[node] = self.low[node].min(self.low[dep]);
+                    }
+                    Some(index) if self.on_stack[dep] => {
+                        self.low[node] = self.low[node].min(index);
+                    }
+                    Some(_) => {}
+                }
+            }
+
+            if Some(self.low[node]) == self.index[node] {
+                let mut component = Vec::new();
+                while let Some(member) = self.stack.pop() {
+                    self.on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                component.sort();
+                self.components.push(component);
+            }
+        }
+    }
+
+    let count = deps.len();
+    let mut tarjan = Tarjan {
+        deps,
+        index: vec![None; count],
+        low: vec![0; count],
+        stack: Vec::new(),
+        on_stack: vec![false; count],
+        next: 0,
+        components: Vec::new(),
+    };
+    for node in 0..count {
+        if tarjan.index[node].is_none() {
+            tarjan.visit(node);
+        }
+    }
+    tarjan.components
+}
+
+/// Assign layers by longest dependency chain and order each layer to keep
+/// connectors short
+///
+/// Edges spanning several layers get a `Slot::Through` in every layer they cross,
+/// so segments only ever join adjacent layers. Edges inside a cycle are not drawn.
+fn layout(deps: &[Vec<usize>], components: &[Vec<usize>]) -> (Vec<Vec<Slot>>, Vec<Segment>) {
+    let mut layer_of = vec![0usize; deps.len()];
+    for component in components {
+        let layer = component
+            .iter()
+            .flat_map(|&node| &deps[node])
+            .filter(|dep| !component.contains(dep))
+            .map(|&dep| layer_of[dep] + 1)
+            .max()
+            .unwrap_or(0);
+        for &node in component {
+            layer_of[node] = layer;
+        }
+    }
+
+    // Slots are created in node order, then chained through-slots per long edge
+    let layer_count = layer_of.iter().max().map_or(0, |&max| max + 1);
+    let mut slots: Vec<(usize, Slot)> = (0..deps.len())
+        .map(|node| (layer_of[node], Slot::Node(node)))
+        .collect();
+    let mut links: Vec<(usize, usize)> = Vec::new();
+    for (node, node_deps) in deps.iter().enumerate() {
+        for &dep in node_deps {
+            if layer_of[dep] >= layer_of[node] {
+                continue;
+            }
+            let mut from = dep;
+            for layer in layer_of[dep] + 1..layer_of[node] {
+                slots.push((layer, Slot::Through));
+                links.push((from, slots.len() - 1));
+                from = slots.len() - 1;
+            }
+            links.push((from, node));
+        }
+    }
+
+    // Barycenter ordering: each layer follows the average position of its inputs
+    let mut position = vec![0.0f64; slots.len()];
+    let mut layers: Vec<Vec<usize>> = vec![Vec::new(); layer_count];
+    for (slot, &(layer, _)) in slots.iter().enumerate() {
+        layers[layer].push(slot);
+    }
+    for layer in &mut layers {
+        let weights: HashMap<usize, f64> = layer
+            .iter()
+            .map(|&slot| {
+                let inputs: Vec<f64> = links
+                    .iter()
+                    .filter(|&&(_, to)| to == slot)
+                    .map(|&(from, _)| position[from])
+                    .collect();
+                let weight = if inputs.is_empty() {
+                    f64::MAX
+                } else {
+                    inputs.iter().sum::<f64>() / inputs.len() as f64
+                };
+                (slot, weight)
+            })
+            .collect();
+        // Stable: slots without inputs keep node order, after those with inputs
+        layer.sort_by(|a, b| weights[a].total_cmp(&weights[b]));
+        for (pos, &slot) in layer.iter().enumerate() {
+            position[slot] = pos as f64;
+        }
+    }
+
+    let segments = links
+        .iter()
+        .map(|&(from, to)| Segment {
+            layer: slots[from].0,
+            from: position[from] as usize,
+            to: position[to] as usize,
+            arrow: matches!(slots[to].1, Slot::Node(_)),
+        })
+        .collect();
+    let layers = layers
+        .into_iter()
+        .map(|layer| layer.into_iter().map(|slot| slots[slot].1).collect())
+        .collect();
+    (layers, segments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(deps: &[(&str, &[&str])]) -> DependencyGraph {
+        let nodes = deps
+            .iter()
+            .map(|(key, _)| GraphNode {
+                key: key.to_string(),
+                title: key.to_string(),
+                subtitle: String::new(),
+                status: PhaseStatus::Pending,
+                phase: PhaseId::new(1),
+                plan: None,
+            })
+            .collect();
+        let depends_on = deps
+            .iter()
+            .map(|(_, deps)| deps.iter().map(|d| d.to_string()).collect())
+            .collect();
+        DependencyGraph::build(nodes, depends_on)
+    }
+
+    fn keys(graph: &DependencyGraph, nodes: &[usize]) -> Vec<String> {
+        nodes.iter().map(|&n| graph.nodes[n].key.clone()).collect()
+    }
+
+    #[test]
+    fn test_topological_order_and_layers() {
+        // 4 depends on 1 directly, so its edge passes through layer 1
+        let g = graph(&[("4", &["3", "1"]), ("3", &["2"]), ("2", &["1"]), ("1", &[])]);
+
+        assert!(g.cycles.is_empty());
+        assert!(g.dangling.is_empty());
+        assert_eq!(keys(&g, &g.order), vec!["1", "2", "3", "4"]);
+        assert_eq!(
+            g.layers,
+            vec![
+                vec![Slot::Node(3)],
+                vec![Slot::Node(2), Slot::Through],
+                vec![Slot::Node(1), Slot::Through],
+                vec![Slot::Node(0)],
+            ]
+        );
+        let arrows = g.segments.iter().filter(|s| s.arrow).count();
+        assert_eq!((g.segments.len(), arrows), (6, 4));
+    }
+
+    #[test]
+    fn test_cycles_and_dangling_references() {
+        let g = graph(&[
+            ("1", &[]),
+            ("2", &["1", "3"]),
+            ("3", &["2"]),
+            ("4", &["3", "9"]),
+            ("5", &["5"]),
+        ]);
+
+        let cycles: Vec<Vec<String>> = g.cycles.iter().map(|c| keys(&g, c)).collect();
+        assert_eq!(cycles, vec![vec!["2", "3"], vec!["5"]]);
+        assert_eq!(
+            g.dangling,
+            vec![DanglingRef {
+                node: 3,
+                missing: "9".to_string()
+            }]
+        );
+        // Dependencies still come first around the cycle
+        let order = keys(&g, &g.order);
+        let at = |key: &str| order.iter().position(|k| k == key).unwrap();
+        assert!(at("1") < at("2") && at("3") < at("4"));
+        // A cycle shares one layer and its internal edges are not drawn
+        assert_eq!(g.position(1).unwrap().0, g.position(2).unwrap().0);
+        assert_eq!(g.segments.len(), 2);
+        assert!(g.in_cycle(4) && !g.in_cycle(0));
+    }
+
+    #[test]
+    fn test_step_between_layers() {
+        let g = graph(&[("1", &[]), ("2", &[]), ("3", &["1", "2"])]);
+
+        assert_eq!(g.step(0, Step::Down), Some(1));
+        assert_eq!(g.step(0, Step::Up), None);
+        assert_eq!(g.step(1, Step::Right), Some(2));
+        assert_eq!(g.step(2, Step::Left), Some(0));
+        assert_eq!(g.step(2, Step::Right), None);
+    }
+}
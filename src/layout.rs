@@ -3,10 +3,14 @@ use ratatui::layout::{Constraint, Direction, Layout as RatatuiLayout, Rect};
 /// Computed layout areas
 #[derive(Debug, Clone, Copy)]
 pub struct Layout {
+    /// Full-width banner above the panes, when shown
+    pub banner: Option<Rect>,
     /// Left pane (tree view) - 30% width
     pub tree: Rect,
     /// Right pane (detail) - 70% width
     pub detail: Rect,
+    /// Command output pane below tree and detail, when shown
+    pub output: Option<Rect>,
     /// Bottom status bar - 1 line
     pub status_bar: Rect,
 }
@@ -18,19 +22,40 @@ pub const MIN_HEIGHT: u16 = 16;
 /// Narrow terminal threshold (reduce tree to 25%)
 pub const NARROW_WIDTH: u16 = 80;
 
+/// Share of the main area height given to the output pane
+pub const OUTPUT_PERCENT: u16 = 35;
+
 /// Compute layout for the given terminal area
-pub fn compute_layout(area: Rect) -> Layout {
-    // First split: main area and status bar
+///
+/// With `show_output`, the main area is split again so command output gets a
+/// full-width pane between the tree/detail panes and the status bar. A
+/// `banner_height` above zero reserves that many lines above the panes.
+pub fn compute_layout(area: Rect, show_output: bool, banner_height: u16) -> Layout {
+    // First split: banner, main area and status bar
     let vertical = RatatuiLayout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Min(1),    // Main content
-            Constraint::Length(1), // Status bar
+            Constraint::Length(banner_height), // Banner
+            Constraint::Min(1),                // Main content
+            Constraint::Length(1),             // Status bar
         ])
         .split(area);
 
-    let main_area = vertical[0];
-    let status_bar = vertical[1];
+    let banner = (banner_height > 0).then_some(vertical[0]);
+    let status_bar = vertical[2];
+
+    let (main_area, output) = if show_output {
+        let split = RatatuiLayout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Percentage(100 - OUTPUT_PERCENT),
+                Constraint::Percentage(OUTPUT_PERCENT),
+            ])
+            .split(vertical[1]);
+        (split[0], Some(split[1]))
+    } else {
+        (vertical[1], None)
+    };
 
     // Determine tree width percentage based on terminal width
     let tree_percent = if area.width < NARROW_WIDTH { 25 } else { 30 };
@@ -45,8 +70,10 @@ pub fn compute_layout(area: Rect) -> Layout {
         .split(main_area);
 
     Layout {
+        banner,
         tree: horizontal[0],
         detail: horizontal[1],
+        output,
         status_bar,
     }
 }
@@ -63,7 +90,7 @@ mod tests {
     #[test]
     fn test_compute_layout_standard() {
         let area = Rect::new(0, 0, 100, 30);
-        let layout = compute_layout(area);
+        let layout = compute_layout(area, false, 0);
 
         // Tree should be ~30%
         assert!(layout.tree.width >= 29 && layout.tree.width <= 31);
@@ -76,12 +103,39 @@ mod tests {
     #[test]
     fn test_compute_layout_narrow() {
         let area = Rect::new(0, 0, 70, 20);
-        let layout = compute_layout(area);
+        let layout = compute_layout(area, false, 0);
 
         // Tree should be ~25% for narrow terminals
         assert!(layout.tree.width >= 16 && layout.tree.width <= 19);
     }
 
+    #[test]
+    fn test_compute_layout_with_output() {
+        let area = Rect::new(0, 0, 100, 31);
+        let layout = compute_layout(area, true, 0);
+
+        let output = layout.output.expect("output pane");
+        // Full width, between the main panes and the status bar
+        assert_eq!(output.width, 100);
+        assert_eq!(output.y, layout.tree.bottom());
+        assert_eq!(output.bottom(), layout.status_bar.y);
+        assert!(output.height >= 10 && output.height <= 11);
+        assert_eq!(layout.tree.height, layout.detail.height);
+        assert!(compute_layout(area, false, 0).output.is_none());
+    }
+
+    #[test]
+    fn test_compute_layout_with_banner() {
+        let area = Rect::new(0, 0, 100, 30);
+        let layout = compute_layout(area, false, 2);
+
+        let banner = layout.banner.expect("banner");
+        assert_eq!((banner.y, banner.width, banner.height), (0, 100, 2));
+        assert_eq!(layout.tree.y, 2);
+        assert_eq!(layout.tree.bottom(), layout.status_bar.y);
+        assert!(compute_layout(area, false, 0).banner.is_none());
+    }
+
     #[test]
     fn test_terminal_too_small() {
         assert!(is_terminal_too_small(Rect::new(0, 0, 50, 20)));
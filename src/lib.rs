@@ -1,10 +1,24 @@
+pub mod activity;
 pub mod app;
+pub mod burnup;
+pub mod commands;
 pub mod components;
 pub mod data;
+pub mod decisions;
+pub mod editor;
 pub mod event;
+pub mod export;
+pub mod graph;
 pub mod layout;
+pub mod lint;
+pub mod markdown;
+pub mod runner;
 pub mod search;
 pub mod state;
 pub mod terminal;
 pub mod update;
+pub mod verify;
 pub mod view;
+pub mod watcher;
+pub mod waves;
+pub mod workspace;
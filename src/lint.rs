@@ -0,0 +1,715 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::data::parser::is_requirements_total_row;
+use crate::data::{PhaseId, Plan, PlanningData};
+
+/// Output format for `kata-tui lint`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum LintFormat {
+    /// One `path:line: severity[code]: message` line per diagnostic
+    #[default]
+    Text,
+    /// JSON array of diagnostics
+    Json,
+}
+
+/// How serious a diagnostic is
+///
+/// Ordered so `max` gives the worst severity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// A problem found in the .planning/ files
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+    /// File the problem is in
+    pub file: PathBuf,
+    /// 1-based line number, when the problem can be pinned to a line
+    pub line: Option<usize>,
+    pub severity: Severity,
+    /// Stable identifier for the check (e.g., "duplicate-phase")
+    pub code: &'static str,
+    pub message: String,
+}
+
+impl Diagnostic {
+    fn new(
+        file: &Path,
+        line: Option<usize>,
+        severity: Severity,
+        code: &'static str,
+        message: String,
+    ) -> Self {
+        Self {
+            file: file.to_path_buf(),
+            line,
+            severity,
+            code,
+            message,
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    /// `path:line: severity[code]: message`, the format editors and CI annotate from
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.file.display())?;
+        if let Some(line) = self.line {
+            write!(f, ":{}", line)?;
+        }
+        write!(f, ": {}[{}]: {}", self.severity, self.code, self.message)
+    }
+}
+
+/// 1-based number of the first line matching `pred`
+fn find_line(content: &str, pred: impl Fn(&str) -> bool) -> Option<usize> {
+    content.lines().position(|l| pred(l.trim())).map(|i| i + 1)
+}
+
+/// Whether a table row has a cell reading `label`, whatever the column order
+fn is_metric_row(line: &str, label: &str) -> bool {
+    line.starts_with('|') && line.split('|').any(|cell| cell.trim() == label)
+}
+
+/// A file the checks re-read for line numbers, or None after reporting why it can't be
+fn read_checked(path: &Path, diagnostics: &mut Vec<Diagnostic>) -> Option<String> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => Some(content),
+        Err(e) => {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            diagnostics.push(Diagnostic::new(
+                path,
+                None,
+                Severity::Error,
+                "unreadable-file",
+                format!("cannot read {}: {}", name, e),
+            ));
+            None
+        }
+    }
+}
+
+/// Check the .planning/ directory for inconsistencies between files
+///
+/// `data` must have been loaded from `planning_dir`; raw files are re-read only
+/// to attach line numbers. Diagnostics are sorted by file and line.
+pub fn lint(planning_dir: &Path, data: &PlanningData) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let files = PlanningFiles::read(planning_dir, &mut diagnostics);
+
+    check_parse_warnings(data, &mut diagnostics);
+    check_duplicate_phases(&files, data, &mut diagnostics);
+    check_requirement_mapping(&files, data, &mut diagnostics);
+    check_state_metrics(&files, data, &mut diagnostics);
+    check_plans(data, &mut diagnostics);
+    check_config(data, &mut diagnostics);
+
+    diagnostics.sort_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)));
+    diagnostics
+}
+
+/// Paths and (readable) contents of the top-level planning files
+struct PlanningFiles {
+    roadmap: (PathBuf, String),
+    requirements: (PathBuf, String),
+    state: (PathBuf, String),
+}
+
+impl PlanningFiles {
+    fn read(planning_dir: &Path, diagnostics: &mut Vec<Diagnostic>) -> Self {
+        let mut read = |name: &str, required: bool| {
+            let path = planning_dir.join(name);
+            let content = match std::fs::read_to_string(&path) {
+                Ok(content) => content,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                    if required {
+                        diagnostics.push(Diagnostic::new(
+                            &path,
+                            None,
+                            Severity::Error,
+                            "missing-file",
+                            format!("{} not found", name),
+                        ));
+                    }
+                    String::new()
+                }
+                Err(e) => {
+                    diagnostics.push(Diagnostic::new(
+                        &path,
+                        None,
+                        Severity::Error,
+                        "unreadable-file",
+                        format!("cannot read {}: {}", name, e),
+                    ));
+                    String::new()
+                }
+            };
+            (path, content)
+        };
+
+        let files = Self {
+            roadmap: read("ROADMAP.md", true),
+            requirements: read("REQUIREMENTS.md", false),
+            state: read("STATE.md", false),
+        };
+        read("PROJECT.md", false);
+        files
+    }
+}
+
+/// Anything the parsers had to skip over
+fn check_parse_warnings(data: &PlanningData, diagnostics: &mut Vec<Diagnostic>) {
+    for warning in &data.warnings {
+        diagnostics.push(Diagnostic::new(
+            &warning.file,
+            Some(warning.line),
+            Severity::Warning,
+            "parse-warning",
+            warning.message.clone(),
+        ));
+    }
+}
+
+/// config.json values must be valid for their setting, and keys known to Kata
+fn check_config(data: &PlanningData, diagnostics: &mut Vec<Diagnostic>) {
+    let Some(config) = &data.config else {
+        return;
+    };
+    let content = read_checked(&config.path, diagnostics).unwrap_or_default();
+
+    for issue in &config.issues {
+        let name = issue.key.rsplit('.').next().unwrap_or_default();
+        let quoted = format!("\"{}\"", name);
+        let line = (!name.is_empty())
+            .then(|| find_line(&content, |l| l.starts_with(&quoted)))
+            .flatten();
+        let (severity, code) = if issue.unknown {
+            (Severity::Warning, "config-unknown-key")
+        } else {
+            (Severity::Error, "config-invalid")
+        };
+        diagnostics.push(Diagnostic::new(
+            &config.path,
+            line,
+            severity,
+            code,
+            issue.message.clone(),
+        ));
+    }
+}
+
+/// Each phase number must have exactly one `Phase N:` heading
+fn check_duplicate_phases(
+    files: &PlanningFiles,
+    data: &PlanningData,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let (path, _) = &files.roadmap;
+    let mut seen: HashMap<PhaseId, usize> = HashMap::new();
+
+    for phase in &data.roadmap.phases {
+        let occurrence = seen.entry(phase.number).or_default();
+        *occurrence += 1;
+        if *occurrence < 2 {
+            continue;
+        }
+
+        diagnostics.push(Diagnostic::new(
+            path,
+            phase.span.as_ref().map(|span| span.start_line),
+            Severity::Error,
+            "duplicate-phase",
+            format!("Phase {} is defined more than once", phase.number),
+        ));
+    }
+}
+
+/// ROADMAP requirement IDs and REQUIREMENTS.md entries must match one-to-one
+fn check_requirement_mapping(
+    files: &PlanningFiles,
+    data: &PlanningData,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let (roadmap_path, _) = &files.roadmap;
+    let (requirements_path, requirements) = &files.requirements;
+    let tracked = &data.requirements;
+
+    let mut mapped: HashMap<&str, PhaseId> = HashMap::new();
+    for phase in &data.roadmap.phases {
+        for req in &phase.requirements {
+            if let Some(first) = mapped.insert(&req.id, phase.number) {
+                diagnostics.push(Diagnostic::new(
+                    roadmap_path,
+                    req.span.as_ref().map(|span| span.start_line),
+                    Severity::Warning,
+                    "duplicate-requirement",
+                    format!(
+                        "{} is assigned to both Phase {} and Phase {}",
+                        req.id, first, phase.number
+                    ),
+                ));
+            }
+        }
+    }
+
+    // Without a REQUIREMENTS.md there is nothing to cross-check against
+    if tracked.checklist.is_empty() && tracked.traceability.is_empty() {
+        return;
+    }
+
+    let defined: HashSet<&str> = tracked
+        .checklist
+        .iter()
+        .map(|e| e.id.as_str())
+        .chain(tracked.traceability.iter().map(|r| r.id.as_str()))
+        .collect();
+
+    for phase in &data.roadmap.phases {
+        for req in &phase.requirements {
+            if !defined.contains(req.id.as_str()) {
+                diagnostics.push(Diagnostic::new(
+                    roadmap_path,
+                    req.span.as_ref().map(|span| span.start_line),
+                    Severity::Error,
+                    "requirement-undefined",
+                    format!(
+                        "{} (Phase {}) is not defined in REQUIREMENTS.md",
+                        req.id, phase.number
+                    ),
+                ));
+            }
+        }
+    }
+
+    for entry in &tracked.checklist {
+        if !mapped.contains_key(entry.id.as_str()) {
+            diagnostics.push(Diagnostic::new(
+                requirements_path,
+                find_line(requirements, |l| l.contains(&format!("**{}**", entry.id))),
+                Severity::Warning,
+                "requirement-unmapped",
+                format!("{} is not assigned to any phase in ROADMAP.md", entry.id),
+            ));
+        }
+    }
+
+    for conflict in tracked.conflicts() {
+        diagnostics.push(Diagnostic::new(
+            requirements_path,
+            find_line(requirements, |l| {
+                l.starts_with('|') && l.contains(&format!(" {} ", conflict.id()))
+            }),
+            Severity::Warning,
+            "requirement-conflict",
+            conflict.to_string(),
+        ));
+    }
+}
+
+/// STATE.md's metrics table must agree with the counts computed from the other files
+///
+/// A metric of 0 is treated as not recorded.
+fn check_state_metrics(
+    files: &PlanningFiles,
+    data: &PlanningData,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let (path, content) = &files.state;
+    let state = &data.state;
+
+    let (phases_total, phases_complete) = data.phase_totals();
+    let (requirements_total, requirements_complete) = data.requirement_totals();
+
+    // (label, recorded in STATE.md, computed from the files, matches the metric's row)
+    type MetricCheck = (&'static str, usize, usize, fn(&str) -> bool);
+    let checks: [MetricCheck; 4] = [
+        (
+            "Total Phases",
+            state.total_phases as usize,
+            phases_total,
+            |l| is_metric_row(l, "Total Phases"),
+        ),
+        (
+            "Phases Complete",
+            state.phases_complete as usize,
+            phases_complete,
+            |l| is_metric_row(l, "Phases Complete"),
+        ),
+        (
+            "Requirements",
+            state.total_requirements as usize,
+            requirements_total,
+            is_requirements_total_row,
+        ),
+        (
+            "Requirements Complete",
+            state.requirements_complete as usize,
+            requirements_complete,
+            |l| is_metric_row(l, "Requirements Complete"),
+        ),
+    ];
+
+    for (label, recorded, computed, is_row) in checks {
+        if recorded != 0 && recorded != computed {
+            diagnostics.push(Diagnostic::new(
+                path,
+                find_line(content, is_row),
+                Severity::Warning,
+                "state-metric-mismatch",
+                format!(
+                    "{} is {} but the planning files give {}",
+                    label, recorded, computed
+                ),
+            ));
+        }
+    }
+}
+
+/// Every PLAN.md needs valid frontmatter whose `depends_on` names existing plans
+///
+/// Checks every loaded plan: those of the roadmap's phases and archived phases, as
+/// the dashboard shows them, and those in phase directories the roadmap lacks,
+/// which are reported as well.
+fn check_plans(data: &PlanningData, diagnostics: &mut Vec<Diagnostic>) {
+    let archived = data
+        .roadmap
+        .milestones
+        .iter()
+        .flat_map(|m| &m.archived_phases);
+    let plans: Vec<&Plan> = data
+        .roadmap
+        .phases
+        .iter()
+        .chain(archived)
+        .flat_map(|phase| &phase.plans)
+        .chain(&data.unattached_plans)
+        .collect();
+    let ids: HashSet<&str> = plans.iter().map(|p| p.id.as_str()).collect();
+
+    for plan in &data.unattached_plans {
+        diagnostics.push(Diagnostic::new(
+            &plan.path,
+            None,
+            Severity::Warning,
+            "unlisted-phase-plan",
+            format!("{} is in a phase directory with no ROADMAP phase", plan.id),
+        ));
+    }
+
+    for plan in plans {
+        let Some(content) = read_checked(&plan.path, diagnostics) else {
+            continue;
+        };
+
+        let Some(frontmatter) = &plan.frontmatter else {
+            let (code, severity, message) = if content.trim_start().starts_with("---") {
+                (
+                    "invalid-frontmatter",
+                    Severity::Error,
+                    format!("{} has frontmatter that is not valid YAML", plan.id),
+                )
+            } else {
+                (
+                    "missing-frontmatter",
+                    Severity::Warning,
+                    format!(
+                        "{} has no YAML frontmatter (wave, depends_on, must_haves)",
+                        plan.id
+                    ),
+                )
+            };
+            diagnostics.push(Diagnostic::new(
+                &plan.path,
+                Some(1),
+                severity,
+                code,
+                message,
+            ));
+            continue;
+        };
+
+        for dep in &frontmatter.depends_on {
+            if !ids.contains(dep.as_str()) {
+                diagnostics.push(Diagnostic::new(
+                    &plan.path,
+                    find_line(&content, |l| l.starts_with("depends_on")),
+                    Severity::Error,
+                    "dangling-depends-on",
+                    format!("{} depends on {}, which has no PLAN.md", plan.id, dep),
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::load_planning_data;
+    use tempfile::tempdir;
+
+    fn write(dir: &Path, name: &str, content: &str) {
+        let path = dir.join(name);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    fn lint_dir(dir: &Path) -> Vec<Diagnostic> {
+        let data = load_planning_data(dir).unwrap();
+        lint(dir, &data)
+    }
+
+    fn codes(diagnostics: &[Diagnostic]) -> Vec<&'static str> {
+        diagnostics.iter().map(|d| d.code).collect()
+    }
+
+    const ROADMAP: &str = r#"# Roadmap
+
+### Phase 1: Foundation
+
+**Requirements:**
+- DISP-01: Display stuff
+
+### Phase 2: Navigation
+
+**Requirements:**
+- NAV-01: Move around
+"#;
+
+    const REQUIREMENTS: &str = r#"# Requirements
+
+- [x] **DISP-01**: Display stuff
+- [ ] **NAV-01**: Move around
+"#;
+
+    #[test]
+    fn test_consistent_files_are_clean() {
+        let dir = tempdir().unwrap();
+        write(dir.path(), "ROADMAP.md", ROADMAP);
+        write(dir.path(), "REQUIREMENTS.md", REQUIREMENTS);
+        write(
+            dir.path(),
+            "STATE.md",
+            "| Total Phases | 2 |\n| Total Requirements | 2 |\n| Requirements Complete | 1 |\n",
+        );
+
+        assert_eq!(lint_dir(dir.path()), vec![]);
+    }
+
+    #[test]
+    fn test_config_issues_reported_with_line() {
+        let dir = tempdir().unwrap();
+        write(dir.path(), "ROADMAP.md", ROADMAP);
+        write(dir.path(), "REQUIREMENTS.md", REQUIREMENTS);
+        write(
+            dir.path(),
+            "config.json",
+            "{\n  \"mode\": \"yolo\",\n  \"depth\": \"deep\",\n  \"colour\": true\n}\n",
+        );
+
+        let diagnostics: Vec<Diagnostic> = lint_dir(dir.path())
+            .into_iter()
+            .filter(|d| d.code.starts_with("config"))
+            .collect();
+
+        assert_eq!(
+            codes(&diagnostics),
+            vec!["config-invalid", "config-unknown-key"]
+        );
+        assert_eq!(diagnostics[0].line, Some(3));
+        assert_eq!(diagnostics[1].line, Some(4));
+        assert_eq!(diagnostics[1].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn test_duplicate_phase_reports_second_heading() {
+        let dir = tempdir().unwrap();
+        write(
+            dir.path(),
+            "ROADMAP.md",
+            &format!("{}\n### Phase 2: Again\n", ROADMAP),
+        );
+
+        let diagnostics = lint_dir(dir.path());
+
+        assert_eq!(codes(&diagnostics), vec!["duplicate-phase"]);
+        assert_eq!(diagnostics[0].line, Some(13));
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+        assert!(diagnostics[0]
+            .to_string()
+            .ends_with("ROADMAP.md:13: error[duplicate-phase]: Phase 2 is defined more than once"));
+    }
+
+    #[test]
+    fn test_requirement_ids_cross_checked() {
+        let dir = tempdir().unwrap();
+        write(dir.path(), "ROADMAP.md", ROADMAP);
+        write(
+            dir.path(),
+            "REQUIREMENTS.md",
+            "- [x] **DISP-01**: Display stuff\n- [ ] **CMD-01**: Run commands\n",
+        );
+
+        let diagnostics = lint_dir(dir.path());
+
+        assert_eq!(
+            codes(&diagnostics),
+            vec!["requirement-unmapped", "requirement-undefined"]
+        );
+        assert_eq!(diagnostics[0].line, Some(2));
+        assert!(diagnostics[1].message.starts_with("NAV-01"));
+        assert_eq!(diagnostics[1].line, Some(11));
+    }
+
+    #[test]
+    fn test_state_metrics_compared_with_computed_counts() {
+        let dir = tempdir().unwrap();
+        write(dir.path(), "ROADMAP.md", ROADMAP);
+        write(dir.path(), "REQUIREMENTS.md", REQUIREMENTS);
+        write(
+            dir.path(),
+            "STATE.md",
+            "| Metric | Value |\n|---|---|\n| Total Phases | 3 |\n| Requirements Complete | 1 |\n",
+        );
+
+        let diagnostics = lint_dir(dir.path());
+
+        assert_eq!(codes(&diagnostics), vec!["state-metric-mismatch"]);
+        assert_eq!(diagnostics[0].line, Some(3));
+        assert_eq!(
+            diagnostics[0].message,
+            "Total Phases is 3 but the planning files give 2"
+        );
+    }
+
+    #[test]
+    fn test_plan_frontmatter_and_dependencies() {
+        let dir = tempdir().unwrap();
+        write(dir.path(), "ROADMAP.md", ROADMAP);
+        write(
+            dir.path(),
+            "phases/01-foundation/01-01-PLAN.md",
+            "<objective>No frontmatter</objective>\n",
+        );
+        write(
+            dir.path(),
+            "phases/01-foundation/01-02-PLAN.md",
+            "---\nphase: 01-foundation\nplan: 02\ndepends_on: [\"01-01\", \"01-09\"]\n---\n",
+        );
+        write(
+            dir.path(),
+            "phases/02-navigation/02-01-PLAN.md",
+            "---\nwave: [not a number\n---\n",
+        );
+
+        let diagnostics = lint_dir(dir.path());
+
+        assert_eq!(
+            codes(&diagnostics),
+            vec![
+                "missing-frontmatter",
+                "dangling-depends-on",
+                "invalid-frontmatter"
+            ]
+        );
+        assert_eq!(diagnostics[1].line, Some(4));
+        assert!(diagnostics[1].message.contains("01-09"));
+    }
+
+    #[test]
+    fn test_plans_outside_roadmap_phases_are_linted() {
+        let dir = tempdir().unwrap();
+        write(dir.path(), "ROADMAP.md", ROADMAP);
+        write(
+            dir.path(),
+            "phases/07-orphan/07-01-PLAN.md",
+            "---\ndepends_on: [\"06-01\"]\n---\n",
+        );
+        write(
+            dir.path(),
+            "phases/07-orphan/07-02-PLAN.md",
+            "No frontmatter\n",
+        );
+
+        let diagnostics = lint_dir(dir.path());
+
+        assert_eq!(
+            codes(&diagnostics),
+            vec![
+                "unlisted-phase-plan",
+                "dangling-depends-on",
+                "unlisted-phase-plan",
+                "missing-frontmatter"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_files_unreadable_at_lint_time_are_reported() {
+        let dir = tempdir().unwrap();
+        write(dir.path(), "ROADMAP.md", ROADMAP);
+        write(
+            dir.path(),
+            "phases/01-foundation/01-01-PLAN.md",
+            "---\nwave: 1\n---\n",
+        );
+        write(dir.path(), "config.json", r#"{"mode": "sideways"}"#);
+        let data = load_planning_data(dir.path()).unwrap();
+
+        for file in ["phases/01-foundation/01-01-PLAN.md", "config.json"] {
+            std::fs::write(dir.path().join(file), [0xff, 0xfe]).unwrap();
+        }
+        let diagnostics = lint(dir.path(), &data);
+
+        assert_eq!(
+            codes(&diagnostics),
+            vec!["unreadable-file", "config-invalid", "unreadable-file"]
+        );
+        assert!(diagnostics[0]
+            .message
+            .starts_with("cannot read config.json"));
+    }
+
+    #[test]
+    fn test_parse_warnings_reported_with_line() {
+        let dir = tempdir().unwrap();
+        write(
+            dir.path(),
+            "ROADMAP.md",
+            &format!("{}\n### Phase X: Unnumbered\n", ROADMAP),
+        );
+
+        let diagnostics = lint_dir(dir.path());
+
+        assert_eq!(codes(&diagnostics), vec!["parse-warning"]);
+        assert_eq!(diagnostics[0].line, Some(13));
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn test_missing_roadmap_is_an_error() {
+        let dir = tempdir().unwrap();
+
+        let diagnostics = lint_dir(dir.path());
+
+        assert_eq!(codes(&diagnostics), vec!["missing-file"]);
+    }
+}
@@ -1,15 +1,61 @@
+use std::fmt::Write as _;
+use std::io::{ErrorKind, Write};
 use std::path::PathBuf;
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use color_eyre::Result;
 
+use kata_tui::app::resolve_planning_dir;
+use kata_tui::data::load_planning_data;
+use kata_tui::export::{export, ExportFormat};
+use kata_tui::lint::{lint, LintFormat, Severity};
+use kata_tui::verify::{report, repository_root, verify, VerifyFormat};
+
 /// Terminal dashboard for Kata project visibility
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
     /// Path to .planning directory (defaults to ./.planning)
-    #[arg(short, long)]
+    #[arg(short, long, global = true)]
     planning_dir: Option<PathBuf>,
+
+    /// Show every project with a .planning directory below this root, switchable with `p`
+    #[arg(long, value_name = "DIR", conflicts_with = "planning_dir")]
+    workspace: Option<PathBuf>,
+
+    /// Kata executable used to run commands from the dashboard
+    #[arg(long, default_value = kata_tui::runner::DEFAULT_KATA_BIN)]
+    kata_bin: PathBuf,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+/// Non-interactive commands (the dashboard runs when none is given)
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Print the parsed planning data as a versioned snapshot
+    Export {
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = ExportFormat::Json)]
+        format: ExportFormat,
+    },
+    /// Check .planning/ files for inconsistencies; exits 1 if errors are found
+    Lint {
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = LintFormat::Text)]
+        format: LintFormat,
+
+        /// Exit 1 on warnings as well as errors
+        #[arg(long)]
+        strict: bool,
+    },
+    /// Check plan must_haves against the working tree; exits 1 if any check fails
+    Verify {
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = VerifyFormat::Text)]
+        format: VerifyFormat,
+    },
 }
 
 #[tokio::main]
@@ -19,6 +65,116 @@ async fn main() -> Result<()> {
     // Parse CLI arguments
     let args = Args::parse();
 
-    // Run the application
-    kata_tui::app::run(args.planning_dir).await
+    match args.command {
+        // Subcommands never touch the terminal, so they work in CI and pipes
+        Some(Command::Export { format }) => {
+            let data = load_planning_data(&resolve_planning_dir(args.planning_dir))?;
+            write_stdout(&export(&data, format)?)
+        }
+        Some(Command::Lint { format, strict }) => {
+            let planning_dir = resolve_planning_dir(args.planning_dir);
+            let data = load_planning_data(&planning_dir)?;
+            let diagnostics = lint(&planning_dir, &data);
+
+            let mut output = String::new();
+            match format {
+                LintFormat::Json => {
+                    writeln!(output, "{}", serde_json::to_string_pretty(&diagnostics)?)?
+                }
+                LintFormat::Text => {
+                    let cwd = std::env::current_dir().unwrap_or_default();
+                    for diagnostic in &diagnostics {
+                        let mut diagnostic = diagnostic.clone();
+                        // Shorter, clickable paths relative to where the command ran
+                        if let Ok(relative) = diagnostic.file.strip_prefix(&cwd) {
+                            diagnostic.file = relative.to_path_buf();
+                        }
+                        writeln!(output, "{}", diagnostic)?;
+                    }
+                    let errors = diagnostics
+                        .iter()
+                        .filter(|d| d.severity == Severity::Error)
+                        .count();
+                    writeln!(
+                        output,
+                        "{} error(s), {} warning(s)",
+                        errors,
+                        diagnostics.len() - errors
+                    )?;
+                }
+            }
+            write_stdout(&output)?;
+
+            let threshold = if strict {
+                Severity::Warning
+            } else {
+                Severity::Error
+            };
+            if diagnostics.iter().any(|d| d.severity >= threshold) {
+                std::process::exit(1);
+            }
+            Ok(())
+        }
+        Some(Command::Verify { format }) => {
+            let planning_dir = resolve_planning_dir(args.planning_dir);
+            let mut data = load_planning_data(&planning_dir)?;
+            verify(repository_root(&planning_dir), &mut data);
+            let reports = report(&data);
+
+            let mut output = String::new();
+            match format {
+                VerifyFormat::Json => {
+                    writeln!(output, "{}", serde_json::to_string_pretty(&reports)?)?
+                }
+                VerifyFormat::Text => {
+                    for plan in &reports {
+                        let verdict = if plan.passed { "PASS" } else { "FAIL" };
+                        writeln!(output, "{} {}", verdict, plan.plan)?;
+                        for check in &plan.checks {
+                            match &check.failure {
+                                None => {
+                                    writeln!(output, "  ✓ {}: {}", check.path, check.expectation)?
+                                }
+                                Some(failure) => writeln!(
+                                    output,
+                                    "  ✗ {}: {} ({})",
+                                    check.path, check.expectation, failure
+                                )?,
+                            }
+                        }
+                    }
+                    let failed = reports.iter().filter(|plan| !plan.passed).count();
+                    writeln!(
+                        output,
+                        "{} plan(s) verified, {} failed",
+                        reports.len(),
+                        failed
+                    )?;
+                }
+            }
+            write_stdout(&output)?;
+
+            if reports.iter().any(|plan| !plan.passed) {
+                std::process::exit(1);
+            }
+            Ok(())
+        }
+        // Run the application
+        None => kata_tui::app::run(args.planning_dir, args.workspace, args.kata_bin).await,
+    }
+}
+
+/// Write subcommand output to stdout
+///
+/// A reader that closes early (`kata-tui export | head`) is not an error; the rest
+/// of the output is dropped.
+fn write_stdout(output: &str) -> Result<()> {
+    let mut stdout = std::io::stdout().lock();
+    match stdout
+        .write_all(output.as_bytes())
+        .and_then(|()| stdout.flush())
+    {
+        Err(e) if e.kind() == ErrorKind::BrokenPipe => Ok(()),
+        result => Ok(result?),
+    }
 }
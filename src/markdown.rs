@@ -0,0 +1,515 @@
+use pulldown_cmark::{
+    Alignment, CodeBlockKind, Event, HeadingLevel, LinkType, Options, Parser, Tag, TagEnd,
+};
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+};
+
+/// Render markdown source into styled ratatui text
+///
+/// Supports bold, italic, strikethrough, inline code, fenced code blocks, headings,
+/// nested ordered/unordered lists, task lists, block quotes, tables and links.
+/// Raw HTML is shown as plain text.
+pub fn render_markdown(source: &str) -> Text<'static> {
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+
+    let mut renderer = Renderer::default();
+    for event in Parser::new_ext(source, options) {
+        renderer.handle(event);
+    }
+    renderer.finish()
+}
+
+/// Render markdown source as a single line of spans, for use inside other lines
+///
+/// Block structure is flattened: separate lines are joined with spaces.
+pub fn render_inline(source: &str) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let lines = render_markdown(source).lines.into_iter();
+    for (idx, line) in lines.filter(|line| line.width() > 0).enumerate() {
+        if idx > 0 {
+            spans.push(Span::raw(" "));
+        }
+        spans.extend(line.spans);
+    }
+    spans
+}
+
+/// Style for inline code and code blocks
+fn code_style() -> Style {
+    Style::default().fg(Color::LightYellow)
+}
+
+/// Style for a heading level
+fn heading_style(level: HeadingLevel) -> Style {
+    match level {
+        HeadingLevel::H1 => Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+        HeadingLevel::H2 => Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+        _ => Style::default().add_modifier(Modifier::BOLD),
+    }
+}
+
+/// One level of list nesting
+struct ListLevel {
+    /// Next number for ordered lists, None for bullets
+    next: Option<u64>,
+    /// Width of this level's marker, used to indent continuation lines
+    marker_width: usize,
+}
+
+/// Table being collected; rendered once all cells are known
+#[derive(Default)]
+struct TableBuffer {
+    alignments: Vec<Alignment>,
+    rows: Vec<Vec<Vec<Span<'static>>>>,
+    header_rows: usize,
+}
+
+/// Streaming event-to-lines converter
+#[derive(Default)]
+struct Renderer {
+    lines: Vec<Line<'static>>,
+    spans: Vec<Span<'static>>,
+    styles: Vec<Style>,
+    lists: Vec<ListLevel>,
+    item_marker: Option<String>,
+    quote_depth: usize,
+    in_code_block: bool,
+    links: Vec<(LinkType, String)>,
+    table: Option<TableBuffer>,
+}
+
+impl Renderer {
+    fn handle(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) if self.in_code_block => {
+                for line in text.lines() {
+                    self.push_span(Span::styled(format!("  {}", line), code_style()));
+                    self.flush_line();
+                }
+            }
+            Event::Text(text) => self.push_text(&text),
+            Event::Code(code) => {
+                let style = self.style().patch(code_style());
+                self.push_span(Span::styled(code.to_string(), style));
+            }
+            Event::Html(html) => {
+                for line in html.lines() {
+                    self.push_text(line);
+                    self.flush_line();
+                }
+            }
+            Event::InlineHtml(html) => self.push_text(&html),
+            Event::TaskListMarker(checked) => {
+                let (marker, color) = if checked {
+                    ("[x] ", Color::Green)
+                } else {
+                    ("[ ] ", Color::DarkGray)
+                };
+                self.push_span(Span::styled(marker, Style::default().fg(color)));
+            }
+            Event::SoftBreak => self.push_text(" "),
+            Event::HardBreak => self.flush_line(),
+            Event::Rule => {
+                self.flush_line();
+                self.lines.push(Line::from(Span::styled(
+                    "─".repeat(40),
+                    Style::default().fg(Color::DarkGray),
+                )));
+                self.blank_line();
+            }
+            Event::FootnoteReference(label) => self.push_text(&format!("[^{}]", label)),
+            Event::InlineMath(math) | Event::DisplayMath(math) => self.push_text(&math),
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Heading { level, .. } => {
+                self.flush_line();
+                self.styles.push(heading_style(level));
+            }
+            Tag::BlockQuote(_) => {
+                self.flush_line();
+                self.quote_depth += 1;
+            }
+            Tag::CodeBlock(kind) => {
+                self.flush_line();
+                self.in_code_block = true;
+                if let CodeBlockKind::Fenced(lang) = kind {
+                    if !lang.is_empty() {
+                        self.push_span(Span::styled(
+                            format!("  [{}]", lang),
+                            Style::default().fg(Color::DarkGray),
+                        ));
+                        self.flush_line();
+                    }
+                }
+            }
+            Tag::List(start) => {
+                self.flush_line();
+                self.lists.push(ListLevel {
+                    next: start,
+                    marker_width: 2,
+                });
+            }
+            Tag::Item => {
+                self.flush_line();
+                if let Some(level) = self.lists.last_mut() {
+                    let marker = match level.next.as_mut() {
+                        Some(n) => {
+                            let marker = format!("{}. ", n);
+                            *n += 1;
+                            marker
+                        }
+                        None => "• ".to_string(),
+                    };
+                    level.marker_width = marker.chars().count();
+                    self.item_marker = Some(marker);
+                }
+            }
+            Tag::Emphasis => self.push_modifier(Modifier::ITALIC),
+            Tag::Strong => self.push_modifier(Modifier::BOLD),
+            Tag::Strikethrough => self.push_modifier(Modifier::CROSSED_OUT),
+            Tag::Link {
+                link_type,
+                dest_url,
+                ..
+            } => {
+                self.styles.push(
+                    Style::default()
+                        .fg(Color::Blue)
+                        .add_modifier(Modifier::UNDERLINED),
+                );
+                self.links.push((link_type, dest_url.to_string()));
+            }
+            Tag::Image { .. } => self.push_text("[image: "),
+            Tag::Table(alignments) => {
+                self.flush_line();
+                self.table = Some(TableBuffer {
+                    alignments,
+                    ..Default::default()
+                });
+            }
+            Tag::TableHead | Tag::TableRow => {
+                if let Some(table) = self.table.as_mut() {
+                    table.rows.push(Vec::new());
+                }
+            }
+            Tag::TableCell => {
+                self.spans.clear();
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => {
+                self.flush_line();
+                if self.lists.is_empty() {
+                    self.blank_line();
+                }
+            }
+            TagEnd::Heading(_) => {
+                self.flush_line();
+                self.styles.pop();
+                self.blank_line();
+            }
+            TagEnd::BlockQuote(_) => {
+                self.flush_line();
+                self.quote_depth = self.quote_depth.saturating_sub(1);
+                if self.quote_depth == 0 {
+                    self.blank_line();
+                }
+            }
+            TagEnd::CodeBlock => {
+                self.in_code_block = false;
+                self.blank_line();
+            }
+            TagEnd::List(_) => {
+                self.flush_line();
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.blank_line();
+                }
+            }
+            TagEnd::Item => {
+                self.flush_line();
+                self.item_marker = None;
+            }
+            TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough => {
+                self.styles.pop();
+            }
+            TagEnd::Link => {
+                self.styles.pop();
+                if let Some((link_type, url)) = self.links.pop() {
+                    let is_autolink = matches!(link_type, LinkType::Autolink | LinkType::Email);
+                    if !is_autolink && !url.is_empty() {
+                        self.push_span(Span::styled(
+                            format!(" ({})", url),
+                            Style::default().fg(Color::DarkGray),
+                        ));
+                    }
+                }
+            }
+            TagEnd::Image => self.push_text("]"),
+            TagEnd::TableCell => {
+                let cell = std::mem::take(&mut self.spans);
+                if let Some(row) = self.table.as_mut().and_then(|t| t.rows.last_mut()) {
+                    row.push(cell);
+                }
+            }
+            TagEnd::TableHead => {
+                if let Some(table) = self.table.as_mut() {
+                    table.header_rows = table.rows.len();
+                }
+            }
+            TagEnd::Table => {
+                if let Some(table) = self.table.take() {
+                    self.render_table(table);
+                    self.blank_line();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Current style: the composition of all active styles
+    fn style(&self) -> Style {
+        self.styles
+            .iter()
+            .fold(Style::default(), |acc, style| acc.patch(*style))
+    }
+
+    fn push_modifier(&mut self, modifier: Modifier) {
+        self.styles.push(Style::default().add_modifier(modifier));
+    }
+
+    fn push_text(&mut self, text: &str) {
+        let span = Span::styled(text.to_string(), self.style());
+        self.push_span(span);
+    }
+
+    /// Append a span, starting a new line with list/quote prefixes if needed
+    fn push_span(&mut self, span: Span<'static>) {
+        if self.spans.is_empty() && self.table.is_none() {
+            self.spans = self.line_prefix();
+        }
+        self.spans.push(span);
+    }
+
+    /// Prefix for a new line: quote bars, list indentation and item marker
+    fn line_prefix(&mut self) -> Vec<Span<'static>> {
+        let mut prefix = Vec::new();
+
+        if self.quote_depth > 0 {
+            prefix.push(Span::styled(
+                "│ ".repeat(self.quote_depth),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+
+        if let Some((current, parents)) = self.lists.split_last() {
+            let indent: usize = parents.iter().map(|level| level.marker_width).sum();
+            match self.item_marker.take() {
+                Some(marker) => {
+                    prefix.push(Span::raw(" ".repeat(indent)));
+                    prefix.push(Span::styled(marker, Style::default().fg(Color::Cyan)));
+                }
+                None => prefix.push(Span::raw(" ".repeat(indent + current.marker_width))),
+            }
+        }
+
+        prefix
+    }
+
+    fn flush_line(&mut self) {
+        if !self.spans.is_empty() {
+            let spans = std::mem::take(&mut self.spans);
+            self.lines.push(Line::from(spans));
+        }
+    }
+
+    /// Add a blank separator line, never doubling up
+    fn blank_line(&mut self) {
+        if self.lines.last().is_some_and(|line| line.width() > 0) {
+            self.lines.push(Line::from(""));
+        }
+    }
+
+    fn render_table(&mut self, table: TableBuffer) {
+        let columns = table.rows.iter().map(Vec::len).max().unwrap_or(0);
+        let widths: Vec<usize> = (0..columns)
+            .map(|col| {
+                table
+                    .rows
+                    .iter()
+                    .filter_map(|row| row.get(col))
+                    .map(|cell| cell.iter().map(Span::width).sum::<usize>())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        let border = Style::default().fg(Color::DarkGray);
+
+        for (row_idx, row) in table.rows.into_iter().enumerate() {
+            let is_header = row_idx < table.header_rows;
+            let mut spans = self.line_prefix();
+
+            for (col, width) in widths.iter().enumerate() {
+                if col > 0 {
+                    spans.push(Span::styled(" │ ", border));
+                }
+                let cell = row.get(col).cloned().unwrap_or_default();
+                let cell_width: usize = cell.iter().map(Span::width).sum();
+                let padding = width.saturating_sub(cell_width);
+                let (left, right) = match table.alignments.get(col) {
+                    Some(Alignment::Right) => (padding, 0),
+                    Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+                    _ => (0, padding),
+                };
+
+                spans.push(Span::raw(" ".repeat(left)));
+                for span in cell {
+                    if is_header {
+                        spans.push(span.patch_style(Style::default().add_modifier(Modifier::BOLD)));
+                    } else {
+                        spans.push(span);
+                    }
+                }
+                spans.push(Span::raw(" ".repeat(right)));
+            }
+            self.lines.push(Line::from(spans));
+
+            if is_header && row_idx + 1 == table.header_rows {
+                let separator: Vec<String> = widths.iter().map(|w| "─".repeat(*w)).collect();
+                let mut spans = self.line_prefix();
+                spans.push(Span::styled(separator.join("─┼─"), border));
+                self.lines.push(Line::from(spans));
+            }
+        }
+    }
+
+    fn finish(mut self) -> Text<'static> {
+        self.flush_line();
+        while self.lines.last().is_some_and(|line| line.width() == 0) {
+            self.lines.pop();
+        }
+        Text::from(self.lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Render to a plain-text form with style annotations for snapshotting
+    fn snapshot(source: &str) -> String {
+        render_markdown(source)
+            .lines
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| {
+                        let style = span.style;
+                        let mut marks = Vec::new();
+                        if style.add_modifier.contains(Modifier::BOLD) {
+                            marks.push("b");
+                        }
+                        if style.add_modifier.contains(Modifier::ITALIC) {
+                            marks.push("i");
+                        }
+                        if style.add_modifier.contains(Modifier::UNDERLINED) {
+                            marks.push("u");
+                        }
+                        if style.add_modifier.contains(Modifier::CROSSED_OUT) {
+                            marks.push("s");
+                        }
+                        if style.fg == Some(Color::LightYellow) {
+                            marks.push("code");
+                        }
+                        if marks.is_empty() {
+                            span.content.to_string()
+                        } else {
+                            format!("{{{}:{}}}", marks.join(","), span.content)
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_inline_formatting() {
+        insta::assert_snapshot!(snapshot(
+            "Some **bold**, *italic*, ***both***, ~~gone~~ and `code` text."
+        ));
+    }
+
+    #[test]
+    fn test_headings_and_paragraphs() {
+        insta::assert_snapshot!(snapshot(
+            "# Title\n\nIntro paragraph\nwith a soft break.\n\n## Section\n\n### Subsection\n\nBody."
+        ));
+    }
+
+    #[test]
+    fn test_nested_lists() {
+        insta::assert_snapshot!(snapshot(
+            "- First\n- Second\n  - Nested **bold**\n  - Nested two\n    1. Deep one\n    2. Deep two\n- Third\n\n1. One\n2. Two\n\n- [x] Done\n- [ ] Todo"
+        ));
+    }
+
+    #[test]
+    fn test_code_block() {
+        insta::assert_snapshot!(snapshot(
+            "Before:\n\n```rust\nfn main() {\n    println!(\"hi\");\n}\n```\n\nAfter."
+        ));
+    }
+
+    #[test]
+    fn test_block_quote() {
+        insta::assert_snapshot!(snapshot(
+            "> Quoted *text*\n> continues\n>\n> > Nested quote\n\nOutside."
+        ));
+    }
+
+    #[test]
+    fn test_table() {
+        insta::assert_snapshot!(snapshot(
+            "| Decision | Outcome |\n|----------|--------:|\n| Rust + `ratatui` | Implemented |\n| TEA | Done |"
+        ));
+    }
+
+    #[test]
+    fn test_links() {
+        insta::assert_snapshot!(snapshot(
+            "See [the archive](milestones/v0.1-ROADMAP.md) or <https://example.com>."
+        ));
+    }
+
+    #[test]
+    fn test_render_inline_flattens_lines() {
+        let spans = render_inline("Install via `cargo install`\n\nthen run");
+        let text: String = spans.iter().map(|s| s.content.as_ref()).collect();
+
+        assert_eq!(text, "Install via cargo install then run");
+        assert_eq!(spans[1].style.fg, Some(Color::LightYellow));
+    }
+
+    #[test]
+    fn test_empty_source() {
+        assert!(render_markdown("").lines.is_empty());
+    }
+}
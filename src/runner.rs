@@ -0,0 +1,314 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::Command;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+
+use crate::event::Event;
+
+/// Default executable used to run Kata commands
+pub const DEFAULT_KATA_BIN: &str = "kata";
+
+/// Which pipe a line of output came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputStream {
+    Stdout,
+    Stderr,
+}
+
+/// How a command finished
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandStatus {
+    /// Process exited; `None` when it was terminated by a signal
+    Exited(Option<i32>),
+    /// Cancelled by the user (Ctrl-C)
+    Cancelled,
+    /// The executable could not be started
+    SpawnFailed(String),
+}
+
+impl CommandStatus {
+    /// Whether the command ran to completion with exit code 0
+    pub fn success(&self) -> bool {
+        matches!(self, CommandStatus::Exited(Some(0)))
+    }
+}
+
+impl fmt::Display for CommandStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandStatus::Exited(Some(code)) => write!(f, "exit {}", code),
+            CommandStatus::Exited(None) => write!(f, "killed by signal"),
+            CommandStatus::Cancelled => write!(f, "cancelled"),
+            CommandStatus::SpawnFailed(err) => write!(f, "failed to start: {}", err),
+        }
+    }
+}
+
+/// Progress of a running command, delivered as `Event::CommandOutput`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandOutput {
+    /// One line of output, without the trailing newline
+    Line { stream: OutputStream, text: String },
+    /// The command has finished; no further output follows
+    Finished(CommandStatus),
+}
+
+/// Runs Kata commands in the background, one at a time
+///
+/// Output is streamed line by line through the event channel so the main loop
+/// keeps rendering and handling keys while a command runs.
+pub struct CommandRunner {
+    program: PathBuf,
+    /// Directory commands start in; None inherits the dashboard's
+    working_dir: Option<PathBuf>,
+    tx: mpsc::UnboundedSender<Event>,
+    cancel: CancellationToken,
+    task: Option<JoinHandle<()>>,
+}
+
+impl CommandRunner {
+    /// Create a runner for the given executable (e.g., "kata" or a full path)
+    pub fn new(program: impl Into<PathBuf>, tx: mpsc::UnboundedSender<Event>) -> Self {
+        Self {
+            program: program.into(),
+            working_dir: None,
+            tx,
+            cancel: CancellationToken::new(),
+            task: None,
+        }
+    }
+
+    /// The executable commands are run with
+    pub fn program(&self) -> &Path {
+        &self.program
+    }
+
+    /// Start later commands in `dir` (e.g., the active workspace project)
+    pub fn set_working_dir(&mut self, dir: impl Into<PathBuf>) {
+        self.working_dir = Some(dir.into());
+    }
+
+    /// Whether a command is still running
+    pub fn is_running(&self) -> bool {
+        self.task.as_ref().is_some_and(|task| !task.is_finished())
+    }
+
+    /// Start `program args...` in the background
+    ///
+    /// Returns false (and does nothing) if a command is already running.
+    pub fn run(&mut self, args: Vec<String>) -> bool {
+        if self.is_running() {
+            return false;
+        }
+
+        self.cancel = CancellationToken::new();
+        self.task = Some(tokio::spawn(execute(
+            self.program.clone(),
+            self.working_dir.clone(),
+            args,
+            self.tx.clone(),
+            self.cancel.clone(),
+        )));
+        true
+    }
+
+    /// Kill the running command, if any
+    pub fn cancel(&self) {
+        self.cancel.cancel();
+    }
+}
+
+impl Drop for CommandRunner {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
+
+/// Spawn the process and forward its output until it exits or is cancelled
+async fn execute(
+    program: PathBuf,
+    working_dir: Option<PathBuf>,
+    args: Vec<String>,
+    tx: mpsc::UnboundedSender<Event>,
+    cancel: CancellationToken,
+) {
+    let send = |output: CommandOutput| {
+        let _ = tx.send(Event::CommandOutput(output));
+    };
+
+    let mut command = Command::new(&program);
+    if let Some(dir) = &working_dir {
+        command.current_dir(dir);
+    }
+    let spawned = command
+        .args(&args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn();
+
+    let mut child = match spawned {
+        Ok(child) => child,
+        Err(e) => {
+            send(CommandOutput::Finished(CommandStatus::SpawnFailed(
+                e.to_string(),
+            )));
+            return;
+        }
+    };
+
+    let mut stdout = BufReader::new(child.stdout.take().expect("stdout is piped")).lines();
+    let mut stderr = BufReader::new(child.stderr.take().expect("stderr is piped")).lines();
+    let mut stdout_open = true;
+    let mut stderr_open = true;
+
+    let status = loop {
+        tokio::select! {
+            _ = cancel.cancelled() => {
+                let _ = child.kill().await;
+                break CommandStatus::Cancelled;
+            }
+            line = stdout.next_line(), if stdout_open => match line {
+                Ok(Some(text)) => send(CommandOutput::Line { stream: OutputStream::Stdout, text }),
+                _ => stdout_open = false,
+            },
+            line = stderr.next_line(), if stderr_open => match line {
+                Ok(Some(text)) => send(CommandOutput::Line { stream: OutputStream::Stderr, text }),
+                _ => stderr_open = false,
+            },
+            // Only reap once both pipes are drained so no trailing output is lost
+            result = child.wait(), if !stdout_open && !stderr_open => {
+                break match result {
+                    Ok(status) => CommandStatus::Exited(status.code()),
+                    Err(e) => CommandStatus::SpawnFailed(e.to_string()),
+                };
+            }
+        }
+    };
+
+    send(CommandOutput::Finished(status));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+    use std::time::Duration;
+    use tempfile::tempdir;
+
+    /// Write an executable shell script standing in for the `kata` binary
+    fn fake_kata(dir: &Path, body: &str) -> PathBuf {
+        let path = dir.join("kata");
+        std::fs::write(&path, format!("#!/bin/sh\n{}\n", body)).unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    async fn next_output(rx: &mut mpsc::UnboundedReceiver<Event>) -> CommandOutput {
+        let event = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("timed out waiting for command output")
+            .expect("channel closed");
+        match event {
+            Event::CommandOutput(output) => output,
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    /// Collect output until the command finishes
+    async fn collect(
+        rx: &mut mpsc::UnboundedReceiver<Event>,
+    ) -> (Vec<CommandOutput>, CommandStatus) {
+        let mut lines = Vec::new();
+        loop {
+            match next_output(rx).await {
+                CommandOutput::Finished(status) => return (lines, status),
+                line => lines.push(line),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_streams_stdout_stderr_and_exit_status() {
+        let dir = tempdir().unwrap();
+        let kata = fake_kata(dir.path(), "echo \"running $1 $2\"\necho oops >&2\nexit 3");
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut runner = CommandRunner::new(&kata, tx);
+
+        assert!(runner.run(vec!["plan-phase".to_string(), "4".to_string()]));
+        let (lines, status) = collect(&mut rx).await;
+
+        assert!(lines.contains(&CommandOutput::Line {
+            stream: OutputStream::Stdout,
+            text: "running plan-phase 4".to_string(),
+        }));
+        assert!(lines.contains(&CommandOutput::Line {
+            stream: OutputStream::Stderr,
+            text: "oops".to_string(),
+        }));
+        assert_eq!(status, CommandStatus::Exited(Some(3)));
+        assert!(!status.success());
+    }
+
+    #[tokio::test]
+    async fn test_runs_in_working_dir() {
+        let dir = tempdir().unwrap();
+        let project = tempdir().unwrap();
+        let kata = fake_kata(dir.path(), "pwd");
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut runner = CommandRunner::new(&kata, tx);
+        runner.set_working_dir(project.path());
+
+        assert!(runner.run(Vec::new()));
+        let (lines, _) = collect(&mut rx).await;
+
+        let expected = project.path().canonicalize().unwrap();
+        assert!(lines.iter().any(|line| matches!(
+            line,
+            CommandOutput::Line { text, .. } if Path::new(text).canonicalize().ok() == Some(expected.clone())
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_kills_running_command() {
+        let dir = tempdir().unwrap();
+        let kata = fake_kata(dir.path(), "echo started\nexec sleep 30");
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut runner = CommandRunner::new(&kata, tx);
+
+        assert!(runner.run(Vec::new()));
+        assert_eq!(
+            next_output(&mut rx).await,
+            CommandOutput::Line {
+                stream: OutputStream::Stdout,
+                text: "started".to_string(),
+            }
+        );
+        assert!(runner.is_running());
+        assert!(!runner.run(Vec::new()), "only one command at a time");
+
+        runner.cancel();
+        let (_, status) = collect(&mut rx).await;
+
+        assert_eq!(status, CommandStatus::Cancelled);
+    }
+
+    #[tokio::test]
+    async fn test_missing_executable_reports_spawn_failure() {
+        let dir = tempdir().unwrap();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut runner = CommandRunner::new(dir.path().join("no-such-kata"), tx);
+
+        runner.run(Vec::new());
+        let (lines, status) = collect(&mut rx).await;
+
+        assert!(lines.is_empty());
+        assert!(matches!(status, CommandStatus::SpawnFailed(_)));
+    }
+}
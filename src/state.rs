@@ -1,14 +1,34 @@
-use std::collections::HashSet;
+use std::collections::{HashSet, VecDeque};
 
 use ratatui::widgets::ListState;
 
-/// Input mode for modal state (normal navigation vs search vs help)
+use crate::burnup::Burnup;
+use crate::commands::KataCommand;
+use crate::data::PhaseId;
+use crate::decisions::DecisionColumn;
+use crate::graph::{DependencyGraph, GraphKind};
+use crate::runner::{CommandOutput, CommandStatus, OutputStream};
+use crate::workspace::ProjectSummary;
+
+/// Lines of command output kept for scrollback
+pub const MAX_OUTPUT_LINES: usize = 5000;
+
+/// Input mode for modal state (normal navigation, search, overlays, command palette)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum InputMode {
     #[default]
     Normal,
     Search,
     Help,
+    Command,
+    Diagnostics,
+    Decisions,
+    Settings,
+    Projects,
+    Activity,
+    Progress,
+    Graph,
+    Waves,
 }
 
 /// Which pane currently has focus
@@ -17,6 +37,166 @@ pub enum FocusedPane {
     #[default]
     Tree,
     Detail,
+    Output,
+}
+
+/// Output pane state for the most recent Kata command
+#[derive(Debug, Default)]
+pub struct CommandOutputState {
+    /// Whether the output pane is shown
+    pub visible: bool,
+    /// Command line being displayed (e.g., "kata plan-phase 4")
+    pub command: String,
+    /// Output lines, oldest first, capped at `MAX_OUTPUT_LINES`
+    pub lines: VecDeque<(OutputStream, String)>,
+    /// Whether the command is still running
+    pub running: bool,
+    /// Exit status once the command has finished
+    pub status: Option<CommandStatus>,
+    /// Lines scrolled back from the bottom (0 follows new output)
+    pub scroll_back: usize,
+}
+
+impl CommandOutputState {
+    /// Reset for a newly started command and show the pane
+    pub fn start(&mut self, command: String) {
+        self.visible = true;
+        self.command = command;
+        self.lines.clear();
+        self.running = true;
+        self.status = None;
+        self.scroll_back = 0;
+    }
+
+    /// Record output from the runner
+    pub fn apply(&mut self, output: CommandOutput) {
+        match output {
+            CommandOutput::Line { stream, text } => {
+                if self.lines.len() == MAX_OUTPUT_LINES {
+                    self.lines.pop_front();
+                }
+                self.lines.push_back((stream, text));
+                // Keep the view anchored while the user is reading scrollback
+                if self.scroll_back > 0 {
+                    self.scroll_back = (self.scroll_back + 1).min(self.max_scroll_back());
+                }
+            }
+            CommandOutput::Finished(status) => {
+                self.running = false;
+                self.status = Some(status);
+            }
+        }
+    }
+
+    /// Scroll towards older output
+    pub fn scroll_up(&mut self, lines: usize) -> bool {
+        let target = (self.scroll_back + lines).min(self.max_scroll_back());
+        let changed = target != self.scroll_back;
+        self.scroll_back = target;
+        changed
+    }
+
+    /// Scroll towards newer output
+    pub fn scroll_down(&mut self, lines: usize) -> bool {
+        let target = self.scroll_back.saturating_sub(lines);
+        let changed = target != self.scroll_back;
+        self.scroll_back = target;
+        changed
+    }
+
+    fn max_scroll_back(&self) -> usize {
+        self.lines.len().saturating_sub(1)
+    }
+}
+
+/// Command palette state (`:` mode)
+#[derive(Debug, Default)]
+pub struct CommandPaletteState {
+    /// Filter typed after `:`
+    pub query: String,
+    /// Commands for the selected tree item, most relevant first
+    pub commands: Vec<KataCommand>,
+    /// Indices into `commands` matching the query, best match first
+    pub matches: Vec<usize>,
+    /// Position in `matches` of the highlighted command
+    pub selected: usize,
+}
+
+impl CommandPaletteState {
+    /// The highlighted command, if any match the query
+    pub fn selected_command(&self) -> Option<KataCommand> {
+        self.matches
+            .get(self.selected)
+            .and_then(|&idx| self.commands.get(idx))
+            .copied()
+    }
+}
+
+/// Key decisions table state (`D` view)
+#[derive(Debug, Default)]
+pub struct DecisionsViewState {
+    /// Fuzzy filter typed into the view
+    pub query: String,
+    /// Column rows are sorted by
+    pub sort: DecisionColumn,
+    /// Whether the sort is reversed
+    pub descending: bool,
+    /// Indices into `decisions::all_decisions` matching the query, in sort order
+    pub rows: Vec<usize>,
+    /// Position in `rows` of the highlighted decision
+    pub selected: usize,
+    /// Whether the highlighted decision is shown in full in a popup
+    pub detail_open: bool,
+}
+
+/// Workspace project switcher state (`p` list)
+#[derive(Debug, Default)]
+pub struct ProjectListState {
+    /// Summaries of the workspace's projects (filled by the app when the list opens)
+    pub projects: Vec<ProjectSummary>,
+    /// Index of the project being displayed
+    pub active: usize,
+    /// Index of the highlighted project
+    pub selected: usize,
+}
+
+/// Dependency graph view state (`G`)
+#[derive(Debug, Default)]
+pub struct GraphViewState {
+    /// Whether phases or plans are shown
+    pub kind: GraphKind,
+    /// Phase graph (built by the app when the view opens or data reloads)
+    pub phases: DependencyGraph,
+    /// Plan graph (built alongside the phase graph)
+    pub plans: DependencyGraph,
+    /// Highlighted node (index into the shown graph's nodes)
+    pub selected: usize,
+}
+
+impl GraphViewState {
+    /// The graph being shown
+    pub fn graph(&self) -> &DependencyGraph {
+        match self.kind {
+            GraphKind::Phases => &self.phases,
+            GraphKind::Plans => &self.plans,
+        }
+    }
+}
+
+/// Wave view state (`w`)
+#[derive(Debug, Default)]
+pub struct WaveViewState {
+    /// Phases that have plans (filled by the app when the view opens or data reloads)
+    pub phases: Vec<PhaseId>,
+    /// Index of the phase being shown
+    pub selected: usize,
+}
+
+impl WaveViewState {
+    /// The phase being shown
+    pub fn phase(&self) -> Option<PhaseId> {
+        self.phases.get(self.selected).copied()
+    }
 }
 
 /// Application state - the single source of truth (TEA Model)
@@ -41,7 +221,16 @@ pub struct AppState {
     pub detail_scroll: u16,
 
     /// Which phase numbers are currently expanded (showing requirements)
-    pub expanded_phases: HashSet<u8>,
+    pub expanded_phases: HashSet<PhaseId>,
+
+    /// Which plans (by ID) are expanded, showing tasks
+    pub expanded_plans: HashSet<String>,
+
+    /// Which milestones (by version) are collapsed, hiding their phases
+    pub collapsed_milestones: HashSet<String>,
+
+    /// Whether the Research node is expanded, showing `research/` documents
+    pub research_expanded: bool,
 
     /// Current search query (empty when not searching)
     pub search_query: String,
@@ -51,6 +240,45 @@ pub struct AppState {
 
     /// Current match index (for cycling through matches)
     pub current_match: usize,
+
+    /// Output of the most recent Kata command
+    pub command_output: CommandOutputState,
+
+    /// Command palette (active in `InputMode::Command`)
+    pub palette: CommandPaletteState,
+
+    /// Scroll offset for the diagnostics panel
+    pub diagnostics_scroll: u16,
+
+    /// Furthest the diagnostics panel can scroll, recorded each time it renders
+    pub diagnostics_max_scroll: u16,
+
+    /// Key decisions view (active in `InputMode::Decisions`)
+    pub decisions: DecisionsViewState,
+
+    /// Highlighted row of the settings view (index into `SETTINGS`)
+    pub settings_selected: usize,
+
+    /// Workspace project switcher (active in `InputMode::Projects`)
+    pub project_list: ProjectListState,
+
+    /// Scroll offset for the git activity panel
+    pub activity_scroll: u16,
+
+    /// Furthest the git activity panel can scroll, recorded each time it renders
+    pub activity_max_scroll: u16,
+
+    /// Progress over time (filled by the app when the progress view opens)
+    pub burnup: Burnup,
+
+    /// Dependency graph view (active in `InputMode::Graph`)
+    pub graph: GraphViewState,
+
+    /// Wave view (active in `InputMode::Waves`)
+    pub waves: WaveViewState,
+
+    /// One-off feedback shown in the status bar until the next key press
+    pub notice: Option<String>,
 }
 
 impl Default for AppState {
@@ -66,9 +294,25 @@ impl Default for AppState {
             selected_index: 0,
             detail_scroll: 0,
             expanded_phases: HashSet::new(), // All collapsed initially
+            expanded_plans: HashSet::new(),
+            collapsed_milestones: HashSet::new(),
+            research_expanded: false,
             search_query: String::new(),
             search_matches: Vec::new(),
             current_match: 0,
+            command_output: CommandOutputState::default(),
+            palette: CommandPaletteState::default(),
+            diagnostics_scroll: 0,
+            diagnostics_max_scroll: 0,
+            decisions: DecisionsViewState::default(),
+            settings_selected: 0,
+            project_list: ProjectListState::default(),
+            activity_scroll: 0,
+            activity_max_scroll: 0,
+            burnup: Burnup::default(),
+            graph: GraphViewState::default(),
+            waves: WaveViewState::default(),
+            notice: None,
         }
     }
 }
@@ -79,7 +323,7 @@ impl AppState {
     }
 
     /// Toggle expansion state for a phase
-    pub fn toggle_expansion(&mut self, phase_num: u8) {
+    pub fn toggle_expansion(&mut self, phase_num: PhaseId) {
         if self.expanded_phases.contains(&phase_num) {
             self.expanded_phases.remove(&phase_num);
         } else {
@@ -88,13 +332,37 @@ impl AppState {
     }
 
     /// Check if a phase is expanded
-    pub fn is_expanded(&self, phase_num: u8) -> bool {
+    pub fn is_expanded(&self, phase_num: PhaseId) -> bool {
         self.expanded_phases.contains(&phase_num)
     }
+
+    /// Toggle expansion state for a plan
+    pub fn toggle_plan(&mut self, id: &str) {
+        if !self.expanded_plans.remove(id) {
+            self.expanded_plans.insert(id.to_string());
+        }
+    }
+
+    /// Check if a plan is expanded
+    pub fn is_plan_expanded(&self, id: &str) -> bool {
+        self.expanded_plans.contains(id)
+    }
+
+    /// Toggle collapse state for a milestone
+    pub fn toggle_milestone(&mut self, version: &str) {
+        if !self.collapsed_milestones.remove(version) {
+            self.collapsed_milestones.insert(version.to_string());
+        }
+    }
+
+    /// Check if a milestone is collapsed
+    pub fn is_milestone_collapsed(&self, version: &str) -> bool {
+        self.collapsed_milestones.contains(version)
+    }
 }
 
 /// Messages that can trigger state changes (TEA Message)
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Message {
     /// Navigate up in the current view
     NavigateUp,
@@ -113,7 +381,65 @@ pub enum Message {
     /// Scroll detail pane down
     ScrollDown,
     /// Toggle expand/collapse of a phase
-    ToggleExpand(u8),
+    ToggleExpand(PhaseId),
+    /// Toggle expand/collapse of a plan's tasks (by plan ID)
+    TogglePlan(String),
+    /// Toggle collapse of a milestone (by version)
+    ToggleMilestone(String),
+    /// Toggle expand/collapse of the Research node
+    ToggleResearch,
+    /// Show the lint diagnostics panel
+    ShowDiagnostics,
+    /// Hide the lint diagnostics panel
+    HideDiagnostics,
+    /// Show the key decisions table
+    ShowDecisions,
+    /// Close the decision popup, or the decisions table if no popup is open
+    HideDecisions,
+    /// Character input for the decisions filter
+    DecisionInput(char),
+    /// Backspace in the decisions filter
+    DecisionBackspace,
+    /// Highlight the next decision
+    NextDecision,
+    /// Highlight the previous decision
+    PrevDecision,
+    /// Sort decisions by the next column
+    SortDecisions,
+    /// Reverse the decisions sort order
+    ReverseDecisionSort,
+    /// Show or hide the highlighted decision in full
+    ToggleDecisionDetail,
+    /// Show the config.json settings view
+    ShowSettings,
+    /// Hide the settings view
+    HideSettings,
+    /// Flip or cycle the highlighted setting and write config.json
+    ChangeSetting,
+    /// Show the workspace project list
+    ShowProjects,
+    /// Hide the workspace project list
+    HideProjects,
+    /// Display the highlighted workspace project
+    SwitchProject,
+    /// Show recent git commits for the project
+    ShowActivity,
+    /// Hide the git activity panel
+    HideActivity,
+    /// Show the progress-over-time chart
+    ShowProgress,
+    /// Hide the progress chart
+    HideProgress,
+    /// Show the dependency graph
+    ShowGraph,
+    /// Hide the dependency graph
+    HideGraph,
+    /// Switch the dependency graph between phases and plans
+    ToggleGraphKind,
+    /// Show the selected phase's plans grouped by wave
+    ShowWaves,
+    /// Hide the wave view
+    HideWaves,
     /// Show help overlay
     ShowHelp,
     /// Hide help overlay
@@ -132,6 +458,32 @@ pub enum Message {
     NextMatch,
     /// Navigate to previous match
     PrevMatch,
+    /// Run the suggested Kata command for the selected item in the background
+    RunCommand,
+    /// Open the command palette
+    EnterCommandMode,
+    /// Close the command palette without running anything
+    ExitCommandMode,
+    /// Character input for the palette filter
+    CommandInput(char),
+    /// Backspace in the palette filter
+    CommandBackspace,
+    /// Highlight the next palette command
+    NextCommand,
+    /// Highlight the previous palette command
+    PrevCommand,
+    /// Run the highlighted palette command
+    ConfirmCommand,
+    /// Copy the highlighted palette command to the clipboard
+    CopyCommand,
+    /// Cancel the running command (Ctrl-C)
+    CancelCommand,
+    /// Show or hide the command output pane
+    ToggleOutput,
+    /// Open the selected item's source file in `$EDITOR`
+    OpenInEditor,
+    /// Open the selected task's `<files>` in `$EDITOR`
+    OpenTaskFiles,
     /// Quit the application
     Quit,
     /// Tick event for periodic updates (future use)
@@ -145,33 +497,86 @@ mod tests {
     #[test]
     fn test_toggle_expand_adds_to_set() {
         let mut state = AppState::new();
-        assert!(!state.is_expanded(1));
+        assert!(!state.is_expanded(PhaseId::new(1)));
 
-        state.toggle_expansion(1);
+        state.toggle_expansion(PhaseId::new(1));
 
-        assert!(state.is_expanded(1));
+        assert!(state.is_expanded(PhaseId::new(1)));
     }
 
     #[test]
     fn test_toggle_expand_removes_from_set() {
         let mut state = AppState::new();
-        state.toggle_expansion(1); // Add
-        assert!(state.is_expanded(1));
+        state.toggle_expansion(PhaseId::new(1)); // Add
+        assert!(state.is_expanded(PhaseId::new(1)));
 
-        state.toggle_expansion(1); // Remove
+        state.toggle_expansion(PhaseId::new(1)); // Remove
 
-        assert!(!state.is_expanded(1));
+        assert!(!state.is_expanded(PhaseId::new(1)));
     }
 
     #[test]
     fn test_multiple_phases_can_be_expanded() {
         let mut state = AppState::new();
 
-        state.toggle_expansion(1);
-        state.toggle_expansion(2);
+        state.toggle_expansion(PhaseId::new(1));
+        state.toggle_expansion(PhaseId::new(2));
+
+        assert!(state.is_expanded(PhaseId::new(1)));
+        assert!(state.is_expanded(PhaseId::new(2)));
+        assert!(!state.is_expanded(PhaseId::new(3)));
+    }
+
+    fn line(text: &str) -> CommandOutput {
+        CommandOutput::Line {
+            stream: OutputStream::Stdout,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_command_output_start_resets_previous_run() {
+        let mut output = CommandOutputState::default();
+        output.start("kata one".to_string());
+        output.apply(line("old"));
+        output.apply(CommandOutput::Finished(CommandStatus::Exited(Some(1))));
+        assert!(!output.running);
+
+        output.start("kata two".to_string());
+
+        assert!(output.visible);
+        assert!(output.running);
+        assert!(output.lines.is_empty());
+        assert_eq!(output.status, None);
+        assert_eq!(output.command, "kata two");
+    }
+
+    #[test]
+    fn test_command_output_caps_scrollback() {
+        let mut output = CommandOutputState::default();
+        for i in 0..MAX_OUTPUT_LINES + 10 {
+            output.apply(line(&i.to_string()));
+        }
+
+        assert_eq!(output.lines.len(), MAX_OUTPUT_LINES);
+        assert_eq!(output.lines.front().unwrap().1, "10");
+    }
+
+    #[test]
+    fn test_command_output_scroll_stays_anchored() {
+        let mut output = CommandOutputState::default();
+        for i in 0..5 {
+            output.apply(line(&i.to_string()));
+        }
+
+        assert!(output.scroll_up(2));
+        output.apply(line("new"));
+        assert_eq!(output.scroll_back, 3);
 
-        assert!(state.is_expanded(1));
-        assert!(state.is_expanded(2));
-        assert!(!state.is_expanded(3));
+        assert!(output.scroll_up(100));
+        assert_eq!(output.scroll_back, 5);
+        assert!(output.scroll_down(100));
+        assert_eq!(output.scroll_back, 0);
+        assert!(!output.scroll_down(1));
     }
 }
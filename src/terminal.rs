@@ -1,4 +1,6 @@
-use std::io::{self, Stdout};
+use std::io::{self, Stdout, Write};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
 
 use color_eyre::Result;
 use crossterm::{
@@ -21,6 +23,22 @@ fn init_panic_hook() {
     }));
 }
 
+/// OSC 52 escape sequence asking the terminal to put `text` on the clipboard
+pub fn osc52_sequence(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", STANDARD.encode(text))
+}
+
+/// Copy text to the system clipboard via the terminal (OSC 52)
+///
+/// Works over SSH and inside tmux (with `set-clipboard on`) since the terminal
+/// emulator, not this process, owns the clipboard. Terminals without OSC 52
+/// support silently ignore the sequence.
+pub fn copy_to_clipboard(text: &str) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    stdout.write_all(osc52_sequence(text).as_bytes())?;
+    stdout.flush()
+}
+
 /// Terminal wrapper with RAII cleanup
 ///
 /// Manages terminal state (raw mode, alternate screen) and ensures
@@ -61,6 +79,23 @@ impl Terminal {
         Ok(())
     }
 
+    /// Hand the terminal back to the shell (leave raw mode and the alternate screen)
+    ///
+    /// Used while a child process such as `$EDITOR` needs the terminal.
+    pub fn suspend(&mut self) -> Result<()> {
+        disable_raw_mode()?;
+        execute!(io::stdout(), LeaveAlternateScreen)?;
+        Ok(())
+    }
+
+    /// Take the terminal back after `suspend` and force a full redraw
+    pub fn resume(&mut self) -> Result<()> {
+        enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen)?;
+        self.inner.clear()?;
+        Ok(())
+    }
+
     /// Get current terminal size
     pub fn size(&self) -> Result<ratatui::layout::Size> {
         Ok(self.inner.size()?)
@@ -75,3 +110,16 @@ impl Drop for Terminal {
         let _ = execute!(io::stdout(), LeaveAlternateScreen);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_osc52_sequence_encodes_text() {
+        assert_eq!(
+            osc52_sequence("/kata:plan-phase 3"),
+            "\x1b]52;c;L2thdGE6cGxhbi1waGFzZSAz\x07"
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use crate::data::SETTINGS;
+use crate::graph::{GraphKind, Step};
 use crate::state::{AppState, FocusedPane, InputMode, Message};
 
 /// TEA Update function - handles all state transitions
@@ -11,6 +13,109 @@ pub fn update(state: &mut AppState, message: Message, tree_len: usize) -> bool {
             true
         }
 
+        Message::NavigateUp if state.input_mode == InputMode::Diagnostics => {
+            if state.diagnostics_scroll > 0 {
+                state.diagnostics_scroll -= 1;
+                true
+            } else {
+                false
+            }
+        }
+
+        Message::NavigateDown if state.input_mode == InputMode::Diagnostics => {
+            if state.diagnostics_scroll < state.diagnostics_max_scroll {
+                state.diagnostics_scroll += 1;
+                true
+            } else {
+                false
+            }
+        }
+
+        Message::NavigateUp if state.input_mode == InputMode::Activity => {
+            if state.activity_scroll > 0 {
+                state.activity_scroll -= 1;
+                true
+            } else {
+                false
+            }
+        }
+
+        Message::NavigateDown if state.input_mode == InputMode::Activity => {
+            if state.activity_scroll < state.activity_max_scroll {
+                state.activity_scroll += 1;
+                true
+            } else {
+                false
+            }
+        }
+
+        Message::NavigateUp if state.input_mode == InputMode::Graph => step_graph(state, Step::Up),
+        Message::NavigateDown if state.input_mode == InputMode::Graph => {
+            step_graph(state, Step::Down)
+        }
+        Message::NavigateLeft if state.input_mode == InputMode::Graph => {
+            step_graph(state, Step::Left)
+        }
+        Message::NavigateRight if state.input_mode == InputMode::Graph => {
+            step_graph(state, Step::Right)
+        }
+
+        Message::NavigateLeft if state.input_mode == InputMode::Waves => {
+            if state.waves.selected > 0 {
+                state.waves.selected -= 1;
+                true
+            } else {
+                false
+            }
+        }
+
+        Message::NavigateRight if state.input_mode == InputMode::Waves => {
+            if state.waves.selected + 1 < state.waves.phases.len() {
+                state.waves.selected += 1;
+                true
+            } else {
+                false
+            }
+        }
+
+        Message::NavigateUp if state.input_mode == InputMode::Settings => {
+            if state.settings_selected > 0 {
+                state.settings_selected -= 1;
+                true
+            } else {
+                false
+            }
+        }
+
+        Message::NavigateDown if state.input_mode == InputMode::Settings => {
+            if state.settings_selected + 1 < SETTINGS.len() {
+                state.settings_selected += 1;
+                true
+            } else {
+                false
+            }
+        }
+
+        Message::NavigateUp if state.input_mode == InputMode::Projects => {
+            let list = &mut state.project_list;
+            if list.selected > 0 {
+                list.selected -= 1;
+                true
+            } else {
+                false
+            }
+        }
+
+        Message::NavigateDown if state.input_mode == InputMode::Projects => {
+            let list = &mut state.project_list;
+            if list.selected + 1 < list.projects.len() {
+                list.selected += 1;
+                true
+            } else {
+                false
+            }
+        }
+
         Message::NavigateUp => match state.focused_pane {
             FocusedPane::Tree => {
                 let current = state.tree_state.selected().unwrap_or(0);
@@ -30,6 +135,7 @@ pub fn update(state: &mut AppState, message: Message, tree_len: usize) -> bool {
                     false
                 }
             }
+            FocusedPane::Output => state.command_output.scroll_up(1),
         },
 
         Message::NavigateDown => match state.focused_pane {
@@ -47,10 +153,11 @@ pub fn update(state: &mut AppState, message: Message, tree_len: usize) -> bool {
                 state.detail_scroll = state.detail_scroll.saturating_add(1);
                 true
             }
+            FocusedPane::Output => state.command_output.scroll_down(1),
         },
 
         Message::NavigateLeft => {
-            if state.focused_pane == FocusedPane::Detail {
+            if state.focused_pane != FocusedPane::Tree {
                 state.focused_pane = FocusedPane::Tree;
                 true
             } else {
@@ -81,11 +188,20 @@ pub fn update(state: &mut AppState, message: Message, tree_len: usize) -> bool {
         Message::SwitchPane => {
             state.focused_pane = match state.focused_pane {
                 FocusedPane::Tree => FocusedPane::Detail,
-                FocusedPane::Detail => FocusedPane::Tree,
+                FocusedPane::Detail if state.command_output.visible => FocusedPane::Output,
+                FocusedPane::Detail | FocusedPane::Output => FocusedPane::Tree,
             };
             true
         }
 
+        Message::ScrollUp if state.focused_pane == FocusedPane::Output => {
+            state.command_output.scroll_up(3)
+        }
+
+        Message::ScrollDown if state.focused_pane == FocusedPane::Output => {
+            state.command_output.scroll_down(3)
+        }
+
         Message::ScrollUp => {
             if state.detail_scroll > 0 {
                 state.detail_scroll = state.detail_scroll.saturating_sub(3);
@@ -105,6 +221,262 @@ pub fn update(state: &mut AppState, message: Message, tree_len: usize) -> bool {
             true
         }
 
+        Message::TogglePlan(id) => {
+            state.toggle_plan(&id);
+            true
+        }
+
+        Message::ToggleMilestone(version) => {
+            state.toggle_milestone(&version);
+            true
+        }
+
+        Message::ToggleResearch => {
+            state.research_expanded = !state.research_expanded;
+            true
+        }
+
+        Message::RunCommand | Message::CancelCommand => {
+            // Process control handled by app layer (owns the CommandRunner)
+            false
+        }
+
+        Message::OpenInEditor | Message::OpenTaskFiles => {
+            // Suspending the terminal for $EDITOR happens in the app layer
+            false
+        }
+
+        Message::EnterCommandMode => {
+            state.input_mode = InputMode::Command;
+            state.palette.query.clear();
+            state.palette.selected = 0;
+            // Candidate commands computed in app layer (needs selected item and data)
+            true
+        }
+
+        Message::ExitCommandMode => {
+            state.input_mode = InputMode::Normal;
+            true
+        }
+
+        Message::CommandInput(c) => {
+            state.palette.query.push(c);
+            state.palette.selected = 0;
+            true
+        }
+
+        Message::CommandBackspace => {
+            state.palette.query.pop();
+            state.palette.selected = 0;
+            true
+        }
+
+        Message::NextCommand => {
+            let len = state.palette.matches.len();
+            if len > 0 {
+                state.palette.selected = (state.palette.selected + 1) % len;
+            }
+            true
+        }
+
+        Message::PrevCommand => {
+            let len = state.palette.matches.len();
+            if len > 0 {
+                state.palette.selected = (state.palette.selected + len - 1) % len;
+            }
+            true
+        }
+
+        Message::ConfirmCommand | Message::CopyCommand => {
+            // Running / copying the highlighted command happens in app layer first
+            state.input_mode = InputMode::Normal;
+            true
+        }
+
+        Message::ToggleOutput => {
+            state.command_output.visible = !state.command_output.visible;
+            if !state.command_output.visible && state.focused_pane == FocusedPane::Output {
+                state.focused_pane = FocusedPane::Tree;
+            }
+            true
+        }
+
+        Message::ShowDiagnostics => {
+            state.input_mode = InputMode::Diagnostics;
+            state.diagnostics_scroll = 0;
+            true
+        }
+
+        Message::HideDiagnostics => {
+            state.input_mode = InputMode::Normal;
+            true
+        }
+
+        Message::ShowSettings => {
+            state.input_mode = InputMode::Settings;
+            state.settings_selected = 0;
+            true
+        }
+
+        Message::HideSettings => {
+            state.input_mode = InputMode::Normal;
+            true
+        }
+
+        // config.json is written in app layer (side effect); the reload redraws
+        Message::ChangeSetting => false,
+
+        Message::ShowProjects => {
+            state.input_mode = InputMode::Projects;
+            // Summaries are refreshed in app layer (needs the workspace)
+            state.project_list.selected = state.project_list.active;
+            true
+        }
+
+        Message::HideProjects => {
+            state.input_mode = InputMode::Normal;
+            true
+        }
+
+        // Project data is loaded in app layer; the list closes either way
+        Message::SwitchProject => {
+            state.input_mode = InputMode::Normal;
+            true
+        }
+
+        Message::ShowActivity => {
+            state.input_mode = InputMode::Activity;
+            // Commits are read from git in app layer
+            state.activity_scroll = 0;
+            true
+        }
+
+        Message::HideActivity => {
+            state.input_mode = InputMode::Normal;
+            true
+        }
+
+        Message::ShowProgress => {
+            // History is read from git in app layer
+            state.input_mode = InputMode::Progress;
+            true
+        }
+
+        Message::HideProgress => {
+            state.input_mode = InputMode::Normal;
+            true
+        }
+
+        Message::ShowGraph => {
+            // Graphs are built and the tree selection located in app layer
+            state.input_mode = InputMode::Graph;
+            true
+        }
+
+        Message::HideGraph => {
+            state.input_mode = InputMode::Normal;
+            true
+        }
+
+        Message::ToggleGraphKind => {
+            let graph = &mut state.graph;
+            let phase = graph.graph().nodes.get(graph.selected).map(|n| n.phase);
+            graph.kind = match graph.kind {
+                GraphKind::Phases => GraphKind::Plans,
+                GraphKind::Plans => GraphKind::Phases,
+            };
+            // Stay on the same phase, or its first plan
+            graph.selected = graph
+                .graph()
+                .nodes
+                .iter()
+                .position(|n| Some(n.phase) == phase)
+                .unwrap_or(0);
+            true
+        }
+
+        Message::ShowWaves => {
+            // Phases are listed and the tree selection located in app layer
+            state.input_mode = InputMode::Waves;
+            true
+        }
+
+        Message::HideWaves => {
+            state.input_mode = InputMode::Normal;
+            true
+        }
+
+        Message::ShowDecisions => {
+            state.input_mode = InputMode::Decisions;
+            state.decisions.query.clear();
+            state.decisions.selected = 0;
+            state.decisions.detail_open = false;
+            // Rows filtered and sorted in app layer (needs planning data)
+            true
+        }
+
+        Message::HideDecisions => {
+            if state.decisions.detail_open {
+                state.decisions.detail_open = false;
+            } else {
+                state.input_mode = InputMode::Normal;
+            }
+            true
+        }
+
+        Message::DecisionInput(_) | Message::DecisionBackspace if state.decisions.detail_open => {
+            false
+        }
+
+        Message::DecisionInput(c) => {
+            state.decisions.query.push(c);
+            state.decisions.selected = 0;
+            true
+        }
+
+        Message::DecisionBackspace => {
+            state.decisions.query.pop();
+            state.decisions.selected = 0;
+            true
+        }
+
+        Message::NextDecision => {
+            if state.decisions.selected + 1 < state.decisions.rows.len() {
+                state.decisions.selected += 1;
+                true
+            } else {
+                false
+            }
+        }
+
+        Message::PrevDecision => {
+            if state.decisions.selected > 0 {
+                state.decisions.selected -= 1;
+                true
+            } else {
+                false
+            }
+        }
+
+        Message::SortDecisions => {
+            state.decisions.sort = state.decisions.sort.next();
+            state.decisions.descending = false;
+            true
+        }
+
+        Message::ReverseDecisionSort => {
+            state.decisions.descending = !state.decisions.descending;
+            true
+        }
+
+        Message::ToggleDecisionDetail => {
+            if state.decisions.rows.is_empty() {
+                return false;
+            }
+            state.decisions.detail_open = !state.decisions.detail_open;
+            true
+        }
+
         Message::ShowHelp => {
             state.input_mode = InputMode::Help;
             true
@@ -176,18 +548,35 @@ pub fn update(state: &mut AppState, message: Message, tree_len: usize) -> bool {
         }
 
         Message::Tick => {
-            // TODO(Phase 3): Periodic data refresh from .planning/ files
+            // Data refresh is driven by the file watcher (Event::DataChanged), not ticks
             false
         }
     }
 }
 
+/// Move the graph selection one step; false at the edge of the graph
+fn step_graph(state: &mut AppState, step: Step) -> bool {
+    let graph = &mut state.graph;
+    match graph.graph().step(graph.selected, step) {
+        Some(node) => {
+            graph.selected = node;
+            true
+        }
+        None => false,
+    }
+}
+
 /// Convert keyboard event to Message based on current input mode
 pub fn key_to_message(key: crossterm::event::KeyEvent, input_mode: InputMode) -> Option<Message> {
-    use crossterm::event::KeyCode;
+    use crossterm::event::{KeyCode, KeyModifiers};
 
     match input_mode {
         InputMode::Normal => match key.code {
+            // Cancel running command (checked before plain character bindings)
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                Some(Message::CancelCommand)
+            }
+
             // Quit
             KeyCode::Char('q') | KeyCode::Esc => Some(Message::Quit),
 
@@ -197,6 +586,39 @@ pub fn key_to_message(key: crossterm::event::KeyEvent, input_mode: InputMode) ->
             // Search
             KeyCode::Char('/') => Some(Message::EnterSearchMode),
 
+            // Command palette
+            KeyCode::Char(':') => Some(Message::EnterCommandMode),
+
+            // Lint diagnostics
+            KeyCode::Char('d') => Some(Message::ShowDiagnostics),
+
+            // Key decisions table
+            KeyCode::Char('D') => Some(Message::ShowDecisions),
+
+            // config.json settings
+            KeyCode::Char('s') => Some(Message::ShowSettings),
+
+            // Workspace project switcher
+            KeyCode::Char('p') => Some(Message::ShowProjects),
+
+            // Git activity
+            KeyCode::Char('a') => Some(Message::ShowActivity),
+
+            // Progress over time
+            KeyCode::Char('P') => Some(Message::ShowProgress),
+
+            // Dependency graph
+            KeyCode::Char('G') => Some(Message::ShowGraph),
+
+            // Plans of the phase by wave
+            KeyCode::Char('w') => Some(Message::ShowWaves),
+
+            // Open the selection's source in $EDITOR
+            KeyCode::Char('e') => Some(Message::OpenInEditor),
+
+            // Open the selected task's files in $EDITOR
+            KeyCode::Char('f') => Some(Message::OpenTaskFiles),
+
             // Navigation - vim style
             KeyCode::Char('j') | KeyCode::Down => Some(Message::NavigateDown),
             KeyCode::Char('k') | KeyCode::Up => Some(Message::NavigateUp),
@@ -211,12 +633,74 @@ pub fn key_to_message(key: crossterm::event::KeyEvent, input_mode: InputMode) ->
             KeyCode::PageUp => Some(Message::ScrollUp),
             KeyCode::PageDown => Some(Message::ScrollDown),
 
+            // Commands
+            KeyCode::Char('x') => Some(Message::RunCommand),
+            KeyCode::Char('o') => Some(Message::ToggleOutput),
+
             _ => None,
         },
         InputMode::Help => match key.code {
             KeyCode::Esc | KeyCode::Char('?') | KeyCode::Char('q') => Some(Message::HideHelp),
             _ => None, // Ignore other keys in help mode
         },
+        InputMode::Diagnostics => match key.code {
+            KeyCode::Esc | KeyCode::Char('d') | KeyCode::Char('q') => {
+                Some(Message::HideDiagnostics)
+            }
+            KeyCode::Char('j') | KeyCode::Down => Some(Message::NavigateDown),
+            KeyCode::Char('k') | KeyCode::Up => Some(Message::NavigateUp),
+            _ => None,
+        },
+        InputMode::Settings => match key.code {
+            KeyCode::Esc | KeyCode::Char('s') | KeyCode::Char('q') => Some(Message::HideSettings),
+            KeyCode::Char('j') | KeyCode::Down => Some(Message::NavigateDown),
+            KeyCode::Char('k') | KeyCode::Up => Some(Message::NavigateUp),
+            KeyCode::Enter | KeyCode::Char(' ') => Some(Message::ChangeSetting),
+            _ => None,
+        },
+        InputMode::Projects => match key.code {
+            KeyCode::Esc | KeyCode::Char('p') | KeyCode::Char('q') => Some(Message::HideProjects),
+            KeyCode::Char('j') | KeyCode::Down => Some(Message::NavigateDown),
+            KeyCode::Char('k') | KeyCode::Up => Some(Message::NavigateUp),
+            KeyCode::Enter => Some(Message::SwitchProject),
+            _ => None,
+        },
+        InputMode::Activity => match key.code {
+            KeyCode::Esc | KeyCode::Char('a') | KeyCode::Char('q') => Some(Message::HideActivity),
+            KeyCode::Char('j') | KeyCode::Down => Some(Message::NavigateDown),
+            KeyCode::Char('k') | KeyCode::Up => Some(Message::NavigateUp),
+            _ => None,
+        },
+        InputMode::Progress => match key.code {
+            KeyCode::Esc | KeyCode::Char('P') | KeyCode::Char('q') => Some(Message::HideProgress),
+            _ => None,
+        },
+        InputMode::Graph => match key.code {
+            KeyCode::Esc | KeyCode::Char('G') | KeyCode::Char('q') => Some(Message::HideGraph),
+            KeyCode::Char('j') | KeyCode::Down => Some(Message::NavigateDown),
+            KeyCode::Char('k') | KeyCode::Up => Some(Message::NavigateUp),
+            KeyCode::Char('h') | KeyCode::Left => Some(Message::NavigateLeft),
+            KeyCode::Char('l') | KeyCode::Right => Some(Message::NavigateRight),
+            KeyCode::Tab => Some(Message::ToggleGraphKind),
+            _ => None,
+        },
+        InputMode::Waves => match key.code {
+            KeyCode::Esc | KeyCode::Char('w') | KeyCode::Char('q') => Some(Message::HideWaves),
+            KeyCode::Char('h') | KeyCode::Left => Some(Message::NavigateLeft),
+            KeyCode::Char('l') | KeyCode::Right => Some(Message::NavigateRight),
+            _ => None,
+        },
+        InputMode::Decisions => match key.code {
+            KeyCode::Esc => Some(Message::HideDecisions),
+            KeyCode::Enter => Some(Message::ToggleDecisionDetail),
+            KeyCode::Backspace => Some(Message::DecisionBackspace),
+            KeyCode::Down => Some(Message::NextDecision),
+            KeyCode::Up => Some(Message::PrevDecision),
+            KeyCode::Tab => Some(Message::SortDecisions),
+            KeyCode::BackTab => Some(Message::ReverseDecisionSort),
+            KeyCode::Char(c) => Some(Message::DecisionInput(c)),
+            _ => None,
+        },
         InputMode::Search => match key.code {
             KeyCode::Esc => Some(Message::ExitSearchMode),
             KeyCode::Enter => Some(Message::ConfirmSearch),
@@ -226,6 +710,18 @@ pub fn key_to_message(key: crossterm::event::KeyEvent, input_mode: InputMode) ->
             KeyCode::Up | KeyCode::BackTab => Some(Message::PrevMatch),
             _ => None,
         },
+        InputMode::Command => match key.code {
+            KeyCode::Char('y') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                Some(Message::CopyCommand)
+            }
+            KeyCode::Esc => Some(Message::ExitCommandMode),
+            KeyCode::Enter => Some(Message::ConfirmCommand),
+            KeyCode::Backspace => Some(Message::CommandBackspace),
+            KeyCode::Char(c) => Some(Message::CommandInput(c)),
+            KeyCode::Down | KeyCode::Tab => Some(Message::NextCommand),
+            KeyCode::Up | KeyCode::BackTab => Some(Message::PrevCommand),
+            _ => None,
+        },
     }
 }
 
@@ -278,6 +774,164 @@ mod tests {
         assert_eq!(state.focused_pane, FocusedPane::Tree);
     }
 
+    #[test]
+    fn test_switch_pane_includes_visible_output() {
+        let mut state = AppState::new();
+        state.command_output.visible = true;
+
+        update(&mut state, Message::SwitchPane, TEST_TREE_LEN);
+        update(&mut state, Message::SwitchPane, TEST_TREE_LEN);
+        assert_eq!(state.focused_pane, FocusedPane::Output);
+
+        update(&mut state, Message::SwitchPane, TEST_TREE_LEN);
+        assert_eq!(state.focused_pane, FocusedPane::Tree);
+    }
+
+    #[test]
+    fn test_hiding_output_moves_focus_to_tree() {
+        let mut state = AppState::new();
+        state.command_output.visible = true;
+        state.focused_pane = FocusedPane::Output;
+
+        update(&mut state, Message::ToggleOutput, TEST_TREE_LEN);
+
+        assert!(!state.command_output.visible);
+        assert_eq!(state.focused_pane, FocusedPane::Tree);
+    }
+
+    #[test]
+    fn test_ctrl_c_cancels_command() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+
+        assert_eq!(
+            key_to_message(ctrl_c, InputMode::Normal),
+            Some(Message::CancelCommand)
+        );
+    }
+
+    #[test]
+    fn test_command_palette_selection_wraps() {
+        let mut state = AppState::new();
+        update(&mut state, Message::EnterCommandMode, TEST_TREE_LEN);
+        assert_eq!(state.input_mode, InputMode::Command);
+        state.palette.matches = vec![0, 1, 2];
+
+        update(&mut state, Message::PrevCommand, TEST_TREE_LEN);
+        assert_eq!(state.palette.selected, 2);
+        update(&mut state, Message::NextCommand, TEST_TREE_LEN);
+        assert_eq!(state.palette.selected, 0);
+
+        // Typing re-ranks, so the highlight returns to the best match
+        update(&mut state, Message::NextCommand, TEST_TREE_LEN);
+        update(&mut state, Message::CommandInput('v'), TEST_TREE_LEN);
+        assert_eq!(state.palette.selected, 0);
+        assert_eq!(state.palette.query, "v");
+
+        update(&mut state, Message::ConfirmCommand, TEST_TREE_LEN);
+        assert_eq!(state.input_mode, InputMode::Normal);
+    }
+
+    #[test]
+    fn test_settings_view_moves_within_settings() {
+        let mut state = AppState::new();
+        update(&mut state, Message::ShowSettings, TEST_TREE_LEN);
+
+        assert!(!update(&mut state, Message::NavigateUp, TEST_TREE_LEN));
+        for _ in 0..SETTINGS.len() + 2 {
+            update(&mut state, Message::NavigateDown, TEST_TREE_LEN);
+        }
+        assert_eq!(state.settings_selected, SETTINGS.len() - 1);
+        assert_eq!(state.tree_state.selected(), Some(0));
+
+        update(&mut state, Message::HideSettings, TEST_TREE_LEN);
+        assert_eq!(state.input_mode, InputMode::Normal);
+    }
+
+    #[test]
+    fn test_diagnostics_panel_scrolls_instead_of_tree() {
+        let mut state = AppState::new();
+        update(&mut state, Message::ShowDiagnostics, TEST_TREE_LEN);
+        state.diagnostics_max_scroll = 2;
+
+        update(&mut state, Message::NavigateDown, TEST_TREE_LEN);
+
+        assert_eq!(state.diagnostics_scroll, 1);
+        assert_eq!(state.tree_state.selected(), Some(0));
+
+        // Scrolling stops once the last line is in view
+        assert!(update(&mut state, Message::NavigateDown, TEST_TREE_LEN));
+        assert!(!update(&mut state, Message::NavigateDown, TEST_TREE_LEN));
+        assert_eq!(state.diagnostics_scroll, 2);
+        assert!(update(&mut state, Message::NavigateUp, TEST_TREE_LEN));
+        assert_eq!(state.diagnostics_scroll, 1);
+
+        update(&mut state, Message::HideDiagnostics, TEST_TREE_LEN);
+        assert_eq!(state.input_mode, InputMode::Normal);
+    }
+
+    #[test]
+    fn test_activity_panel_scrolls_instead_of_tree() {
+        let mut state = AppState::new();
+        state.activity_scroll = 4;
+        update(&mut state, Message::ShowActivity, TEST_TREE_LEN);
+        assert_eq!(state.activity_scroll, 0);
+        state.activity_max_scroll = 1;
+
+        update(&mut state, Message::NavigateDown, TEST_TREE_LEN);
+        assert_eq!(state.activity_scroll, 1);
+        assert_eq!(state.tree_state.selected(), Some(0));
+
+        // Scrolling stops once the last commit is in view
+        assert!(!update(&mut state, Message::NavigateDown, TEST_TREE_LEN));
+        assert_eq!(state.activity_scroll, 1);
+
+        update(&mut state, Message::HideActivity, TEST_TREE_LEN);
+        assert_eq!(state.input_mode, InputMode::Normal);
+    }
+
+    #[test]
+    fn test_wave_view_switches_phases() {
+        let mut state = AppState::new();
+        update(&mut state, Message::ShowWaves, TEST_TREE_LEN);
+        state.waves.phases = vec![crate::data::PhaseId::new(1), crate::data::PhaseId::new(2)];
+
+        assert!(!update(&mut state, Message::NavigateLeft, TEST_TREE_LEN));
+        assert!(update(&mut state, Message::NavigateRight, TEST_TREE_LEN));
+        assert!(!update(&mut state, Message::NavigateRight, TEST_TREE_LEN));
+        assert_eq!(state.waves.phase(), Some(crate::data::PhaseId::new(2)));
+        assert_eq!(state.tree_state.selected(), Some(0));
+
+        update(&mut state, Message::HideWaves, TEST_TREE_LEN);
+        assert_eq!(state.input_mode, InputMode::Normal);
+    }
+
+    #[test]
+    fn test_escape_closes_decision_popup_before_view() {
+        let mut state = AppState::new();
+        update(&mut state, Message::ShowDecisions, TEST_TREE_LEN);
+        state.decisions.rows = vec![1, 0];
+
+        update(&mut state, Message::NextDecision, TEST_TREE_LEN);
+        assert!(!update(&mut state, Message::NextDecision, TEST_TREE_LEN));
+        assert_eq!(state.decisions.selected, 1);
+
+        update(&mut state, Message::ToggleDecisionDetail, TEST_TREE_LEN);
+        assert!(!update(
+            &mut state,
+            Message::DecisionInput('x'),
+            TEST_TREE_LEN
+        ));
+
+        update(&mut state, Message::HideDecisions, TEST_TREE_LEN);
+        assert!(!state.decisions.detail_open);
+        assert_eq!(state.input_mode, InputMode::Decisions);
+
+        update(&mut state, Message::HideDecisions, TEST_TREE_LEN);
+        assert_eq!(state.input_mode, InputMode::Normal);
+    }
+
     #[test]
     fn test_navigate_down_respects_tree_bounds() {
         let mut state = AppState::new();
@@ -0,0 +1,406 @@
+use std::collections::BTreeSet;
+use std::path::{Component, Path, PathBuf};
+
+use clap::ValueEnum;
+use regex::Regex;
+use serde::Serialize;
+
+use crate::data::{
+    Artifact, KeyLink, MustHaveCheck, MustHaveKind, PhaseId, Plan, PlanVerification, PlanningData,
+};
+
+/// Output format for `kata-tui verify`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum VerifyFormat {
+    /// One block per plan with a line per check
+    #[default]
+    Text,
+    /// JSON array of plan results
+    Json,
+}
+
+/// A verified plan, for reporting
+#[derive(Debug, Clone, Serialize)]
+pub struct PlanReport {
+    pub plan: String,
+    pub phase: PhaseId,
+    pub passed: bool,
+    pub checks: Vec<MustHaveCheck>,
+}
+
+/// The repository a .planning/ directory belongs to (its parent)
+///
+/// A bare relative `.planning` belongs to the current directory.
+pub fn repository_root(planning_dir: &Path) -> &Path {
+    match planning_dir.parent() {
+        Some(parent) if parent.as_os_str().is_empty() => Path::new("."),
+        Some(parent) => parent,
+        None => planning_dir,
+    }
+}
+
+/// Check every plan's must_haves against the repository at `root`
+///
+/// Results are stored on each plan's `must_have_checks`; plans without artifacts or
+/// key links are left unverified.
+pub fn verify(root: &Path, data: &mut PlanningData) {
+    let roadmap = &mut data.roadmap;
+    let archived = roadmap
+        .milestones
+        .iter_mut()
+        .flat_map(|m| &mut m.archived_phases);
+    for phase in roadmap.phases.iter_mut().chain(archived) {
+        for plan in &mut phase.plans {
+            plan.must_have_checks = verify_plan(root, plan);
+        }
+    }
+}
+
+/// Check one plan's must_haves, or None if it declares none
+pub fn verify_plan(root: &Path, plan: &Plan) -> Option<PlanVerification> {
+    let must_haves = &plan.frontmatter.as_ref()?.must_haves;
+    if must_haves.artifacts.is_empty() && must_haves.key_links.is_empty() {
+        return None;
+    }
+
+    let checks = must_haves
+        .artifacts
+        .iter()
+        .map(|artifact| check_artifact(root, artifact))
+        .chain(
+            must_haves
+                .key_links
+                .iter()
+                .map(|link| check_key_link(root, link)),
+        )
+        .collect();
+    Some(PlanVerification { checks })
+}
+
+/// Verified plans in roadmap order, for the `verify` subcommand
+pub fn report(data: &PlanningData) -> Vec<PlanReport> {
+    let archived = data
+        .roadmap
+        .milestones
+        .iter()
+        .flat_map(|m| &m.archived_phases);
+    let mut phases: Vec<_> = data.roadmap.phases.iter().chain(archived).collect();
+    phases.sort_by_key(|phase| phase.number);
+
+    phases
+        .into_iter()
+        .flat_map(|phase| {
+            phase.plans.iter().filter_map(|plan| {
+                let verification = plan.must_have_checks.as_ref()?;
+                Some(PlanReport {
+                    plan: plan.id.clone(),
+                    phase: phase.number,
+                    passed: verification.passed(),
+                    checks: verification.checks.clone(),
+                })
+            })
+        })
+        .collect()
+}
+
+/// Files the must_haves checks read, so they can be watched for changes
+pub fn checked_files(root: &Path, data: &PlanningData) -> Vec<PathBuf> {
+    let archived = data
+        .roadmap
+        .milestones
+        .iter()
+        .flat_map(|m| &m.archived_phases);
+    let files: BTreeSet<PathBuf> = data
+        .roadmap
+        .phases
+        .iter()
+        .chain(archived)
+        .flat_map(|phase| &phase.plans)
+        .filter_map(|plan| plan.frontmatter.as_ref())
+        .flat_map(|fm| {
+            let artifacts = fm.must_haves.artifacts.iter().map(|a| a.path.as_str());
+            let links = fm.must_haves.key_links.iter().map(|l| l.from.as_str());
+            artifacts.chain(links)
+        })
+        .filter_map(|path| resolve(root, path))
+        .collect();
+    files.into_iter().collect()
+}
+
+/// `path` joined onto `root`, or None if it points outside the repository
+///
+/// Absolute paths and `..` that climb above `root` are rejected, and so are
+/// existing files that a symlink places outside it.
+fn resolve(root: &Path, path: &str) -> Option<PathBuf> {
+    let mut depth = 0usize;
+    for component in Path::new(path).components() {
+        match component {
+            Component::Normal(_) => depth += 1,
+            Component::CurDir => {}
+            Component::ParentDir => depth = depth.checked_sub(1)?,
+            Component::RootDir | Component::Prefix(_) => return None,
+        }
+    }
+
+    let joined = root.join(path);
+    if let (Ok(canonical), Ok(canonical_root)) = (joined.canonicalize(), root.canonicalize()) {
+        if !canonical.starts_with(canonical_root) {
+            return None;
+        }
+    }
+    Some(joined)
+}
+
+/// Read a must_haves file, with the failure to report if it cannot be read
+fn read_checked(root: &Path, path: &str) -> Result<String, String> {
+    let file = resolve(root, path).ok_or_else(|| "outside the repository".to_string())?;
+    std::fs::read_to_string(file).map_err(|e| read_failure(&e))
+}
+
+/// The file exists, contains the `contains` string and mentions every export
+fn check_artifact(root: &Path, artifact: &Artifact) -> MustHaveCheck {
+    let mut expectation = vec!["exists".to_string()];
+    if let Some(contains) = &artifact.contains {
+        expectation.push(format!("contains {:?}", contains));
+    }
+    if !artifact.exports.is_empty() {
+        expectation.push(format!("exports {}", artifact.exports.join(", ")));
+    }
+
+    let failure = match read_checked(root, &artifact.path) {
+        Err(failure) => Some(failure),
+        Ok(content) => match &artifact.contains {
+            Some(contains) if !content.contains(contains.as_str()) => {
+                Some(format!("does not contain {:?}", contains))
+            }
+            _ => artifact
+                .exports
+                .iter()
+                .find(|symbol| !content.contains(symbol.as_str()))
+                .map(|symbol| format!("does not mention export {}", symbol)),
+        },
+    };
+
+    MustHaveCheck {
+        kind: MustHaveKind::Artifact,
+        path: artifact.path.clone(),
+        expectation: expectation.join(", "),
+        failure,
+    }
+}
+
+/// The `from` file exists and, if a pattern is given, matches it
+fn check_key_link(root: &Path, link: &KeyLink) -> MustHaveCheck {
+    let mut expectation = format!("links to {}", link.to);
+    if let Some(pattern) = &link.pattern {
+        expectation.push_str(&format!(" (/{}/)", pattern));
+    }
+
+    let failure = match read_checked(root, &link.from) {
+        Err(failure) => Some(failure),
+        Ok(content) => match &link.pattern {
+            None => None,
+            Some(pattern) => match Regex::new(pattern) {
+                Err(e) => Some(format!("invalid pattern: {}", e)),
+                Ok(regex) if !regex.is_match(&content) => {
+                    Some(format!("pattern /{}/ not found", pattern))
+                }
+                Ok(_) => None,
+            },
+        },
+    };
+
+    MustHaveCheck {
+        kind: MustHaveKind::KeyLink,
+        path: link.from.clone(),
+        expectation,
+        failure,
+    }
+}
+
+fn read_failure(error: &std::io::Error) -> String {
+    if error.kind() == std::io::ErrorKind::NotFound {
+        "file not found".to_string()
+    } else {
+        format!("cannot read: {}", error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{MustHaves, Phase, PlanFrontmatter};
+    use tempfile::tempdir;
+
+    fn plan_with(must_haves: MustHaves) -> Plan {
+        Plan {
+            id: "02-01".to_string(),
+            frontmatter: Some(PlanFrontmatter {
+                must_haves,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn artifact(path: &str, contains: Option<&str>) -> Artifact {
+        Artifact {
+            path: path.to_string(),
+            contains: contains.map(String::from),
+            ..Default::default()
+        }
+    }
+
+    fn link(from: &str, pattern: &str) -> KeyLink {
+        KeyLink {
+            from: from.to_string(),
+            to: "InputMode".to_string(),
+            pattern: Some(pattern.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_artifacts_exist_and_contain() {
+        let dir = tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("src")).unwrap();
+        std::fs::write(dir.path().join("src/state.rs"), "pub enum InputMode {}\n").unwrap();
+
+        let plan = plan_with(MustHaves {
+            artifacts: vec![
+                artifact("src/state.rs", Some("enum InputMode")),
+                artifact("src/state.rs", Some("enum FocusedPane")),
+                artifact("src/missing.rs", None),
+            ],
+            ..Default::default()
+        });
+        let verification = verify_plan(dir.path(), &plan).unwrap();
+
+        let failures: Vec<Option<&str>> = verification
+            .checks
+            .iter()
+            .map(|check| check.failure.as_deref())
+            .collect();
+        assert_eq!(
+            failures,
+            vec![
+                None,
+                Some("does not contain \"enum FocusedPane\""),
+                Some("file not found")
+            ]
+        );
+        assert!(!verification.passed());
+        assert_eq!(verification.passed_count(), 1);
+    }
+
+    #[test]
+    fn test_key_link_patterns() {
+        let dir = tempdir().unwrap();
+        std::fs::write(dir.path().join("view.rs"), "match state.input_mode {}\n").unwrap();
+
+        let plan = plan_with(MustHaves {
+            key_links: vec![
+                link("view.rs", r"state\.input_mode"),
+                link("view.rs", r"state\.focused_pane"),
+                link("view.rs", r"state\.("),
+            ],
+            ..Default::default()
+        });
+        let verification = verify_plan(dir.path(), &plan).unwrap();
+
+        assert!(verification.checks[0].passed());
+        assert_eq!(
+            verification.checks[1].failure.as_deref(),
+            Some(r"pattern /state\.focused_pane/ not found")
+        );
+        assert!(verification.checks[2]
+            .failure
+            .as_deref()
+            .unwrap()
+            .starts_with("invalid pattern"));
+    }
+
+    #[test]
+    fn test_verify_stores_results_and_skips_plans_without_must_haves() {
+        let dir = tempdir().unwrap();
+        std::fs::write(dir.path().join("main.rs"), "fn main() {}\n").unwrap();
+        let mut data = PlanningData::default();
+        data.roadmap.phases.push(Phase {
+            number: PhaseId::new(2),
+            plans: vec![
+                plan_with(MustHaves {
+                    artifacts: vec![artifact("main.rs", Some("fn main"))],
+                    ..Default::default()
+                }),
+                Plan::default(),
+            ],
+            ..Default::default()
+        });
+
+        verify(dir.path(), &mut data);
+
+        let phase = &data.roadmap.phases[0];
+        assert!(phase.plans[1].must_have_checks.is_none());
+        assert_eq!(phase.must_haves_passed(), Some(true));
+        assert_eq!(report(&data).len(), 1);
+        assert_eq!(
+            checked_files(dir.path(), &data),
+            vec![dir.path().join("main.rs")]
+        );
+    }
+
+    #[test]
+    fn test_paths_outside_repository_fail_and_are_not_watched() {
+        let dir = tempdir().unwrap();
+        let root = dir.path().join("repo");
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::write(root.join("src/lib.rs"), "pub fn run() {}\n").unwrap();
+        std::fs::write(dir.path().join("secret.txt"), "pub fn run() {}\n").unwrap();
+        let secret = dir.path().join("secret.txt");
+
+        let plan = plan_with(MustHaves {
+            artifacts: vec![
+                artifact("src/../src/lib.rs", Some("fn run")),
+                artifact("../secret.txt", Some("fn run")),
+                artifact(secret.to_str().unwrap(), Some("fn run")),
+            ],
+            key_links: vec![link("src/../../secret.txt", "run")],
+            ..Default::default()
+        });
+        let verification = verify_plan(&root, &plan).unwrap();
+
+        let failures: Vec<Option<&str>> = verification
+            .checks
+            .iter()
+            .map(|check| check.failure.as_deref())
+            .collect();
+        assert_eq!(
+            failures,
+            vec![
+                None,
+                Some("outside the repository"),
+                Some("outside the repository"),
+                Some("outside the repository")
+            ]
+        );
+
+        let mut data = PlanningData::default();
+        data.roadmap.phases.push(Phase {
+            plans: vec![plan],
+            ..Default::default()
+        });
+        assert_eq!(
+            checked_files(&root, &data),
+            vec![root.join("src/../src/lib.rs")]
+        );
+    }
+
+    #[test]
+    fn test_repository_root_of_relative_planning_dir() {
+        assert_eq!(repository_root(Path::new(".planning")), Path::new("."));
+        assert_eq!(
+            repository_root(Path::new("/work/app/.planning")),
+            Path::new("/work/app")
+        );
+    }
+}
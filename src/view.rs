@@ -2,12 +2,21 @@ use std::collections::HashSet;
 
 use ratatui::Frame;
 
+use crate::activity::ActivityFeed;
+use crate::components::blockers_banner::banner_height;
 use crate::components::tree_view::{TreeItem, TreeView};
-use crate::components::{DetailPane, HelpOverlay, SearchInput, StatusBar};
-use crate::data::PlanningData;
+use crate::components::{
+    ActivityPanel, BlockersBanner, CommandPalette, DecisionsView, DependencyGraphView, DetailPane,
+    DiagnosticsPanel, HelpOverlay, OutputPane, ProgressChart, ProjectList, SearchInput,
+    SettingsView, StatusBar, WaveView,
+};
+use crate::data::{PhaseId, PlanningData};
+use crate::decisions::all_decisions;
 use crate::layout::{compute_layout, is_terminal_too_small};
+use crate::lint::Diagnostic;
 use crate::state::InputMode;
 use crate::state::{AppState, FocusedPane};
+use crate::waves::phase_waves;
 
 /// Render the entire UI
 ///
@@ -17,7 +26,9 @@ pub fn view(
     state: &mut AppState,
     data: &PlanningData,
     tree_items: &[TreeItem],
-    phases_with_children: &HashSet<u8>,
+    phases_with_children: &HashSet<PhaseId>,
+    diagnostics: &[Diagnostic],
+    activity: &ActivityFeed,
 ) {
     let area = frame.area();
 
@@ -27,7 +38,13 @@ pub fn view(
         return;
     }
 
-    let layout = compute_layout(area);
+    let blockers = &data.state.blockers;
+    let layout = compute_layout(area, state.command_output.visible, banner_height(blockers));
+
+    // Render blockers banner (top, only when STATE.md lists blockers)
+    if let Some(banner_area) = layout.banner {
+        frame.render_widget(BlockersBanner::new(blockers), banner_area);
+    }
 
     // Render tree view (left pane)
     let tree_focused = state.focused_pane == FocusedPane::Tree;
@@ -35,7 +52,10 @@ pub fn view(
         tree_items,
         tree_focused,
         &state.expanded_phases,
+        &state.expanded_plans,
         phases_with_children,
+        &state.collapsed_milestones,
+        state.research_expanded,
     );
     frame.render_stateful_widget(tree_view, layout.tree, &mut state.tree_state);
 
@@ -47,8 +67,20 @@ pub fn view(
     let detail_pane = DetailPane::new(selected_item, data, detail_focused, state.detail_scroll);
     frame.render_widget(detail_pane, layout.detail);
 
+    // Render command output (bottom pane, when shown)
+    if let Some(output_area) = layout.output {
+        let output_focused = state.focused_pane == FocusedPane::Output;
+        let output_pane = OutputPane::new(&state.command_output, output_focused);
+        frame.render_widget(output_pane, output_area);
+    }
+
     // Render status bar (bottom)
-    let status_bar = StatusBar::new(&data.state, state.focused_pane);
+    let status_bar = StatusBar::new(
+        &data.state,
+        state.focused_pane,
+        state.notice.as_deref(),
+        diagnostics,
+    );
     frame.render_widget(status_bar, layout.status_bar);
 
     // Render overlays based on input mode
@@ -65,6 +97,47 @@ pub fn view(
             );
             frame.render_widget(search_input, layout.status_bar);
         }
+        InputMode::Diagnostics => {
+            // Keep the offset valid when a reload shortens the list
+            state.diagnostics_max_scroll = DiagnosticsPanel::max_scroll(diagnostics, area);
+            state.diagnostics_scroll = state.diagnostics_scroll.min(state.diagnostics_max_scroll);
+            let panel = DiagnosticsPanel::new(diagnostics, state.diagnostics_scroll);
+            frame.render_widget(panel, area);
+        }
+        InputMode::Command => {
+            frame.render_widget(CommandPalette::new(&state.palette), area);
+        }
+        InputMode::Decisions => {
+            let decisions = all_decisions(data);
+            frame.render_widget(DecisionsView::new(&decisions, &state.decisions), area);
+        }
+        InputMode::Settings => {
+            let settings = SettingsView::new(data.config.as_ref(), state.settings_selected);
+            frame.render_widget(settings, area);
+        }
+        InputMode::Projects => {
+            frame.render_widget(ProjectList::new(&state.project_list), area);
+        }
+        InputMode::Graph => {
+            frame.render_widget(DependencyGraphView::new(&state.graph), area);
+        }
+        InputMode::Waves => {
+            let waves = state
+                .waves
+                .phase()
+                .and_then(|phase| phase_waves(data, phase));
+            frame.render_widget(WaveView::new(waves.as_ref(), &state.waves), area);
+        }
+        InputMode::Progress => {
+            frame.render_widget(ProgressChart::new(&state.burnup), area);
+        }
+        InputMode::Activity => {
+            // Keep the offset valid when a reload shortens the feed
+            state.activity_max_scroll = ActivityPanel::max_scroll(activity, area);
+            state.activity_scroll = state.activity_scroll.min(state.activity_max_scroll);
+            let panel = ActivityPanel::new(activity, state.activity_scroll);
+            frame.render_widget(panel, area);
+        }
         InputMode::Normal => {}
     }
 }
@@ -81,4 +154,4 @@ fn render_size_warning(frame: &mut Frame) {
 }
 
 // Re-export for use by app.rs
-pub use crate::components::tree_view::{build_tree_items, phases_with_requirements};
+pub use crate::components::tree_view::{build_tree_items, phases_with_children};
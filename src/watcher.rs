@@ -0,0 +1,202 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use color_eyre::Result;
+use notify_debouncer_mini::notify::{RecommendedWatcher, RecursiveMode};
+use notify_debouncer_mini::{new_debouncer, DebounceEventResult, Debouncer};
+use tokio::sync::mpsc;
+
+use crate::event::Event;
+
+/// Default debounce window for file change events
+///
+/// Editors often emit several events per save (write, rename, chmod), so changes
+/// are coalesced before triggering a reload.
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// File extensions that contain planning data
+const WATCHED_EXTENSIONS: &[&str] = &["md", "json"];
+
+/// Debounced watcher for the .planning/ directory
+///
+/// Sends `Event::DataChanged` through the event channel whenever a planning file
+/// changes. The watcher stops when dropped.
+pub struct FileWatcher {
+    _debouncer: Debouncer<RecommendedWatcher>,
+}
+
+impl FileWatcher {
+    /// Start watching `planning_dir` recursively
+    pub fn new(
+        planning_dir: &Path,
+        tx: mpsc::UnboundedSender<Event>,
+        debounce: Duration,
+    ) -> Result<Self> {
+        let mut debouncer = new_debouncer(debounce, move |result: DebounceEventResult| {
+            // Watcher errors are transient (e.g. a file vanishing mid-save); skip them
+            let Ok(events) = result else {
+                return;
+            };
+
+            let paths: Vec<PathBuf> = events
+                .into_iter()
+                .map(|event| event.path)
+                .filter(|path| is_planning_file(path))
+                .collect();
+
+            if !paths.is_empty() {
+                let _ = tx.send(Event::DataChanged(paths));
+            }
+        })?;
+
+        debouncer
+            .watcher()
+            .watch(planning_dir, RecursiveMode::Recursive)?;
+
+        Ok(Self {
+            _debouncer: debouncer,
+        })
+    }
+}
+
+/// Debounced watcher for the files plan must_haves are checked against
+///
+/// Watches each file's parent directory non-recursively (editors often replace
+/// files on save, which a watch on the file itself would miss) and sends
+/// `Event::SourcesChanged` for changes to the given files only.
+pub struct SourceWatcher {
+    _debouncer: Debouncer<RecommendedWatcher>,
+}
+
+impl SourceWatcher {
+    /// Start watching `files`; directories that do not exist yet are skipped
+    pub fn new(
+        files: Vec<PathBuf>,
+        tx: mpsc::UnboundedSender<Event>,
+        debounce: Duration,
+    ) -> Result<Self> {
+        let mut dirs: Vec<PathBuf> = files
+            .iter()
+            .filter_map(|file| file.parent())
+            .filter(|dir| dir.is_dir())
+            .map(Path::to_path_buf)
+            .collect();
+        dirs.sort();
+        dirs.dedup();
+
+        let mut debouncer = new_debouncer(debounce, move |result: DebounceEventResult| {
+            let Ok(events) = result else {
+                return;
+            };
+
+            let paths: Vec<PathBuf> = events
+                .into_iter()
+                .map(|event| event.path)
+                .filter(|path| files.contains(path))
+                .collect();
+
+            if !paths.is_empty() {
+                let _ = tx.send(Event::SourcesChanged(paths));
+            }
+        })?;
+
+        for dir in &dirs {
+            debouncer
+                .watcher()
+                .watch(dir, RecursiveMode::NonRecursive)?;
+        }
+
+        Ok(Self {
+            _debouncer: debouncer,
+        })
+    }
+}
+
+/// Check whether a changed path is a planning file worth reloading for
+///
+/// Ignores editor swap/backup files and hidden files.
+fn is_planning_file(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+        return false;
+    };
+
+    if name.starts_with('.') || name.ends_with('~') {
+        return false;
+    }
+
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| WATCHED_EXTENSIONS.contains(&ext))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_is_planning_file() {
+        assert!(is_planning_file(Path::new("/p/.planning/STATE.md")));
+        assert!(is_planning_file(Path::new("/p/.planning/config.json")));
+        assert!(!is_planning_file(Path::new("/p/.planning/.STATE.md.swp")));
+        assert!(!is_planning_file(Path::new("/p/.planning/STATE.md~")));
+        assert!(!is_planning_file(Path::new("/p/.planning/notes.txt")));
+    }
+
+    #[tokio::test]
+    async fn test_write_sends_data_changed() {
+        let dir = tempdir().unwrap();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let _watcher = FileWatcher::new(dir.path(), tx, Duration::from_millis(50)).unwrap();
+
+        std::fs::write(dir.path().join("STATE.md"), "# Project State\n").unwrap();
+
+        let event = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("watcher did not report the change")
+            .unwrap();
+
+        match event {
+            Event::DataChanged(paths) => {
+                assert!(paths.iter().any(|p| p.ends_with("STATE.md")));
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_source_watcher_sends_only_checked_files() {
+        let dir = tempdir().unwrap();
+        let checked = dir.path().join("state.rs");
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let _watcher =
+            SourceWatcher::new(vec![checked.clone()], tx, Duration::from_millis(50)).unwrap();
+
+        std::fs::write(dir.path().join("other.rs"), "ignored").unwrap();
+        std::fs::write(&checked, "pub enum InputMode {}").unwrap();
+
+        let event = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("watcher did not report the change")
+            .unwrap();
+
+        match event {
+            Event::SourcesChanged(paths) => {
+                assert!(paths.iter().all(|p| p.ends_with("state.rs")));
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ignored_files_do_not_send() {
+        let dir = tempdir().unwrap();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let _watcher = FileWatcher::new(dir.path(), tx, Duration::from_millis(50)).unwrap();
+
+        std::fs::write(dir.path().join("scratch.txt"), "ignored").unwrap();
+
+        let result = tokio::time::timeout(Duration::from_millis(500), rx.recv()).await;
+        assert!(result.is_err());
+    }
+}
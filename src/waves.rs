@@ -0,0 +1,287 @@
+use std::collections::HashMap;
+
+use crate::data::{Phase, PhaseId, Plan, PlanningData};
+
+/// Whether a plan can be executed yet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Readiness {
+    /// A SUMMARY.md exists
+    Complete,
+    /// Every dependency is complete and the plan has no SUMMARY.md yet
+    Runnable,
+    /// Waiting on a dependency that is incomplete or has no PLAN.md
+    Blocked,
+}
+
+/// A plan placed in its phase's waves
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WavePlan {
+    pub id: String,
+    /// Objective's first line, or the summary title once executed
+    pub title: String,
+    /// Declared `wave:`; None without frontmatter or with wave 0
+    pub wave: Option<u32>,
+    pub depends_on: Vec<String>,
+    pub readiness: Readiness,
+    /// Dependencies holding a blocked plan back
+    pub waiting_on: Vec<String>,
+    /// Ways the declared wave disagrees with the plan's dependencies
+    pub problems: Vec<String>,
+}
+
+/// Plans that declare the same wave
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Wave {
+    /// None for plans without a wave, which come last
+    pub number: Option<u32>,
+    pub plans: Vec<WavePlan>,
+}
+
+/// A phase's plans grouped by wave
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhaseWaves {
+    pub phase: PhaseId,
+    pub name: String,
+    pub waves: Vec<Wave>,
+}
+
+impl PhaseWaves {
+    /// Every plan of the phase, wave by wave
+    pub fn plans(&self) -> impl Iterator<Item = &WavePlan> {
+        self.waves.iter().flat_map(|wave| &wave.plans)
+    }
+}
+
+/// Phases of the roadmap that have plans, in roadmap order
+pub fn phases_with_plans(data: &PlanningData) -> Vec<PhaseId> {
+    data.roadmap
+        .phases
+        .iter()
+        .filter(|phase| !phase.plans.is_empty())
+        .map(|phase| phase.number)
+        .collect()
+}
+
+/// Group a phase's plans by `wave:` and check them against `depends_on`
+///
+/// Dependencies may name plans of any phase; plans of archived milestones count as
+/// complete. Waves only order plans within a phase, so the wave checks look at
+/// same-phase dependencies: a plan must come in a later wave than each of them.
+/// Waiting longer than needed is allowed.
+pub fn phase_waves(data: &PlanningData, phase: PhaseId) -> Option<PhaseWaves> {
+    let phase = data.roadmap.phases.iter().find(|p| p.number == phase)?;
+
+    let archived = data
+        .roadmap
+        .milestones
+        .iter()
+        .flat_map(|milestone| &milestone.archived_phases);
+    let mut complete: HashMap<&str, bool> = archived
+        .flat_map(|phase| &phase.plans)
+        .map(|plan| (plan.id.as_str(), true))
+        .collect();
+    complete.extend(
+        data.roadmap
+            .phases
+            .iter()
+            .flat_map(|phase| &phase.plans)
+            .map(|plan| (plan.id.as_str(), plan.is_complete())),
+    );
+
+    let mut waves: Vec<Wave> = Vec::new();
+    for plan in &phase.plans {
+        let placed = wave_plan(phase, plan, &complete);
+        match waves.iter_mut().find(|wave| wave.number == placed.wave) {
+            Some(wave) => wave.plans.push(placed),
+            None => waves.push(Wave {
+                number: placed.wave,
+                plans: vec![placed],
+            }),
+        }
+    }
+    // Numbered waves in order, then the plans without one
+    waves.sort_by_key(|wave| (wave.number.is_none(), wave.number));
+
+    Some(PhaseWaves {
+        phase: phase.number,
+        name: phase.name.clone(),
+        waves,
+    })
+}
+
+fn declared_wave(plan: &Plan) -> Option<u32> {
+    plan.frontmatter
+        .as_ref()
+        .map(|fm| fm.wave)
+        .filter(|&wave| wave > 0)
+}
+
+fn wave_plan(phase: &Phase, plan: &Plan, complete: &HashMap<&str, bool>) -> WavePlan {
+    let wave = declared_wave(plan);
+    let depends_on = plan
+        .frontmatter
+        .as_ref()
+        .map(|fm| fm.depends_on.clone())
+        .unwrap_or_default();
+
+    let mut problems = Vec::new();
+    let waiting_on: Vec<String> = depends_on
+        .iter()
+        .filter(|dep| complete.get(dep.as_str()) != Some(&true))
+        .cloned()
+        .collect();
+    for dep in &depends_on {
+        if !complete.contains_key(dep.as_str()) {
+            problems.push(format!("depends on {}, which has no PLAN.md", dep));
+        }
+    }
+
+    match wave {
+        None => problems.push("declares no wave".to_string()),
+        Some(wave) => {
+            for dep in &depends_on {
+                let Some(dep_wave) = phase
+                    .plans
+                    .iter()
+                    .find(|p| &p.id == dep)
+                    .and_then(declared_wave)
+                else {
+                    continue;
+                };
+                if dep_wave >= wave {
+                    problems.push(format!(
+                        "in wave {} but depends on {} in wave {}",
+                        wave, dep, dep_wave
+                    ));
+                }
+            }
+        }
+    }
+
+    let readiness = if plan.is_complete() {
+        Readiness::Complete
+    } else if waiting_on.is_empty() {
+        Readiness::Runnable
+    } else {
+        Readiness::Blocked
+    };
+
+    WavePlan {
+        id: plan.id.clone(),
+        title: plan.title().to_string(),
+        wave,
+        depends_on,
+        readiness,
+        waiting_on,
+        problems,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{Milestone, PlanFrontmatter, PlanSummary};
+
+    fn plan(id: &str, wave: u32, deps: &[&str], done: bool) -> Plan {
+        Plan {
+            id: id.to_string(),
+            frontmatter: Some(PlanFrontmatter {
+                wave,
+                depends_on: deps.iter().map(|d| d.to_string()).collect(),
+                ..Default::default()
+            }),
+            summary: done.then(PlanSummary::default),
+            ..Default::default()
+        }
+    }
+
+    fn data(plans: Vec<Plan>) -> PlanningData {
+        let mut data = PlanningData::default();
+        data.roadmap.phases.push(Phase {
+            number: PhaseId::new(2),
+            plans,
+            ..Default::default()
+        });
+        data
+    }
+
+    fn ids(waves: &PhaseWaves) -> Vec<(Option<u32>, Vec<&str>)> {
+        waves
+            .waves
+            .iter()
+            .map(|w| (w.number, w.plans.iter().map(|p| p.id.as_str()).collect()))
+            .collect()
+    }
+
+    fn get<'a>(waves: &'a PhaseWaves, id: &str) -> &'a WavePlan {
+        waves.plans().find(|p| p.id == id).unwrap()
+    }
+
+    #[test]
+    fn test_waves_and_readiness() {
+        let mut data = data(vec![
+            plan("02-01", 1, &[], true),
+            plan("02-02", 1, &["01-03"], false),
+            plan("02-03", 2, &["02-01"], false),
+            plan("02-04", 2, &["02-01", "02-02"], false),
+            Plan {
+                id: "02-05".to_string(),
+                ..Default::default()
+            },
+        ]);
+        data.roadmap.milestones.push(Milestone {
+            archived_phases: vec![Phase {
+                number: PhaseId::new(1),
+                plans: vec![plan("01-03", 1, &[], false)],
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+
+        let waves = phase_waves(&data, PhaseId::new(2)).unwrap();
+
+        assert_eq!(
+            ids(&waves),
+            vec![
+                (Some(1), vec!["02-01", "02-02"]),
+                (Some(2), vec!["02-03", "02-04"]),
+                (None, vec!["02-05"]),
+            ]
+        );
+        assert_eq!(get(&waves, "02-01").readiness, Readiness::Complete);
+        // Archived plans are done even without a summary
+        assert_eq!(get(&waves, "02-02").readiness, Readiness::Runnable);
+        assert_eq!(get(&waves, "02-03").readiness, Readiness::Runnable);
+        let blocked = get(&waves, "02-04");
+        assert_eq!(blocked.readiness, Readiness::Blocked);
+        assert_eq!(blocked.waiting_on, vec!["02-02"]);
+        assert!(blocked.problems.is_empty());
+        assert_eq!(get(&waves, "02-05").problems, vec!["declares no wave"]);
+        assert_eq!(phase_waves(&data, PhaseId::new(9)), None);
+    }
+
+    #[test]
+    fn test_wave_inconsistencies() {
+        let data = data(vec![
+            plan("02-01", 1, &[], false),
+            plan("02-02", 1, &["02-01"], false),
+            plan("02-03", 3, &["02-01"], false),
+            plan("02-04", 2, &["02-09"], false),
+        ]);
+
+        let waves = phase_waves(&data, PhaseId::new(2)).unwrap();
+
+        assert_eq!(
+            get(&waves, "02-02").problems,
+            vec!["in wave 1 but depends on 02-01 in wave 1"]
+        );
+        // A later wave than needed only delays the plan
+        assert!(get(&waves, "02-03").problems.is_empty());
+        let missing = get(&waves, "02-04");
+        assert_eq!(missing.readiness, Readiness::Blocked);
+        assert_eq!(
+            missing.problems,
+            vec!["depends on 02-09, which has no PLAN.md"]
+        );
+    }
+}
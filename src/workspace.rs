@@ -0,0 +1,198 @@
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::eyre;
+use color_eyre::Result;
+
+use crate::data::{load_planning_data, PhaseId, PlanningData};
+
+/// Directories never searched for projects: build output and dependencies
+const SKIPPED_DIRS: &[&str] = &["target", "node_modules"];
+
+/// One project in a workspace, summarized from its STATE.md
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProjectSummary {
+    /// PROJECT.md title, or the repository directory name
+    pub name: String,
+    pub planning_dir: PathBuf,
+    pub current_phase: Option<PhaseId>,
+    pub phases_complete: u8,
+    pub total_phases: u8,
+    /// Progress percentage
+    pub progress: u8,
+    /// Status (Planning, Executing, etc.)
+    pub status: String,
+}
+
+impl ProjectSummary {
+    pub fn new(planning_dir: &Path, data: &PlanningData) -> Self {
+        let name = if data.project.name.is_empty() {
+            planning_dir
+                .parent()
+                .and_then(Path::file_name)
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| planning_dir.display().to_string())
+        } else {
+            data.project.name.clone()
+        };
+        let state = &data.state;
+
+        Self {
+            name,
+            planning_dir: planning_dir.to_path_buf(),
+            current_phase: state.current_phase,
+            phases_complete: state.phases_complete,
+            total_phases: state.total_phases,
+            progress: state.progress,
+            status: state.status.clone(),
+        }
+    }
+
+    /// Load and summarize a project; unreadable projects keep only their name
+    fn load(planning_dir: &Path) -> Self {
+        let data = load_planning_data(planning_dir).unwrap_or_default();
+        Self::new(planning_dir, &data)
+    }
+}
+
+/// Every project with a `.planning/` directory below a root
+#[derive(Debug, Clone, Default)]
+pub struct Workspace {
+    pub root: PathBuf,
+    /// Projects in path order
+    pub projects: Vec<ProjectSummary>,
+    /// Index of the project being displayed
+    pub active: usize,
+}
+
+impl Workspace {
+    /// Find the projects below `root`; fails if there are none
+    pub fn discover(root: &Path) -> Result<Self> {
+        let projects: Vec<ProjectSummary> = discover_planning_dirs(root)
+            .iter()
+            .map(|dir| ProjectSummary::load(dir))
+            .collect();
+        if projects.is_empty() {
+            return Err(eyre!(
+                "No .planning/ directories found under {}",
+                root.display()
+            ));
+        }
+
+        Ok(Self {
+            root: root.to_path_buf(),
+            projects,
+            active: 0,
+        })
+    }
+
+    /// Re-read every project's summary (other projects are not watched)
+    pub fn refresh(&mut self) {
+        for project in &mut self.projects {
+            *project = ProjectSummary::load(&project.planning_dir);
+        }
+    }
+
+    pub fn active_project(&self) -> &ProjectSummary {
+        &self.projects[self.active]
+    }
+}
+
+/// All `.planning/` directories below `root`, sorted
+///
+/// Skips hidden directories, `target/` and `node_modules/`, and does not look
+/// inside a `.planning/` directory for further projects.
+pub fn discover_planning_dirs(root: &Path) -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    let mut pending = vec![root.to_path_buf()];
+
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            // file_type() does not follow symlinks, so link cycles are not walked
+            if !entry.file_type().is_ok_and(|t| t.is_dir()) {
+                continue;
+            }
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if name == ".planning" {
+                found.push(entry.path());
+            } else if !name.starts_with('.') && !SKIPPED_DIRS.contains(&name.as_ref()) {
+                pending.push(entry.path());
+            }
+        }
+    }
+
+    found.sort();
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn write(dir: &Path, name: &str, content: &str) {
+        let path = dir.join(name);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_discover_skips_build_and_dependency_dirs() {
+        let dir = tempdir().unwrap();
+        for project in [
+            "api",
+            "apps/web",
+            "target/debug",
+            "apps/web/node_modules/dep",
+            ".git/modules/x",
+        ] {
+            std::fs::create_dir_all(dir.path().join(project).join(".planning")).unwrap();
+        }
+        std::fs::create_dir_all(dir.path().join("api/.planning/phases/.planning")).unwrap();
+
+        let found = discover_planning_dirs(dir.path());
+
+        assert_eq!(
+            found,
+            vec![
+                dir.path().join("api/.planning"),
+                dir.path().join("apps/web/.planning"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_summary_from_state() {
+        let dir = tempdir().unwrap();
+        write(dir.path(), "api/.planning/PROJECT.md", "# Billing API\n");
+        write(
+            dir.path(),
+            "api/.planning/STATE.md",
+            "**Phase:** 3 - Billing\n**Status:** Executing\n**Progress:** 40%\n\n\
+             | Metric | Value |\n|--------|-------|\n| Total Phases | 5 |\n| Phases Complete | 2 |\n",
+        );
+        std::fs::create_dir_all(dir.path().join("web/.planning")).unwrap();
+
+        let workspace = Workspace::discover(dir.path()).unwrap();
+
+        let [api, web] = workspace.projects.as_slice() else {
+            panic!("expected two projects, got {:?}", workspace.projects);
+        };
+        assert_eq!(api.name, "Billing API");
+        assert_eq!(api.current_phase, Some(PhaseId::new(3)));
+        assert_eq!(api.status, "Executing");
+        assert_eq!(api.progress, 40);
+        assert_eq!((api.phases_complete, api.total_phases), (2, 5));
+        // No PROJECT.md: named after the repository directory
+        assert_eq!(web.name, "web");
+    }
+
+    #[test]
+    fn test_discover_empty_workspace_fails() {
+        let dir = tempdir().unwrap();
+        assert!(Workspace::discover(dir.path()).is_err());
+    }
+}